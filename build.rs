@@ -0,0 +1,60 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Collect the `.rs` files directly inside `dir`, sorted by file stem
+fn collect_templates(dir: &Path) -> io::Result<Vec<(String, PathBuf)>> {
+    let mut templates = Vec::new();
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("rs") {
+            continue;
+        }
+        if let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) {
+            templates.push((stem.to_string(), path.canonicalize()?));
+        }
+    }
+
+    templates.sort();
+    Ok(templates)
+}
+
+/// Render a static `(name, contents)` table that embeds every template with `include_str!`
+fn render_table(name: &str, doc: &str, templates: &[(String, PathBuf)]) -> String {
+    let mut out = format!("/// {}\npub static {}: &[(&str, &str)] = &[\n", doc, name);
+    for (stem, path) in templates {
+        out.push_str(&format!(
+            "    ({:?}, include_str!({:?})),\n",
+            stem,
+            path.display().to_string()
+        ));
+    }
+    out.push_str("];\n\n");
+    out
+}
+
+fn main() -> io::Result<()> {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let resources_dir = manifest_dir.join("src/templates/resources");
+    let generated_dir = resources_dir.join("generated");
+
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={}", resources_dir.display());
+
+    let mut out = String::from("// Generated by build.rs. Do not edit.\n\n");
+    out.push_str(&render_table(
+        "RESOURCE_TEMPLATES",
+        "Extension and shared resource templates from `src/templates/resources`, sorted by name.",
+        &collect_templates(&resources_dir)?,
+    ));
+    out.push_str(&render_table(
+        "GENERATED_TEMPLATES",
+        "Generated resource templates from `src/templates/resources/generated`, sorted by name.",
+        &collect_templates(&generated_dir)?,
+    ));
+
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap()).join("embedded_templates.rs");
+    fs::write(out_path, out)
+}
@@ -0,0 +1,985 @@
+//! Rust source generation from the Stripe OpenAPI spec.
+//!
+//! The output follows the layout of the shipped templates: one file per Stripe
+//! object in `resources/generated/`, with helper schemas that only one file
+//! uses defined next to their single user.
+
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::openapi::{self, Spec};
+
+const BANNER: &str = "// ======================================\n\
+                      // This file was automatically generated.\n\
+                      // ======================================\n";
+
+/// Field names that need a `_` suffix and a `#[serde(rename)]`
+const RUST_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in",
+    "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// A file for `resources/generated/`
+pub struct GeneratedFile {
+    /// File stem, e.g. `billing_meter`
+    pub name: String,
+    pub content: String,
+}
+
+/// Everything generated from a set of schemas
+pub struct Generated {
+    pub files: Vec<GeneratedFile>,
+    /// Id types the generated files import from `ids.rs`
+    pub ids: BTreeSet<String>,
+}
+
+/// Generate resource files for `schemas` and event files for `events`
+pub fn generate(spec: &Spec, schemas: &BTreeSet<String>, events: &[String]) -> Generated {
+    let layout = Layout::new(spec.schemas(), schemas);
+    let mut generated = Generated { files: Vec::new(), ids: BTreeSet::new() };
+
+    for (file, members) in &layout.files {
+        let mut writer = FileWriter::new(&layout, file);
+        for schema in members {
+            writer.render_schema(schema);
+        }
+        generated.ids.extend(writer.ids.iter().cloned());
+        generated.files.push(GeneratedFile { name: file.clone(), content: writer.finish() });
+    }
+
+    let objects = event_objects(spec.schemas(), schemas);
+    for event in events {
+        generated.files.push(GeneratedFile {
+            name: openapi::schema_file_name(event),
+            content: render_event(event, event_object(&objects, event).map(type_name).as_deref()),
+        });
+    }
+
+    generated.files.sort_by(|a, b| a.name.cmp(&b.name));
+    generated
+}
+
+/// Add a `def_id!` line to an `ids.rs` source for every id it does not define yet
+///
+/// The spec does not document id prefixes, so new ids accept any string.
+pub fn render_ids(ids_rs: &str, ids: &BTreeSet<String>) -> String {
+    let defined = defined_ids(ids_rs);
+    let missing: Vec<&String> = ids.iter().filter(|id| !defined.contains(*id)).collect();
+    if missing.is_empty() {
+        return ids_rs.to_string();
+    }
+
+    let new_lines: String = missing.iter().map(|id| format!("def_id!({}: String);\n", id)).collect();
+    let mut lines: Vec<&str> = ids_rs.split_inclusive('\n').collect();
+    let insert_at = lines
+        .iter()
+        .rposition(|line| line.starts_with("def_id!(") && line.trim_end().ends_with(");"))
+        .map_or(lines.len(), |index| index + 1);
+    lines.insert(insert_at, &new_lines);
+    lines.concat()
+}
+
+/// Replace the variants of `EventType` in a `webhook_events.rs` source
+///
+/// Variants already present keep their names so existing matches still compile.
+pub fn render_event_types(webhook_events_rs: &str, events: &[String]) -> Option<String> {
+    let (body_start, body_end) = event_type_body(webhook_events_rs)?;
+    let existing = event_variants(webhook_events_rs);
+
+    let mut new_body = String::new();
+    for event in events {
+        let variant = existing.get(event).cloned().unwrap_or_else(|| type_name(event));
+        new_body.push_str(&format!("    #[serde(rename = \"{}\")]\n    {},\n", event, variant));
+    }
+    new_body.push_str("    #[serde(other)]\n    #[default]\n    Unknown,\n");
+
+    Some(format!(
+        "{}{}{}",
+        &webhook_events_rs[..body_start],
+        new_body,
+        &webhook_events_rs[body_end..]
+    ))
+}
+
+/// The `EventType` variant for each event in a `webhook_events.rs` source
+pub fn event_variants(webhook_events_rs: &str) -> BTreeMap<String, String> {
+    let mut variants = BTreeMap::new();
+    let Some((body_start, body_end)) = event_type_body(webhook_events_rs) else {
+        return variants;
+    };
+
+    // `#[serde(rename = "...")]` -> variant name pairs
+    let mut lines = webhook_events_rs[body_start..body_end].lines().map(str::trim);
+    while let Some(line) = lines.next() {
+        if let Some(event) = line.strip_prefix("#[serde(rename = \"").and_then(|rest| rest.strip_suffix("\")]")) {
+            if let Some(variant) = lines.next() {
+                variants.insert(event.to_string(), variant.trim_end_matches(',').to_string());
+            }
+        }
+    }
+    variants
+}
+
+/// Byte range of the variants in `pub enum EventType { ... }`
+fn event_type_body(webhook_events_rs: &str) -> Option<(usize, usize)> {
+    let start = webhook_events_rs.find("pub enum EventType {")?;
+    let body_start = start + webhook_events_rs[start..].find('\n')? + 1;
+    let body_end = body_start + webhook_events_rs[body_start..].find("\n}")? + 1;
+    Some((body_start, body_end))
+}
+
+/// Replace the variants of `ErrorCode` in an `error.rs` source
+///
+/// Variants already present keep their names so existing matches still compile.
+pub fn render_error_codes(error_rs: &str, codes: &[String]) -> Option<String> {
+    let start = error_rs.find("    ErrorCode {\n")?;
+    let body_start = start + "    ErrorCode {\n".len();
+    let body_end = body_start + error_rs[body_start..].find("    }\n")?;
+
+    // `Variant => "code",` lines
+    let existing: BTreeMap<&str, &str> = error_rs[body_start..body_end]
+        .lines()
+        .filter_map(|line| line.trim().trim_end_matches(',').split_once(" => "))
+        .map(|(variant, code)| (code.trim_matches('"'), variant))
+        .collect();
+
+    let mut new_body = String::new();
+    for code in codes {
+        let variant = existing.get(code.as_str()).map_or_else(|| variant_name(code), |variant| variant.to_string());
+        new_body.push_str(&format!("        {} => \"{}\",\n", variant, code));
+    }
+
+    Some(format!("{}{}{}", &error_rs[..body_start], new_body, &error_rs[body_end..]))
+}
+
+/// Render `typed_event.rs`: a `TypedEvent` with a variant for each event in
+/// `events` (the installed payloads) and an `Unknown` fallback for the rest
+pub fn render_typed_events(webhook_events_rs: &str, events: &BTreeSet<String>) -> String {
+    let variants = event_variants(webhook_events_rs);
+    let typed: Vec<(&String, String)> = events
+        .iter()
+        .filter_map(|event| Some((variants.get(event)?, type_name(event))))
+        .collect();
+
+    let mut imports: Vec<&str> = typed.iter().map(|(_, payload)| payload.as_str()).collect();
+    imports.extend(["Event", "EventType"]);
+    imports.sort_unstable();
+
+    let mut out = format!(
+        "{}\nuse crate::stripe::resources::{{{}}};\n\
+         use serde::de::DeserializeOwned;\nuse serde::{{Deserialize, Deserializer}};\nuse serde_json::Value;\n\n\
+         /// An event with its `data` deserialized into the payload for its type.\n\
+         ///\n/// Events whose payload is not installed keep their `data` as JSON.\n\
+         #[derive(Clone, Debug)]\npub enum TypedEvent {{\n",
+        BANNER,
+        imports.join(", ")
+    );
+    for (variant, payload) in &typed {
+        out.push_str(&format!("    {}(Box<{}>),\n", variant, payload));
+    }
+    out.push_str(
+        "    Unknown(EventType, Value),\n}\n\n\
+         impl TypedEvent {\n    \
+         /// Deserialize the `data` of an event of type `event_type`.\n    \
+         pub fn from_data(event_type: EventType, data: Value) -> Result<Self, serde_json::Error> {\n        \
+         Ok(match event_type {\n",
+    );
+    for (variant, _) in &typed {
+        out.push_str(&format!("            EventType::{0} => Self::{0}(payload(data)?),\n", variant));
+    }
+    out.push_str(
+        "            event_type => Self::Unknown(event_type, data),\n        })\n    }\n}\n\n\
+         fn payload<T: DeserializeOwned>(data: Value) -> Result<Box<T>, serde_json::Error> {\n    \
+         serde_json::from_value(data).map(Box::new)\n}\n\n\
+         /// Deserialize a whole event, such as a webhook payload.\n\
+         impl<'de> Deserialize<'de> for TypedEvent {\n    \
+         fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {\n        \
+         #[derive(Deserialize)]\n        struct RawEvent {\n            \
+         #[serde(rename = \"type\")]\n            type_: EventType,\n            data: Value,\n        }\n\n        \
+         let event = RawEvent::deserialize(deserializer)?;\n        \
+         Self::from_data(event.type_, event.data).map_err(serde::de::Error::custom)\n    }\n}\n\n\
+         impl Event {\n    \
+         /// The event's `data` as the typed payload for its type.\n    \
+         pub fn typed(&self) -> Result<TypedEvent, serde_json::Error> {\n        \
+         TypedEvent::from_data(self.type_, serde_json::to_value(&self.data)?)\n    }\n}\n",
+    );
+    out
+}
+
+/// Id types an `ids.rs` source defines
+fn defined_ids(ids_rs: &str) -> BTreeSet<String> {
+    ids_rs
+        .lines()
+        .map(str::trim_start)
+        .filter_map(|line| {
+            line.strip_prefix("def_id!(")
+                .or_else(|| line.strip_prefix("enum "))
+                .or_else(|| line.strip_prefix("pub struct "))
+        })
+        .map(|rest| {
+            rest.chars().take_while(|c| c.is_ascii_alphanumeric() || *c == '_').collect()
+        })
+        .collect()
+}
+
+/// Which file defines each schema
+struct Layout<'a> {
+    schemas: &'a Map<String, Value>,
+    /// Schema name -> file stem
+    owners: BTreeMap<String, String>,
+    /// File stem -> schemas defined in it, the file's own schema first
+    files: BTreeMap<String, Vec<String>>,
+}
+
+impl<'a> Layout<'a> {
+    /// Stripe objects get their own file, as do helpers referenced from more
+    /// than one schema; every other helper lives with the schema using it.
+    fn new(schemas: &'a Map<String, Value>, selected: &BTreeSet<String>) -> Self {
+        let mut referencers: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+        for name in selected {
+            let mut refs = BTreeSet::new();
+            openapi::find_refs(&schemas[name.as_str()], &mut refs);
+            for reference in refs {
+                if reference == *name || !selected.contains(&reference) {
+                    continue;
+                }
+                if let Some((key, _)) = schemas.get_key_value(&reference) {
+                    referencers.entry(key.as_str()).or_default().insert(name.as_str());
+                }
+            }
+        }
+
+        let single_referencer = |name: &str| -> Option<&str> {
+            let is_object = schemas[name].get("x-resourceId").is_some();
+            match referencers.get(name) {
+                Some(users) if users.len() == 1 && !is_object => users.first().copied(),
+                _ => None,
+            }
+        };
+
+        let mut owners = BTreeMap::new();
+        let mut files: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for name in selected {
+            // Follow the chain of single users up to a schema that owns a file
+            let mut root = name.as_str();
+            let mut seen = BTreeSet::from([root]);
+            while let Some(user) = single_referencer(root) {
+                if !seen.insert(user) {
+                    root = name;
+                    break;
+                }
+                root = user;
+            }
+
+            let file = openapi::schema_file_name(root);
+            owners.insert(name.clone(), file.clone());
+            let members = files.entry(file).or_default();
+            if root == name {
+                members.insert(0, name.clone());
+            } else {
+                members.push(name.clone());
+            }
+        }
+
+        Self { schemas, owners, files }
+    }
+}
+
+/// Renders the types of one generated file
+struct FileWriter<'a> {
+    layout: &'a Layout<'a>,
+    file: &'a str,
+    ids: BTreeSet<String>,
+    params: BTreeSet<&'static str>,
+    resources: BTreeSet<String>,
+    uses_hash_map: bool,
+    /// The file's own object, rendered first
+    main: Vec<String>,
+    structs: BTreeMap<String, String>,
+    enums: BTreeMap<String, String>,
+}
+
+impl<'a> FileWriter<'a> {
+    fn new(layout: &'a Layout<'a>, file: &'a str) -> Self {
+        Self {
+            layout,
+            file,
+            ids: BTreeSet::new(),
+            params: BTreeSet::new(),
+            resources: BTreeSet::new(),
+            uses_hash_map: false,
+            main: Vec::new(),
+            structs: BTreeMap::new(),
+            enums: BTreeMap::new(),
+        }
+    }
+
+    /// Render a named schema from `components/schemas`
+    fn render_schema(&mut self, name: &str) {
+        let schema = &self.layout.schemas[name];
+        let type_name = type_name(name);
+        let is_main = openapi::schema_file_name(name) == self.file;
+
+        if let Some(variants) = union_variants(schema) {
+            let doc = format!("The resource representing a Stripe \"{}\".", name);
+            let union = self.render_union(&type_name, &doc, variants, false);
+            self.enums.insert(type_name, union);
+            return;
+        }
+
+        let mut out = String::new();
+        if is_main {
+            let title = schema.get("title").and_then(Value::as_str).unwrap_or(name);
+            out.push_str(&format!("/// The resource representing a Stripe \"{}\".\n", title));
+            if let Some(object) = object_name(schema) {
+                out.push_str(&format!(
+                    "///\n/// For more details see <https://stripe.com/docs/api/{}/object>\n",
+                    object.replace('.', "/")
+                ));
+            }
+        } else {
+            out.push_str(&doc_comment(schema.get("description"), ""));
+        }
+
+        let id_type = self.id_type(name, schema);
+        out.push_str(&self.render_struct(&type_name, schema, id_type.as_deref(), is_main));
+
+        if schema.get("x-resourceId").is_some() {
+            out.push('\n');
+            out.push_str(&self.render_object_impl(&type_name, name, schema, id_type.as_deref()));
+            self.params.insert("Object");
+        }
+
+        if is_main {
+            self.main.push(out);
+        } else {
+            self.structs.insert(type_name, out);
+        }
+    }
+
+    /// The id type of a Stripe object with a required string `id`
+    fn id_type(&mut self, name: &str, schema: &Value) -> Option<String> {
+        schema.get("x-resourceId")?;
+        let id = schema.pointer("/properties/id")?;
+        let required = is_required(schema, "id");
+        if id.get("type").and_then(Value::as_str) != Some("string") || !required {
+            return None;
+        }
+
+        // `deleted_customer` shares `CustomerId` with `customer`
+        let object = name.strip_prefix("deleted_").unwrap_or(name);
+        let id_type = format!("{}Id", type_name(object));
+        self.ids.insert(id_type.clone());
+        Some(id_type)
+    }
+
+    fn render_object_impl(&self, type_name: &str, name: &str, schema: &Value, id_type: Option<&str>) -> String {
+        let object = object_name(schema).unwrap_or(name);
+        let id = match id_type {
+            Some(id_type) => format!(
+                "    type Id = {};\n    fn id(&self) -> Self::Id {{\n        self.id.clone()\n    }}\n",
+                id_type
+            ),
+            None => "    type Id = ();\n    fn id(&self) -> Self::Id {}\n".to_string(),
+        };
+        format!(
+            "impl Object for {} {{\n{}    fn object(&self) -> &'static str {{\n        \"{}\"\n    }}\n}}\n",
+            type_name, id, object
+        )
+    }
+
+    /// Render a struct for an object schema
+    fn render_struct(&mut self, type_name: &str, schema: &Value, id_type: Option<&str>, is_main: bool) -> String {
+        let mut fields = Vec::new();
+        if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+            // `id` leads, everything else follows in name order
+            let mut names: Vec<&String> = properties.keys().collect();
+            names.sort_by_key(|name| (name.as_str() != "id", name.as_str()));
+
+            for field in names {
+                let property = &properties[field.as_str()];
+                if field == "object" && is_constant(property) {
+                    continue;
+                }
+                fields.push(self.render_field(type_name, field, property, is_required(schema, field), id_type));
+            }
+        }
+
+        let open = if is_main || fields.is_empty() { "{\n" } else { "{\n\n" };
+        format!(
+            "#[derive(Clone, Debug, Default, Deserialize, Serialize)]\npub struct {} {}{}}}\n",
+            type_name,
+            open,
+            fields.join("\n")
+        )
+    }
+
+    fn render_field(
+        &mut self,
+        parent: &str,
+        field: &str,
+        property: &Value,
+        required: bool,
+        id_type: Option<&str>,
+    ) -> String {
+        let mut rust_type = match id_type {
+            Some(id_type) if field == "id" => id_type.to_string(),
+            _ => self.rust_type(parent, field, property),
+        };
+        if rust_type == parent {
+            rust_type = format!("Box<{}>", rust_type);
+        }
+
+        let nullable = property.get("nullable").and_then(Value::as_bool).unwrap_or(false);
+        if nullable || !required {
+            rust_type = format!("Option<{}>", rust_type);
+        }
+
+        let mut out = doc_comment(property.get("description"), "    ");
+        if !required {
+            out.push_str("    #[serde(skip_serializing_if = \"Option::is_none\")]\n");
+        }
+        let ident = if RUST_KEYWORDS.contains(&field) {
+            out.push_str(&format!("    #[serde(rename = \"{}\")]\n", field));
+            format!("{}_", field)
+        } else {
+            field.to_string()
+        };
+        out.push_str(&format!("    pub {}: {},\n", ident, rust_type));
+        out
+    }
+
+    /// The Rust type of a property of `parent`, rendering any inline types it needs
+    fn rust_type(&mut self, parent: &str, field: &str, property: &Value) -> String {
+        if let Some(reference) = schema_ref(property) {
+            return self.reference_type(reference);
+        }
+
+        if let Some(variants) = union_variants(property) {
+            let expandable = property.get("x-expansionResources").is_some();
+            let variants: Vec<&Value> = variants
+                .iter()
+                .filter(|variant| !is_empty_string_placeholder(variant))
+                .filter(|variant| !(expandable && is_plain_string(variant)))
+                .collect();
+
+            if let [variant] = variants.as_slice() {
+                let inner = self.rust_type(parent, field, variant);
+                if expandable {
+                    self.params.insert("Expandable");
+                    return format!("Expandable<{}>", inner);
+                }
+                return inner;
+            }
+
+            let name = format!("{}{}", parent, type_name(field));
+            let doc = format!("An enum representing the possible values of an `{}`'s `{}` field.", parent, field);
+            let union = self.render_union(&name, &doc, variants.into_iter().cloned().collect(), expandable);
+            self.enums.insert(name.clone(), union);
+            return name;
+        }
+
+        if let Some(values) = property.get("enum").and_then(Value::as_array) {
+            let values: Vec<&str> = values.iter().filter_map(Value::as_str).collect();
+            if !values.is_empty() && property.get("type").and_then(Value::as_str) == Some("string") {
+                let name = format!("{}{}", parent, type_name(field));
+                let rendered = render_enum(parent, field, &name, &values);
+                self.enums.insert(name.clone(), rendered);
+                return name;
+            }
+        }
+
+        let is_timestamp = property.get("format").and_then(Value::as_str) == Some("unix-time");
+        match property.get("type").and_then(Value::as_str) {
+            Some("string") | Some("integer") if is_timestamp => {
+                self.params.insert("Timestamp");
+                "Timestamp".to_string()
+            }
+            Some("string") => "String".to_string(),
+            Some("integer") => "i64".to_string(),
+            Some("number") => "f64".to_string(),
+            Some("boolean") => "bool".to_string(),
+            Some("array") => match property.get("items") {
+                Some(items) => format!("Vec<{}>", self.rust_type(parent, field, items)),
+                None => "Vec<serde_json::Value>".to_string(),
+            },
+            Some("object") => self.object_type(parent, field, property),
+            _ => "serde_json::Value".to_string(),
+        }
+    }
+
+    /// The Rust type of an inline object property
+    fn object_type(&mut self, parent: &str, field: &str, property: &Value) -> String {
+        let properties = property.get("properties").and_then(Value::as_object);
+
+        // Stripe list objects: `{ object: "list", data: [...], has_more, url }`
+        let list_items = properties
+            .filter(|properties| properties.contains_key("has_more"))
+            .and_then(|properties| properties.get("data"))
+            .and_then(|data| data.get("items"));
+        if let Some(items) = list_items {
+            self.params.insert("List");
+            return format!("List<{}>", self.rust_type(parent, field, items));
+        }
+
+        if properties.is_some_and(|properties| !properties.is_empty()) {
+            let name = format!("{}{}", parent, type_name(field));
+            let mut out = doc_comment(property.get("description"), "");
+            out.push_str(&self.render_struct(&name, property, None, false));
+            self.structs.insert(name.clone(), out);
+            return name;
+        }
+
+        match property.get("additionalProperties") {
+            Some(_) if field == "metadata" => {
+                self.params.insert("Metadata");
+                "Metadata".to_string()
+            }
+            Some(values) if values.is_object() && values.as_object().is_some_and(|v| !v.is_empty()) => {
+                self.uses_hash_map = true;
+                format!("HashMap<String, {}>", self.rust_type(parent, field, values))
+            }
+            _ => "serde_json::Value".to_string(),
+        }
+    }
+
+    /// The type of a named schema, importing it when another file defines it
+    fn reference_type(&mut self, reference: &str) -> String {
+        let Some(owner) = self.layout.owners.get(reference) else {
+            return "serde_json::Value".to_string();
+        };
+        let name = type_name(reference);
+        if owner != self.file {
+            self.resources.insert(name.clone());
+        }
+        name
+    }
+
+    /// Render an enum over several possible types
+    ///
+    /// Expandable unions keep a plain id variant, since the referenced types
+    /// share no `Object` implementation for `Expandable` to use.
+    fn render_union(&mut self, name: &str, doc: &str, variants: Vec<Value>, expandable: bool) -> String {
+        let mut members: Vec<(String, String)> = Vec::new();
+        if expandable {
+            members.push(("Id".to_string(), "String".to_string()));
+        }
+        for variant in &variants {
+            let (ident, rust_type) = match schema_ref(variant) {
+                Some(reference) => {
+                    let rust_type = self.reference_type(reference);
+                    (type_name(reference), format!("Box<{}>", rust_type))
+                }
+                None => {
+                    let rust_type = self.rust_type(name, "value", variant);
+                    let ident: String = type_name(&rust_type.replace(|c: char| !c.is_ascii_alphanumeric(), "_"));
+                    (ident, rust_type)
+                }
+            };
+            if !members.iter().any(|(existing, _)| *existing == ident) {
+                members.push((ident, rust_type));
+            }
+        }
+        if members.is_empty() {
+            members.push(("Value".to_string(), "serde_json::Value".to_string()));
+        }
+
+        let mut out = format!(
+            "/// {}\n#[derive(Clone, Debug, Deserialize, Serialize)]\n#[serde(untagged)]\npub enum {} {{\n",
+            doc, name
+        );
+        for (ident, rust_type) in &members {
+            out.push_str(&format!("    {}({}),\n", ident, rust_type));
+        }
+        out.push_str(&format!(
+            "}}\n\nimpl std::default::Default for {} {{\n    fn default() -> Self {{\n        Self::{}(Default::default())\n    }}\n}}\n",
+            name, members[0].0
+        ));
+        out
+    }
+
+    /// Assemble the file: banner, imports, the main object, helper structs, then enums
+    fn finish(self) -> String {
+        let mut out = format!("{}\n", BANNER);
+
+        if self.uses_hash_map {
+            out.push_str("use std::collections::HashMap;\n\n");
+        }
+        if !self.ids.is_empty() {
+            out.push_str(&format!("use crate::stripe::ids::{{{}}};\n", join(&self.ids)));
+        }
+        if !self.params.is_empty() {
+            out.push_str(&format!("use crate::stripe::params::{{{}}};\n", join(&self.params)));
+        }
+        if !self.resources.is_empty() {
+            out.push_str(&format!("use crate::stripe::resources::{{{}}};\n", join(&self.resources)));
+        }
+        out.push_str("use serde::{Deserialize, Serialize};\n");
+
+        let items = self.main.into_iter().chain(self.structs.into_values()).chain(self.enums.into_values());
+        for item in items {
+            out.push('\n');
+            out.push_str(&item);
+        }
+        out
+    }
+}
+
+/// Render an event file: the event's `data`, with the object it is about
+///
+/// `*.updated` events also carry the attributes' previous values.
+fn render_event(event: &str, object: Option<&str>) -> String {
+    let updated = event.ends_with(".updated");
+    let mut out = format!("{}\n", BANNER);
+    if updated {
+        out.push_str("use std::collections::HashMap;\n\n");
+    }
+    if let Some(object) = object {
+        out.push_str(&format!("use crate::stripe::resources::{{{}}};\n", object));
+    }
+    out.push_str(&format!(
+        "use serde::{{Deserialize, Serialize}};\n\n/// The resource representing a Stripe \"{}\".\n\
+         #[derive(Clone, Debug, Default, Deserialize, Serialize)]\npub struct {} {{\n    \
+         /// The object the event is about.\n    pub object: {},\n",
+        event,
+        type_name(event),
+        object.unwrap_or("serde_json::Value")
+    ));
+    if updated {
+        out.push_str(
+            "\n    /// The values of the attributes that changed, as they were before the event.\n    \
+             #[serde(skip_serializing_if = \"Option::is_none\")]\n    \
+             pub previous_attributes: Option<HashMap<String, serde_json::Value>>,\n",
+        );
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// Events whose object their name does not give away: (event prefix, schema)
+const EVENT_OBJECTS: &[(&str, &str)] = &[
+    ("account.external_account.", "external_account"),
+    ("application_fee.refund.", "fee_refund"),
+    ("customer.source.", "payment_source"),
+];
+
+/// Selected object schemas, keyed by their `x-resourceId` with `.` as `_`
+fn event_objects<'a>(schemas: &Map<String, Value>, selected: &'a BTreeSet<String>) -> BTreeMap<String, &'a str> {
+    let mut objects = BTreeMap::new();
+    for name in selected {
+        if let Some(resource_id) = schemas.get(name).and_then(|schema| schema.get("x-resourceId")).and_then(Value::as_str) {
+            objects.insert(resource_id.replace('.', "_"), name.as_str());
+        }
+    }
+    for (_, schema) in EVENT_OBJECTS {
+        if let Some(name) = selected.get(*schema) {
+            objects.insert(name.clone(), name.as_str());
+        }
+    }
+    objects
+}
+
+/// The schema of the object an event is about
+///
+/// The object is named by a run of the event's segments; runs ending later
+/// win, then longer ones. `customer.subscription.created` is about a
+/// `subscription`, `issuing_authorization.request` about an `issuing.authorization`.
+fn event_object<'a>(objects: &BTreeMap<String, &'a str>, event: &str) -> Option<&'a str> {
+    if let Some((_, schema)) = EVENT_OBJECTS.iter().find(|(prefix, _)| event.starts_with(prefix)) {
+        return objects.get(*schema).copied();
+    }
+    let segments: Vec<&str> = event.split('.').collect();
+    (1..=segments.len())
+        .rev()
+        .flat_map(|end| (0..end).map(move |start| (start, end)))
+        .find_map(|(start, end)| objects.get(&segments[start..end].join("_")).copied())
+}
+
+/// Render a string enum with `as_str`, `AsRef<str>`, `Display` and `Default`
+fn render_enum(parent: &str, field: &str, name: &str, values: &[&str]) -> String {
+    let mut variants: Vec<(String, &str)> = Vec::new();
+    for value in values {
+        let ident = variant_name(value);
+        if !variants.iter().any(|(existing, _)| *existing == ident) {
+            variants.push((ident, value));
+        }
+    }
+
+    let mut out = format!(
+        "/// An enum representing the possible values of an `{}`'s `{}` field.\n\
+         #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]\n\
+         #[serde(rename_all = \"snake_case\")]\npub enum {} {{\n",
+        parent, field, name
+    );
+    for (ident, value) in &variants {
+        if snake_case(ident) != *value {
+            out.push_str(&format!("    #[serde(rename = \"{}\")]\n", value));
+        }
+        out.push_str(&format!("    {},\n", ident));
+    }
+    out.push_str(&format!("}}\n\nimpl {} {{\n    pub fn as_str(self) -> &'static str {{\n        match self {{\n", name));
+    for (ident, value) in &variants {
+        out.push_str(&format!("            {}::{} => \"{}\",\n", name, ident, value));
+    }
+    out.push_str(&format!(
+        "        }}\n    }}\n}}\n\n\
+         impl AsRef<str> for {name} {{\n    fn as_ref(&self) -> &str {{\n        self.as_str()\n    }}\n}}\n\n\
+         impl std::fmt::Display for {name} {{\n    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {{\n        self.as_str().fmt(f)\n    }}\n}}\n\n\
+         impl std::default::Default for {name} {{\n    fn default() -> Self {{\n        Self::{first}\n    }}\n}}\n",
+        name = name,
+        first = variants[0].0
+    ));
+    out
+}
+
+/// Render a description as `///` lines: the first sentence, then the rest one sentence per line
+fn doc_comment(description: Option<&Value>, indent: &str) -> String {
+    let Some(description) = description.and_then(Value::as_str) else {
+        return String::new();
+    };
+    let text = description
+        .replace("<p>", "")
+        .replace("</p>", " ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    if text.is_empty() {
+        return String::new();
+    }
+
+    let sentences = split_sentences(&text);
+    let mut out = format!("{}/// {}\n", indent, sentences[0]);
+    if sentences.len() > 1 {
+        out.push_str(&format!("{}///\n", indent));
+        for sentence in &sentences[1..] {
+            out.push_str(&format!("{}/// {}\n", indent, sentence));
+        }
+    }
+    out
+}
+
+/// Split text after every `. ` that starts a new sentence
+fn split_sentences(text: &str) -> Vec<&str> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    for (index, _) in text.match_indices(". ") {
+        let next = text[index + 2..].chars().next();
+        if next.is_some_and(|c| c.is_uppercase() || c == '[') {
+            sentences.push(&text[start..=index]);
+            start = index + 2;
+        }
+    }
+    sentences.push(&text[start..]);
+    sentences
+}
+
+/// The CamelCase type name for a schema or event (e.g. `issuing.card` -> `IssuingCard`)
+fn type_name(name: &str) -> String {
+    name.split(|c: char| !c.is_ascii_alphanumeric())
+        .map(capitalize)
+        .collect()
+}
+
+/// The enum variant name for a value (e.g. `by_id` -> `ById`)
+fn variant_name(value: &str) -> String {
+    let name = type_name(value);
+    match name.chars().next() {
+        None => "Empty".to_string(),
+        Some(c) if c.is_ascii_digit() => format!("V{}", name),
+        Some(_) => name,
+    }
+}
+
+/// How serde's `rename_all = "snake_case"` renders a variant name
+fn snake_case(ident: &str) -> String {
+    let mut snake = String::new();
+    for (i, ch) in ident.char_indices() {
+        if ch.is_uppercase() && i > 0 {
+            snake.push('_');
+        }
+        snake.push(ch.to_ascii_lowercase());
+    }
+    snake
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+fn join<T: AsRef<str>>(names: impl IntoIterator<Item = T>) -> String {
+    names.into_iter().map(|name| name.as_ref().to_string()).collect::<Vec<_>>().join(", ")
+}
+
+/// The schema a `$ref` property points at
+fn schema_ref(property: &Value) -> Option<&str> {
+    property.get("$ref")?.as_str()?.strip_prefix("#/components/schemas/")
+}
+
+/// The members of an `anyOf` / `oneOf`
+fn union_variants(schema: &Value) -> Option<Vec<Value>> {
+    schema
+        .get("anyOf")
+        .or_else(|| schema.get("oneOf"))
+        .and_then(Value::as_array)
+        .cloned()
+}
+
+/// The `object` value of a Stripe object (e.g. `billing.meter`)
+fn object_name(schema: &Value) -> Option<&str> {
+    schema.pointer("/properties/object/enum/0")?.as_str()
+}
+
+fn is_required(schema: &Value, field: &str) -> bool {
+    schema
+        .get("required")
+        .and_then(Value::as_array)
+        .is_some_and(|required| required.iter().any(|name| name == field))
+}
+
+/// A single-value enum, such as the `object` tag of every Stripe object
+fn is_constant(property: &Value) -> bool {
+    property.get("enum").and_then(Value::as_array).is_some_and(|values| values.len() == 1)
+}
+
+fn is_plain_string(schema: &Value) -> bool {
+    schema.get("type").and_then(Value::as_str) == Some("string") && schema.get("enum").is_none()
+}
+
+/// `{ type: string, enum: [""] }`, used by the spec to allow unsetting a field
+fn is_empty_string_placeholder(schema: &Value) -> bool {
+    schema
+        .get("enum")
+        .and_then(Value::as_array)
+        .is_some_and(|values| values.len() == 1 && values[0] == "")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// A spec where `customer` reaches `address` and `discount` directly, `coupon`
+    /// through `discount`, and `discount` points back at `customer`
+    fn fixture_spec() -> Spec {
+        Spec {
+            document: json!({
+                "paths": {},
+                "components": {
+                    "schemas": {
+                        "customer": {
+                            "type": "object",
+                            "x-resourceId": "customer",
+                            "required": ["id"],
+                            "properties": {
+                                "id": { "type": "string" },
+                                "object": { "type": "string", "enum": ["customer"] },
+                                "address": { "anyOf": [{ "$ref": "#/components/schemas/address" }], "nullable": true },
+                                "discount": { "anyOf": [{ "$ref": "#/components/schemas/discount" }], "nullable": true },
+                                "tax": { "$ref": "#/components/schemas/not_in_spec" }
+                            }
+                        },
+                        "address": {
+                            "type": "object",
+                            "properties": { "city": { "type": "string", "nullable": true } }
+                        },
+                        "discount": {
+                            "type": "object",
+                            "x-resourceId": "discount",
+                            "required": ["id"],
+                            "properties": {
+                                "id": { "type": "string" },
+                                "coupon": { "$ref": "#/components/schemas/coupon" },
+                                "customer": { "$ref": "#/components/schemas/customer" }
+                            }
+                        },
+                        "coupon": {
+                            "type": "object",
+                            "x-resourceId": "coupon",
+                            "required": ["id"],
+                            "properties": {
+                                "id": { "type": "string" },
+                                "duration": { "type": "string", "enum": ["forever", "once", "repeating"] }
+                            }
+                        },
+                        "unrelated": { "type": "object", "properties": {} }
+                    }
+                }
+            }),
+        }
+    }
+
+    fn file<'a>(generated: &'a Generated, name: &str) -> &'a str {
+        &generated.files.iter().find(|file| file.name == name).unwrap().content
+    }
+
+    #[test]
+    fn test_ref_closure() {
+        let spec = fixture_spec();
+        let schemas = spec.dependent_schemas(&BTreeSet::from(["customer".to_string()]));
+        assert_eq!(schemas, BTreeSet::from(["address", "coupon", "customer", "discount"].map(String::from)));
+
+        let generated = generate(&spec, &schemas, &[]);
+        let names: Vec<&str> = generated.files.iter().map(|file| file.name.as_str()).collect();
+        // `address` has a single user, so it lives in `customer.rs`
+        assert_eq!(names, ["coupon", "customer", "discount"]);
+        assert!(file(&generated, "customer").contains("pub struct Address {"));
+        assert_eq!(generated.ids, BTreeSet::from(["CouponId", "CustomerId", "DiscountId"].map(String::from)));
+    }
+
+    #[test]
+    fn test_render_enum() {
+        let rendered = render_enum("Coupon", "duration", "CouponDuration", &["forever", "once", "3d_secure", "once"]);
+        assert!(rendered.starts_with(
+            "/// An enum representing the possible values of an `Coupon`'s `duration` field.\n"
+        ));
+        assert!(rendered.contains("pub enum CouponDuration {\n    Forever,\n    Once,\n"));
+        assert_eq!(rendered.matches("    Once,\n").count(), 1);
+        assert!(rendered.contains("CouponDuration::Forever => \"forever\",\n"));
+        assert!(rendered.contains("\n}\n\nimpl std::default::Default for CouponDuration {"));
+        assert!(rendered.ends_with("        Self::Forever\n    }\n}\n"));
+
+        // Values whose snake case form differs keep their name through a rename
+        let ident = variant_name("3d_secure");
+        assert!(rendered.contains(&format!("    #[serde(rename = \"3d_secure\")]\n    {},\n", ident)));
+    }
+
+    #[test]
+    fn test_render_ids() {
+        let ids_rs = "use crate::stripe::params::Object;\n\ndef_id!(ChargeId, \"ch_\");\ndef_id!(CustomerId, \"cus_\");\n\nimpl ChargeId {}\n";
+        let ids = BTreeSet::from(["ChargeId", "CouponId", "DiscountId"].map(String::from));
+        assert_eq!(
+            render_ids(ids_rs, &ids),
+            "use crate::stripe::params::Object;\n\ndef_id!(ChargeId, \"ch_\");\ndef_id!(CustomerId, \"cus_\");\n\
+             def_id!(CouponId: String);\ndef_id!(DiscountId: String);\n\nimpl ChargeId {}\n"
+        );
+
+        let defined = BTreeSet::from(["ChargeId".to_string()]);
+        assert_eq!(render_ids(ids_rs, &defined), ids_rs);
+    }
+
+    #[test]
+    fn test_render_event_types() {
+        let webhook_events_rs = "#[derive(Default)]\npub enum EventType {\n    #[serde(rename = \"account.updated\")]\n    \
+             AccountUpdated,\n    #[serde(rename = \"charge.succeeded\")]\n    ChargeSucceededRenamed,\n    #[serde(other)]\n    \
+             #[default]\n    Unknown,\n}\n\nimpl EventType {}\n";
+        let events = ["charge.succeeded", "customer.created"].map(String::from);
+
+        let rendered = render_event_types(webhook_events_rs, &events).unwrap();
+        assert_eq!(
+            rendered,
+            "#[derive(Default)]\npub enum EventType {\n    #[serde(rename = \"charge.succeeded\")]\n    ChargeSucceededRenamed,\n    \
+             #[serde(rename = \"customer.created\")]\n    CustomerCreated,\n    #[serde(other)]\n    #[default]\n    Unknown,\n}\n\n\
+             impl EventType {}\n"
+        );
+        assert_eq!(event_variants(&rendered).len(), 2);
+
+        assert!(render_event_types("pub struct Event {}\n", &events).is_none());
+    }
+}
@@ -1,14 +1,27 @@
 use anyhow::{Context, Result, anyhow};
-use colored::Colorize;
 use std::path::{Path, PathBuf};
-use std::fs;
-use std::collections::HashSet;
+use std::collections::BTreeSet;
 
+use crate::codegen;
 use crate::components;
-use crate::utils::fs as fs_utils;
+use crate::commands::init;
+use crate::core;
+use crate::lockfile::Lockfile;
+use crate::dependencies::{self, TemplateKind};
+use crate::features;
+use crate::openapi;
+use crate::utils::changes::{ChangeSet, Mode};
+use crate::utils::fs::{self as fs_utils, Overwrite};
+use crate::utils::output::{self, Dependency, Event};
 
 /// Run the add command to add a Stripe API component
-pub fn run(component: &str, target_dir: Option<&PathBuf>, force: bool) -> Result<String> {
+pub fn run(
+    component: &str,
+    target_dir: Option<&PathBuf>,
+    force: bool,
+    mode: Mode,
+    overwrite: Overwrite,
+) -> Result<String> {
     // Check for common misuse: using "generated" as component name
     if component == "generated" {
         return Err(anyhow!(
@@ -20,57 +33,44 @@ pub fn run(component: &str, target_dir: Option<&PathBuf>, force: bool) -> Result
     let src_dir = fs_utils::find_src_directory(target_dir.map(Path::new))
         .context("Could not find the src directory. Are you in a Rust project?")?;
 
+    // Every change is planned first, then applied or previewed
+    let root_dir = src_dir.parent().unwrap_or(&src_dir).to_path_buf();
+    let mut changes = ChangeSet::new(mode, &root_dir).with_overwrite(overwrite);
+
     // Ensure the stripe directory exists
     let stripe_dir = src_dir.join("stripe");
     if !stripe_dir.exists() {
-        println!("Stripe SDK not initialized. Initializing first...");
+        output::info("Stripe SDK not initialized. Initializing first...");
         // Create the basic structure required for add command to work
-        fs::create_dir_all(&stripe_dir).context("Failed to create stripe directory")?;
-        println!(
-            "{} Created directory: {}",
-            "✓".green(),
-            stripe_dir.display()
-        );
+        changes.create_dir_all(&stripe_dir);
         
         // Create a basic lib.rs file if it doesn't exist
         let lib_path = stripe_dir.join("lib.rs");
-        if !lib_path.exists() {
-            fs::write(&lib_path, 
+        if !changes.exists(&lib_path) {
+            changes.write(&lib_path, 
                 "//! Stripe API SDK for Rust\n\n\
                  //! This module contains automatically generated Stripe API bindings.\n\n\
                  pub mod error;\n\
                  pub mod ids;\n\
                  pub mod params;\n"
-            ).context("Failed to create lib.rs")?;
-            println!("{} Created file: {}", "✓".green(), lib_path.display());
+            );
         }
     }
 
     // Create resources directory and its subdirectories if they don't exist
     let resources_dir = stripe_dir.join("resources");
-    if !resources_dir.exists() {
-        fs::create_dir_all(&resources_dir).context("Failed to create resources directory")?;
-        println!(
-            "{} Created directory: {}",
-            "✓".green(),
-            resources_dir.display()
-        );
-    }
+    changes.create_dir_all(&resources_dir);
     
     // Create the generated subdirectory for base resource definitions
     let generated_dir = resources_dir.join("generated");
-    if !generated_dir.exists() {
-        fs::create_dir_all(&generated_dir).context("Failed to create resources/generated directory")?;
-        println!(
-            "{} Created directory: {}",
-            "✓".green(),
-            generated_dir.display()
-        );
-    }
+    changes.create_dir_all(&generated_dir);
+
+    let mut lockfile = Lockfile::load(&stripe_dir)?;
 
     // Handle "all" component option
     if component == "all" {
-        return add_all_components(&stripe_dir, &resources_dir, &generated_dir, force);
+        let message = add_all_components(&mut changes, &stripe_dir, &resources_dir, &mut lockfile, force)?;
+        return finish(changes, lockfile, &root_dir, &stripe_dir, message);
     }
 
     // Validate component name
@@ -82,131 +82,210 @@ pub fn run(component: &str, target_dir: Option<&PathBuf>, force: bool) -> Result
     }
 
     // Generate and write the component file(s)
-    add_single_component(&stripe_dir, &resources_dir, &generated_dir, component, force)?;
+    add_single_component(&mut changes, &stripe_dir, component, &mut lockfile, force)?;
 
     // Update resources.rs to include the newly added component
-    update_resources_rs(&stripe_dir, force)?;
+    update_resources_rs(&mut changes, &stripe_dir, force)?;
 
-    Ok(format!("Successfully added {} component", component))
+    finish(changes, lockfile, &root_dir, &stripe_dir, format!("Successfully added {} component", component))
+}
+
+/// Enable the features the installed files use, record the lockfile, then
+/// apply or preview the planned changes
+fn finish(
+    mut changes: ChangeSet,
+    mut lockfile: Lockfile,
+    root_dir: &Path,
+    stripe_dir: &Path,
+    message: String,
+) -> Result<String> {
+    update_typed_events(&mut changes, &stripe_dir.join("resources"))?;
+
+    let enable = lockfile.files.keys().filter_map(|path| features::for_file(path)).collect();
+    init::add_dependencies(&mut changes, root_dir, &enable)?;
+
+    lockfile.stage(&mut changes, stripe_dir)?;
+
+    let preview = changes.is_preview();
+    changes.finish()?;
+
+    if preview {
+        return Ok("Dry run complete: no files were changed".to_string());
+    }
+    Ok(message)
 }
 
 /// Add a single component including both the extension and generated files
 fn add_single_component(
+    changes: &mut ChangeSet,
     stripe_dir: &Path, 
-    resources_dir: &Path, 
-    generated_dir: &Path, 
     component: &str, 
+    lockfile: &mut Lockfile,
     force: bool
 ) -> Result<()> {
     // Get the component mapping to determine which files to add
     let component_mapping = components::get_component_file_mapping(component)?;
-    
+    let mut extension_modules = Vec::new();
+    let mut generated_modules = component_mapping.generated_files.clone();
+    let mut component_files = Vec::new();
+    let mut dependency_files = Vec::new();
+
+    // Every import must be satisfied before anything is written
+    let resolution = dependencies::resolve(&component_mapping.templates());
+    if !resolution.is_complete() {
+        let missing: Vec<String> = resolution
+            .unresolved_types
+            .iter()
+            .map(|(file, type_name)| format!("{} imports {}, which no template defines", file, type_name))
+            .chain(
+                resolution
+                    .unresolved_ids
+                    .iter()
+                    .map(|(file, id)| format!("{} imports {}, which is not defined in ids.rs", file, id)),
+            )
+            .collect();
+        return Err(anyhow!(
+            "Cannot add component '{}' because its files would not compile:\n  {}",
+            component,
+            missing.join("\n  ")
+        ));
+    }
+
     // Add the extension file if it exists
     if let Some(ext_file) = &component_mapping.extension_file {
-        let ext_content = components::generate_extension_file(component, ext_file)?;
-        let ext_path = resources_dir.join(format!("{}.rs", ext_file));
+        let ext_content = components::generate_extension_file(ext_file)?;
+        let ext_path = format!("resources/{}.rs", ext_file);
         
-        fs_utils::write_file(
-            &ext_path,
-            &ext_content,
-            force,
-            &format!("stripe/resources/{}.rs", ext_file),
-        )?;
+        lockfile.write_file(changes, stripe_dir, &ext_path, &ext_content, force)?;
+        component_files.push(ext_path);
         
-        println!("{} Added extension file: {}.rs", "✓".green(), ext_file);
+        extension_modules.push(ext_file.clone());
     }
     
     // Add all the generated files
     for gen_file in &component_mapping.generated_files {
         let gen_content = components::generate_generated_file(gen_file)?;
-        let gen_path = generated_dir.join(format!("{}.rs", gen_file));
-        
-        fs_utils::write_file(
-            &gen_path,
-            &gen_content,
-            force,
-            &format!("stripe/resources/generated/{}.rs", gen_file),
-        )?;
+        let gen_path = format!("resources/generated/{}.rs", gen_file);
         
-        println!("{} Added generated file: {}.rs", "✓".green(), gen_file);
+        lockfile.write_file(changes, stripe_dir, &gen_path, &gen_content, force)?;
+        component_files.push(gen_path);
     }
 
+    // Pull in every file the component's imports depend on
+    for required in &resolution.required {
+        let path = match required.file.kind {
+            TemplateKind::Extension => {
+                extension_modules.push(required.file.name.to_string());
+                format!("resources/{}.rs", required.file.name)
+            }
+            TemplateKind::Generated => {
+                generated_modules.push(required.file.name.to_string());
+                format!("resources/generated/{}.rs", required.file.name)
+            }
+        };
+        dependency_files.push(path.clone());
+
+        // Dependencies shared with other components are only rewritten when forced
+        if changes.exists(&stripe_dir.join(&path)) && !force {
+            if !lockfile.files.contains_key(&path) {
+                lockfile.record_file(&path, required.file.content());
+            }
+            continue;
+        }
+
+        lockfile.write_file(changes, stripe_dir, &path, required.file.content(), force)?;
+        changes.set_reason(
+            &stripe_dir.join(&path),
+            format!("dependency: {} is used by {}", required.type_name, required.required_by),
+        );
+        changes.add_dependency(Dependency::Resource {
+            path: format!("src/stripe/{}", path),
+            type_name: required.type_name.clone(),
+            required_by: format!("src/stripe/resources/{}", required.required_by),
+        });
+    }
+
+    lockfile.record_component(component, component_files, dependency_files);
+    declare_resource_modules(changes, stripe_dir, &extension_modules, &generated_modules)
+}
+
+/// Declare extension and generated modules, and re-export them from resources/mod.rs
+pub fn declare_resource_modules(
+    changes: &mut ChangeSet,
+    stripe_dir: &Path,
+    extension_modules: &[String],
+    generated_modules: &[String],
+) -> Result<()> {
+    let resources_dir = stripe_dir.join("resources");
+
     // Make sure the resources directory is included in lib.rs
-    update_mod_rs(stripe_dir, "resources")?;
-    
-    // Update the resources/mod.rs file to include this component
-    update_resources_mod_rs(resources_dir, component, "generated")?;
-    
+    update_mod_rs(changes, stripe_dir, "resources")?;
+
+    // Update the resources/mod.rs file to include the extension modules
+    update_resources_mod_rs(changes, &resources_dir, extension_modules, "generated")?;
+
     // Update the resources/generated/mod.rs file to include all generated files
-    update_generated_mod_rs(generated_dir, &component_mapping.generated_files)?;
+    update_generated_mod_rs(changes, &resources_dir.join("generated"), generated_modules)?;
 
-    Ok(())
+    update_resources_rs(changes, stripe_dir, false)
 }
 
 /// Add all components
 fn add_all_components(
+    changes: &mut ChangeSet,
     stripe_dir: &Path, 
     resources_dir: &Path, 
-    generated_dir: &Path, 
+    lockfile: &mut Lockfile,
     force: bool
 ) -> Result<String> {
     let templates = components::get_all_component_templates();
     let mut added_count = 0;
     
-    println!("Adding all Stripe API components...");
+    output::info("Adding all Stripe API components...");
     
     for component in &templates {
-        match add_single_component(stripe_dir, resources_dir, generated_dir, component, force) {
+        match add_single_component(changes, stripe_dir, component, lockfile, force) {
             Ok(_) => {
-                println!("{} Added component: {}", "✓".green(), component);
                 added_count += 1;
             },
             Err(e) => {
-                println!("{} Failed to add component {}: {}", "✗".red(), component, e);
+                output::emit(Event::Error { message: &format!("Failed to add component {}: {}", component, e) });
             }
         }
     }
     
     // Also add the types.rs and generated.rs files 
-    if let Ok(types_content) = components::generate_resource_types_file() {
-        let types_path = resources_dir.join("types.rs");
-        fs_utils::write_file(
-            &types_path,
-            &types_content,
-            force,
-            "stripe/resources/types.rs",
-        )?;
-        println!("{} Added: types.rs", "✓".green());
+    if let Ok(types_content) = core::generate_resource_types_file() {
+        lockfile.write_file(changes, stripe_dir, "resources/types.rs", types_content, force)?;
     }
     
     if let Ok(gen_content) = components::generate_resource_generated_file() {
         let gen_path = resources_dir.join("generated.rs");
-        fs_utils::write_file(
+        changes.write_file(
             &gen_path,
-            &gen_content,
+            gen_content,
             force,
             "stripe/resources/generated.rs",
         )?;
-        println!("{} Added: generated.rs", "✓".green());
     }
 
     // Update resources.rs to include all components
-    update_resources_rs(stripe_dir, force)?;
+    update_resources_rs(changes, stripe_dir, force)?;
     
     Ok(format!("Successfully added {} Stripe API components", added_count))
 }
 
 /// Update the main lib.rs file to include the new module
-fn update_mod_rs(stripe_dir: &Path, module: &str) -> Result<()> {
+fn update_mod_rs(changes: &mut ChangeSet, stripe_dir: &Path, module: &str) -> Result<()> {
     let mod_path = stripe_dir.join("mod.rs");
 
-    if !mod_path.exists() {
+    if !changes.exists(&mod_path) {
         return Err(anyhow!(
             "mod.rs not found. Run 'cargo stripe init' to create core files."
         ));
     }
 
-    let mod_content = std::fs::read_to_string(&mod_path).context("Failed to read lib.rs")?;
+    let mod_content = changes.read_to_string(&mod_path).context("Failed to read lib.rs")?;
 
     // Check if module is already included
     let module_mod_line = format!("pub mod {};", module);
@@ -227,37 +306,29 @@ fn update_mod_rs(stripe_dir: &Path, module: &str) -> Result<()> {
         format!("{}\n{}", mod_content, module_mod_line)
     };
 
-    std::fs::write(&mod_path, updated_content).context("Failed to update lib.rs")?;
-
-    println!("{} Updated: {}", "✓".green(), mod_path.display());
+    changes.write(&mod_path, updated_content);
     Ok(())
 }
 
-/// Update or create the resources/mod.rs file to include the component and generated module
-fn update_resources_mod_rs(resources_dir: &Path, component: &str, submodule: &str) -> Result<()> {
+/// Update or create the resources/mod.rs file to include the component modules and generated module
+fn update_resources_mod_rs(changes: &mut ChangeSet, resources_dir: &Path, modules: &[String], submodule: &str) -> Result<()> {
     let mod_path = resources_dir.join("mod.rs");
     
     // If mod.rs doesn't exist, create it
-    let mod_content = if mod_path.exists() {
-        std::fs::read_to_string(&mod_path).context("Failed to read resources/mod.rs")?
+    let mod_content = if changes.exists(&mod_path) {
+        changes.read_to_string(&mod_path).context("Failed to read resources/mod.rs")?
     } else {
         "//! Stripe API resources\n\npub mod types;\npub mod generated;\n".to_string()
     };
     
-    // Format component name for module declaration
-    let component_module = if component.ends_with("_ext") {
-        component.trim_end_matches("_ext")
-    } else {
-        component
-    };
-    
-    // Check and add the component module
-    let module_mod_line = format!("pub mod {};", component_module);
-    let updated_content = if !mod_content.contains(&module_mod_line) {
-        add_module_to_content(&mod_content, &module_mod_line)
-    } else {
-        mod_content.clone()
-    };
+    // Check and add the component modules
+    let mut updated_content = mod_content.clone();
+    for module in modules {
+        let module_mod_line = format!("pub mod {};", module);
+        if !updated_content.contains(&module_mod_line) {
+            updated_content = add_module_to_content(&updated_content, &module_mod_line);
+        }
+    }
     
     // Check and add the submodule if needed
     let submodule_mod_line = format!("pub mod {};", submodule);
@@ -268,27 +339,26 @@ fn update_resources_mod_rs(resources_dir: &Path, component: &str, submodule: &st
     };
     
     if final_content != mod_content {
-        std::fs::write(&mod_path, final_content).context("Failed to update resources/mod.rs")?;
-        println!("{} Updated: {}", "✓".green(), mod_path.display());
+        changes.write(&mod_path, final_content);
     }
     
     Ok(())
 }
 
 /// Update or create the resources/generated/mod.rs file to include all generated files
-fn update_generated_mod_rs(generated_dir: &Path, generated_files: &[String]) -> Result<()> {
+pub fn update_generated_mod_rs(changes: &mut ChangeSet, generated_dir: &Path, generated_files: &[String]) -> Result<()> {
     let mod_path = generated_dir.join("mod.rs");
     
     // If mod.rs doesn't exist, create it
-    let mod_content = if mod_path.exists() {
-        std::fs::read_to_string(&mod_path).context("Failed to read resources/generated/mod.rs")?
+    let mod_content = if changes.exists(&mod_path) {
+        changes.read_to_string(&mod_path).context("Failed to read resources/generated/mod.rs")?
     } else {
         "//! Generated Stripe API resource definitions\n\n".to_string()
     };
     
     let mut updated_content = mod_content.clone();
     
-    // Add each generated file as a module
+    // Add each generated file as a module and re-export its types
     for file in generated_files {
         let module_name = file.trim_end_matches(".rs");
         let module_mod_line = format!("pub mod {};", module_name);
@@ -296,33 +366,80 @@ fn update_generated_mod_rs(generated_dir: &Path, generated_files: &[String]) ->
         if !updated_content.contains(&module_mod_line) {
             updated_content = add_module_to_content(&updated_content, &module_mod_line);
         }
+
+        let module_use_line = format!("pub use self::{}::*;", module_name);
+        if !updated_content.contains(&module_use_line) {
+            if !updated_content.ends_with('\n') {
+                updated_content.push('\n');
+            }
+            updated_content.push_str(&module_use_line);
+            updated_content.push('\n');
+        }
     }
     
-    if updated_content != mod_content {
-        std::fs::write(&mod_path, updated_content).context("Failed to update resources/generated/mod.rs")?;
-        println!("{} Updated: {}", "✓".green(), mod_path.display());
+    if updated_content != mod_content || !changes.exists(&mod_path) {
+        changes.write(&mod_path, updated_content);
     }
     
     Ok(())
 }
 
+/// Keep resources/typed_event.rs in step with the installed event payloads
+///
+/// The file needs `EventType` and `Event`, so it only exists while both
+/// webhook_events.rs and generated/event.rs do.
+pub fn update_typed_events(changes: &mut ChangeSet, resources_dir: &Path) -> Result<()> {
+    let webhook_events_path = resources_dir.join("webhook_events.rs");
+    let typed_event_path = resources_dir.join("typed_event.rs");
+    let generated_dir = resources_dir.join("generated");
+
+    if !changes.exists(&webhook_events_path) || !changes.exists(&generated_dir.join("event.rs")) {
+        if changes.exists(&typed_event_path) {
+            changes.remove_file(&typed_event_path);
+            let mod_path = resources_dir.join("mod.rs");
+            if changes.exists(&mod_path) {
+                let content = changes.read_to_string(&mod_path)?;
+                let updated: String = content
+                    .split_inclusive('\n')
+                    .filter(|line| !matches!(line.trim(), "pub mod typed_event;" | "pub use self::typed_event::*;"))
+                    .collect();
+                if updated != content {
+                    changes.write(&mod_path, updated);
+                }
+            }
+        }
+        return Ok(());
+    }
+
+    let webhook_events = changes.read_to_string(&webhook_events_path)?;
+    let installed = codegen::event_variants(&webhook_events)
+        .into_keys()
+        .filter(|event| changes.exists(&generated_dir.join(format!("{}.rs", openapi::schema_file_name(event)))))
+        .collect();
+
+    changes.write(&typed_event_path, codegen::render_typed_events(&webhook_events, &installed));
+    changes.set_reason(&typed_event_path, "typed payloads for the installed events");
+    update_resources_mod_rs(changes, resources_dir, &["typed_event".to_string()], "generated")?;
+    update_resources_rs(changes, resources_dir.parent().unwrap_or(resources_dir), false)
+}
+
 /// Update the resources/mod.rs file to include all components and their re-exports
-fn update_resources_rs(stripe_dir: &Path, _force: bool) -> Result<()> {
+fn update_resources_rs(changes: &mut ChangeSet, stripe_dir: &Path, _force: bool) -> Result<()> {
     let resources_dir = stripe_dir.join("resources");
-    if !resources_dir.exists() {
+    if !changes.exists(&resources_dir) {
         return Err(anyhow!("Resources directory not found. This should not happen."));
     }
     
     // Collect all component modules from resources/mod.rs
     let mod_path = resources_dir.join("mod.rs");
-    if !mod_path.exists() {
+    if !changes.exists(&mod_path) {
         return Ok(());  // No components added yet
     }
     
-    let mod_content = std::fs::read_to_string(&mod_path).context("Failed to read resources/mod.rs")?;
+    let mod_content = changes.read_to_string(&mod_path).context("Failed to read resources/mod.rs")?;
     
     // Extract all module names
-    let mut components = HashSet::new();
+    let mut components = BTreeSet::new();
     for line in mod_content.lines() {
         if line.starts_with("pub mod ") && line.ends_with(";") {
             let module = line["pub mod ".len()..line.len()-1].trim();
@@ -341,7 +458,7 @@ fn update_resources_rs(stripe_dir: &Path, _force: bool) -> Result<()> {
         // Keep all pub mod declarations
         if line.starts_with("pub mod ") {
             updated_mod_content.push_str(line);
-            updated_mod_content.push_str("\n");
+            updated_mod_content.push('\n');
         } 
         // Skip any existing re-export lines, we'll add them again below
         else if line.contains("// Re-exports") || line.starts_with("pub use self::") {
@@ -351,13 +468,13 @@ fn update_resources_rs(stripe_dir: &Path, _force: bool) -> Result<()> {
         // Keep any other content that's not a module declaration or re-export
         else if !past_modules || !line.is_empty() {
             updated_mod_content.push_str(line);
-            updated_mod_content.push_str("\n");
+            updated_mod_content.push('\n');
         }
     }
     
     // Add a blank line before re-exports if needed
     if !updated_mod_content.ends_with("\n\n") {
-        updated_mod_content.push_str("\n");
+        updated_mod_content.push('\n');
     }
     
     // Add the re-exports section
@@ -379,14 +496,12 @@ fn update_resources_rs(stripe_dir: &Path, _force: bool) -> Result<()> {
     }
     
     // Write the updated mod.rs file
-    std::fs::write(&mod_path, updated_mod_content).context("Failed to update resources/mod.rs")?;
-    println!("{} Updated: {} with re-exports", "✓".green(), mod_path.display());
+    changes.write(&mod_path, updated_mod_content);
     
     // Make sure the 'resources.rs' file doesn't exist to prevent conflicts
     let resources_rs_path = stripe_dir.join("resources.rs");
-    if resources_rs_path.exists() {
-        std::fs::remove_file(&resources_rs_path).context("Failed to remove resources.rs")?;
-        println!("{} Removed: {} to prevent module conflicts", "✓".green(), resources_rs_path.display());
+    if changes.exists(&resources_rs_path) {
+        changes.remove_file(&resources_rs_path);
     }
     
     Ok(())
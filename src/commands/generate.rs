@@ -0,0 +1,257 @@
+use anyhow::{Context, Result, anyhow};
+use colored::Colorize;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::codegen;
+use crate::commands::{add, init};
+use crate::core;
+use crate::features;
+use crate::lockfile::{LOCKFILE_NAME, Lockfile};
+use crate::openapi::{self, Spec};
+use crate::utils::changes::{ChangeSet, Mode};
+use crate::utils::fs as fs_utils;
+
+/// Run the generate command to build resource bindings from an OpenAPI spec
+///
+/// Without `resources` every schema and event in the spec is generated;
+/// otherwise only the schemas the named `/v1/<resource>` groups depend on.
+/// In a project, the generated files are declared in the resource modules
+/// like `add` does; with a lockfile they are also recorded together with the
+/// spec they came from.
+pub fn run(spec_path: &Path, out_dir: Option<&PathBuf>, resources: &[String], force: bool) -> Result<String> {
+    let spec = Spec::load(spec_path)?;
+    println!("{} Loaded OpenAPI spec: {}", "✓".green(), spec_path.display());
+
+    let stripe_dir = match out_dir {
+        Some(dir) => dir.clone(),
+        None => fs_utils::find_src_directory(None)?.join("stripe"),
+    };
+
+    let initial: BTreeSet<String> = if resources.is_empty() {
+        spec.schemas().keys().cloned().collect()
+    } else {
+        let groups = spec.resource_groups();
+        let mut initial = BTreeSet::new();
+        for resource in resources {
+            let paths = groups
+                .get(resource)
+                .ok_or_else(|| anyhow!("Unknown resource '{}': the spec has no /v1/{} paths", resource, resource))?;
+            for (_, item) in paths {
+                openapi::find_refs(item, &mut initial);
+            }
+        }
+        initial
+    };
+    let schemas = spec.dependent_schemas(&initial);
+
+    // Events are generated alongside the object they describe
+    let schema_files: BTreeSet<String> = schemas.iter().map(|name| openapi::schema_file_name(name)).collect();
+    let events: Vec<String> = spec
+        .event_types()
+        .into_iter()
+        .filter(|event| {
+            resources.is_empty()
+                || event
+                    .rsplit_once('.')
+                    .is_some_and(|(object, _)| schema_files.contains(&openapi::schema_file_name(object)))
+        })
+        .collect();
+
+    let generated = codegen::generate(&spec, &schemas, &events);
+
+    let spec_name = spec_path.display().to_string();
+
+    // Projects set up by `init` record the generated files; other output
+    // directories (such as src/templates) get no lockfile
+    let mut lockfile = if stripe_dir.join(LOCKFILE_NAME).exists() {
+        Some(Lockfile::load(&stripe_dir)?)
+    } else {
+        None
+    };
+
+    // Output into a project is declared in its modules and features the way
+    // `add` does it; other directories only get the files
+    let project_root = if out_dir.is_none() || lockfile.is_some() {
+        Some(fs_utils::find_project_root(Some(&stripe_dir))?)
+    } else {
+        None
+    };
+
+    // Writes are planned, then applied together with the lockfile
+    let root_dir = match &project_root {
+        Some(root) => root.clone(),
+        None => stripe_dir.parent().unwrap_or(&stripe_dir).to_path_buf(),
+    };
+    let mut changes = ChangeSet::new(Mode::Apply, &root_dir);
+
+    let resources_dir = stripe_dir.join("resources");
+    let generated_dir = resources_dir.join("generated");
+    changes.create_dir_all(&resources_dir);
+    changes.create_dir_all(&generated_dir);
+    for file in &generated.files {
+        let path = format!("resources/generated/{}.rs", file.name);
+        match &mut lockfile {
+            Some(lockfile) => {
+                let tracked = lockfile.files.contains_key(&path);
+                if lockfile.write_file(&mut changes, &stripe_dir, &path, &file.content, force)? || !tracked {
+                    lockfile.mark_generated(&path, &spec_name);
+                }
+            }
+            None => {
+                changes.write_file(&stripe_dir.join(&path), &file.content, force, &format!("stripe/{}", path))?;
+            }
+        }
+    }
+
+    // ids.rs, error.rs and webhook_events.rs are edited in place and stay
+    // tracked against their templates, so `update` merges the edits
+    let ids_path = stripe_dir.join("ids.rs");
+    let ids_rs = read_or_template(&ids_path, core::generate_ids_rs()?)?;
+    let updated_ids = codegen::render_ids(&ids_rs, &generated.ids);
+    if updated_ids != ids_rs || !ids_path.exists() {
+        changes.write(&ids_path, updated_ids);
+    }
+
+    let error_codes = spec.error_codes();
+    if !error_codes.is_empty() {
+        let error_path = stripe_dir.join("error.rs");
+        let error_rs = read_or_template(&error_path, core::generate_error_rs()?)?;
+        let updated = codegen::render_error_codes(&error_rs, &error_codes)
+            .filter(|updated| *updated != error_rs || !error_path.exists());
+        if let Some(updated) = updated {
+            changes.write(&error_path, updated);
+        }
+    }
+
+    if !events.is_empty() {
+        let webhook_events_path = resources_dir.join("webhook_events.rs");
+        let template = crate::embedded::resource_template("webhook_events").unwrap_or_default();
+        let webhook_events_rs = read_or_template(&webhook_events_path, template)?;
+        if let Some(updated) = codegen::render_event_types(&webhook_events_rs, &events) {
+            changes.write(&webhook_events_path, updated);
+        }
+    }
+
+    if let Some(project_root) = &project_root {
+        let webhook_events_path = resources_dir.join("webhook_events.rs");
+        let mut extension_modules = Vec::new();
+        let mut paths: Vec<String> =
+            generated.files.iter().map(|file| format!("resources/generated/{}.rs", file.name)).collect();
+        if changes.exists(&webhook_events_path) {
+            extension_modules.push("webhook_events".to_string());
+            paths.push("resources/webhook_events.rs".to_string());
+        }
+        let generated_modules: Vec<String> = generated.files.iter().map(|file| file.name.clone()).collect();
+        add::declare_resource_modules(&mut changes, &stripe_dir, &extension_modules, &generated_modules)?;
+        add::update_typed_events(&mut changes, &resources_dir)?;
+
+        let enable = paths.iter().filter_map(|path| features::for_file(path)).collect();
+        init::add_dependencies(&mut changes, project_root, &enable)?;
+    }
+
+    if let Some(lockfile) = &mut lockfile {
+        lockfile.stage(&mut changes, &stripe_dir)?;
+    }
+    changes.finish()?;
+
+    Ok(format!(
+        "Generated {} files ({} schemas, {} events) in {}",
+        generated.files.len(),
+        schemas.len(),
+        events.len(),
+        stripe_dir.display()
+    ))
+}
+
+/// Read a file that is updated in place, falling back to its template
+fn read_or_template(path: &Path, template: &str) -> Result<String> {
+    if path.exists() {
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))
+    } else {
+        Ok(template.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::update;
+
+    const SPEC: &str = r#"{
+        "openapi": "3.0.0",
+        "paths": {
+            "/v1/webhook_endpoints": {
+                "post": {
+                    "requestBody": {
+                        "content": {
+                            "application/x-www-form-urlencoded": {
+                                "schema": {
+                                    "properties": {
+                                        "enabled_events": { "items": { "enum": ["coupon.created"] } }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        },
+        "components": {
+            "schemas": {
+                "coupon": {
+                    "type": "object",
+                    "x-resourceId": "coupon",
+                    "required": ["id"],
+                    "properties": { "id": { "type": "string" } }
+                }
+            }
+        }
+    }"#;
+
+    #[test]
+    fn test_generate_records_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let stripe_dir = dir.path().join("src/stripe");
+        fs::create_dir_all(&stripe_dir).unwrap();
+        fs::write(dir.path().join("Cargo.toml"), "[package]\nname = \"host\"\n").unwrap();
+        fs::write(stripe_dir.join("mod.rs"), "pub mod client;\n").unwrap();
+        let spec_path = dir.path().join("spec.json");
+        fs::write(&spec_path, SPEC).unwrap();
+        Lockfile::default().save(&stripe_dir).unwrap();
+
+        run(&spec_path, Some(&stripe_dir), &[], false).unwrap();
+        let lockfile = Lockfile::load(&stripe_dir).unwrap();
+        let locked = &lockfile.files["resources/generated/coupon.rs"];
+        assert_eq!(locked.spec.as_deref(), Some(spec_path.display().to_string().as_str()));
+        assert!(fs::read_to_string(stripe_dir.join("ids.rs")).unwrap().contains("CouponId"));
+
+        // The generated modules are declared like `add` declares them
+        assert!(fs::read_to_string(stripe_dir.join("mod.rs")).unwrap().contains("pub mod resources;"));
+        let resources_mod_rs = fs::read_to_string(stripe_dir.join("resources/mod.rs")).unwrap();
+        assert!(resources_mod_rs.contains("pub mod generated;"));
+        assert!(resources_mod_rs.contains("pub mod webhook_events;"));
+        let generated_mod_rs = fs::read_to_string(stripe_dir.join("resources/generated/mod.rs")).unwrap();
+        assert!(generated_mod_rs.contains("pub mod coupon;"));
+        let cargo_toml = fs::read_to_string(dir.path().join("Cargo.toml")).unwrap();
+        assert!(cargo_toml.contains("webhook-events"));
+
+        // `update` keeps generated files instead of replacing them with the shipped template
+        let coupon_rs = fs::read_to_string(stripe_dir.join("resources/generated/coupon.rs")).unwrap();
+        update::run(Some(&dir.path().to_path_buf())).unwrap();
+        assert_eq!(fs::read_to_string(stripe_dir.join("resources/generated/coupon.rs")).unwrap(), coupon_rs);
+    }
+
+    #[test]
+    fn test_generate_without_lockfile() {
+        let dir = tempfile::tempdir().unwrap();
+        let spec_path = dir.path().join("spec.json");
+        fs::write(&spec_path, SPEC).unwrap();
+
+        let out_dir = dir.path().join("templates");
+        run(&spec_path, Some(&out_dir), &[], false).unwrap();
+        assert!(out_dir.join("resources/generated/coupon.rs").exists());
+        assert!(!out_dir.join(LOCKFILE_NAME).exists());
+    }
+}
@@ -1,16 +1,33 @@
 use anyhow::{Context, Result};
 use colored::Colorize;
-use std::fs;
-use std::io::Read;
+use std::collections::BTreeSet;
 use std::path::{Path, PathBuf};
 
 use crate::core;
-use crate::utils::fs as fs_utils;
+use crate::features;
+use crate::lockfile::Lockfile;
+use crate::utils::changes::{ChangeSet, Mode};
+use crate::utils::fs::{self as fs_utils, Overwrite};
+use crate::utils::output::{self, Dependency};
 
 /// Run the init command to set up the base Stripe SDK files
-pub fn run(target_dir: Option<&PathBuf>, force: bool) -> Result<String> {
+///
+/// With `blocking`, the client is set up to return results instead of futures.
+pub fn run(
+    target_dir: Option<&PathBuf>,
+    force: bool,
+    blocking: bool,
+    mode: Mode,
+    overwrite: Overwrite,
+) -> Result<String> {
     // If target directory is provided, ensure it exists and contains a Rust project
     if let Some(dir) = target_dir {
+        if mode != Mode::Apply && !dir.exists() {
+            return Err(anyhow::anyhow!(
+                "{} does not exist. Run without --dry-run/--diff to create a new project there.",
+                dir.display()
+            ));
+        }
         fs_utils::ensure_project_exists(dir)?;
     }
 
@@ -26,61 +43,61 @@ pub fn run(target_dir: Option<&PathBuf>, force: bool) -> Result<String> {
         ));
     }
 
+    // Every change is planned first, then applied or previewed
+    let mut changes = ChangeSet::new(mode, &root_dir).with_overwrite(overwrite);
+
     // Create the stripe directory if it doesn't exist
     let stripe_dir = src_dir.join("stripe");
-    if !stripe_dir.exists() {
-        fs::create_dir_all(&stripe_dir).context("Failed to create stripe directory")?;
-        println!(
-            "{} Created directory: {}",
-            "✓".green(),
-            stripe_dir.display()
-        );
-    }
+    changes.create_dir_all(&stripe_dir);
 
     // Create the client directory
     let client_dir = stripe_dir.join("client");
-    if !client_dir.exists() {
-        fs::create_dir_all(&client_dir).context("Failed to create client directory")?;
-        println!(
-            "{} Created directory: {}",
-            "✓".green(),
-            client_dir.display()
-        );
-    }
+    changes.create_dir_all(&client_dir);
 
     // Create the resources directory
     let resources_dir = stripe_dir.join("resources");
-    if !resources_dir.exists() {
-        fs::create_dir_all(&resources_dir).context("Failed to create resources directory")?;
-        println!(
-            "{} Created directory: {}",
-            "✓".green(),
-            resources_dir.display()
-        );
-    }
+    changes.create_dir_all(&resources_dir);
 
-    // Generate and write core files
-    write_core_files(&stripe_dir, &client_dir, &resources_dir, force)?;
+    // Generate and write core files, recording them in the lockfile
+    let mut lockfile = Lockfile::load(&stripe_dir)?;
+    write_core_files(&mut changes, &stripe_dir, &resources_dir, &mut lockfile, force)?;
+    lockfile.stage(&mut changes, &stripe_dir)?;
 
-    // Add the required dependencies to Cargo.toml
-    add_dependencies(&root_dir)?;
+    // Add the required dependencies and features to Cargo.toml
+    let mut enable: BTreeSet<_> = features::DEFAULT_FEATURES.iter().copied().collect();
+    if blocking {
+        enable.insert(features::BLOCKING_FEATURE);
+    }
+    if !add_dependencies(&mut changes, &root_dir, &enable)? {
+        output::info(format!(
+            "{} All required dependencies already present in Cargo.toml",
+            "✓".green()
+        ));
+    }
 
+    let preview = changes.is_preview();
+    changes.finish()?;
+
+    if preview {
+        return Ok("Dry run complete: no files were changed".to_string());
+    }
     Ok(format!(
         "Successfully initialized Stripe SDK in {}",
         stripe_dir.display()
     ))
 }
 
-/// Write all core SDK files to the project
+/// Plan writing all core SDK files to the project
 fn write_core_files(
+    changes: &mut ChangeSet,
     stripe_dir: &Path,
-    client_dir: &Path,
     resources_dir: &Path,
+    lockfile: &mut Lockfile,
     force: bool,
 ) -> Result<()> {
     // Create main files
     let lib_rs_content = core::generate_mod_rs()?;
-    fs_utils::write_file(
+    changes.write_file(
         &stripe_dir.join("mod.rs"),
         lib_rs_content,
         force,
@@ -89,38 +106,42 @@ fn write_core_files(
 
     // Create error.rs - Error handling
     let error_rs_content = core::generate_error_rs()?;
-    fs_utils::write_file(
-        &stripe_dir.join("error.rs"),
+    lockfile.write_file(
+        changes,
+        stripe_dir,
+        "error.rs",
         error_rs_content,
         force,
-        "stripe/error.rs",
     )?;
 
     // Create ids.rs - ID types
     let ids_rs_content = core::generate_ids_rs()?;
-    fs_utils::write_file(
-        &stripe_dir.join("ids.rs"),
+    lockfile.write_file(
+        changes,
+        stripe_dir,
+        "ids.rs",
         ids_rs_content,
         force,
-        "stripe/ids.rs",
     )?;
 
     // Create params.rs - Parameter types
     let params_rs_content = core::generate_params_rs()?;
-    fs_utils::write_file(
-        &stripe_dir.join("params.rs"),
+    lockfile.write_file(
+        changes,
+        stripe_dir,
+        "params.rs",
         params_rs_content,
         force,
-        "stripe/params.rs",
     )?;
 
     // Create resources/types.rs - Common types
     if let Ok(types_content) = core::generate_resource_types_file() {
-        fs_utils::write_file(
-            &resources_dir.join("types.rs"),
-            &types_content,
+        lockfile.write_file(
+            changes,
+            stripe_dir,
+            "resources/types.rs",
+            types_content,
             force,
-            "stripe/resources/types.rs",
         )?;
     }
 
@@ -131,7 +152,7 @@ fn write_core_files(
         // Re-exports\n\
         pub use self::types::*;\n";
     
-    fs_utils::write_file(
+    changes.write_file(
         &resources_dir.join("mod.rs"),
         resources_mod_content,
         force,
@@ -140,59 +161,109 @@ fn write_core_files(
     
     // Check if resources.rs exists and remove it to prevent conflicts
     let resources_rs_path = stripe_dir.join("resources.rs");
-    if resources_rs_path.exists() {
-        std::fs::remove_file(&resources_rs_path)
-            .context("Failed to remove conflicting resources.rs file")?;
-        println!(
-            "{} Removed conflicting file: {}",
-            "✓".green(),
-            resources_rs_path.display()
-        );
+    if changes.exists(&resources_rs_path) {
+        changes.remove_file(&resources_rs_path);
     }
 
     // Create client files
 
     // Create client/mod.rs - Client module
     let client_mod_rs_content = core::generate_client_mod_rs()?;
-    fs_utils::write_file(
-        &client_dir.join("mod.rs"),
+    lockfile.write_file(
+        changes,
+        stripe_dir,
+        "client/mod.rs",
         client_mod_rs_content,
         force,
-        "stripe/client/mod.rs",
+    )?;
+
+    // Create client/request_options.rs - Per-request options
+    let request_options_rs_content = core::generate_client_request_options_rs()?;
+    lockfile.write_file(
+        changes,
+        stripe_dir,
+        "client/request_options.rs",
+        request_options_rs_content,
+        force,
     )?;
 
     // Create client/request_strategy.rs - Request strategy
     let request_strategy_rs_content = core::generate_client_request_strategy_rs()?;
-    fs_utils::write_file(
-        &client_dir.join("request_strategy.rs"),
+    lockfile.write_file(
+        changes,
+        stripe_dir,
+        "client/request_strategy.rs",
         request_strategy_rs_content,
         force,
-        "stripe/client/request_strategy.rs",
+    )?;
+
+    // Create client/response_meta.rs - Response headers and status
+    let response_meta_rs_content = core::generate_client_response_meta_rs()?;
+    lockfile.write_file(
+        changes,
+        stripe_dir,
+        "client/response_meta.rs",
+        response_meta_rs_content,
+        force,
     )?;
 
     // Create client/stripe_client.rs - Stripe client
     let stripe_rs_content = core::generate_client_stripe_client_rs()?;
-    fs_utils::write_file(
-        &client_dir.join("stripe_client.rs"),
+    lockfile.write_file(
+        changes,
+        stripe_dir,
+        "client/stripe_client.rs",
         stripe_rs_content,
         force,
-        "stripe/client/stripe_client.rs",
     )?;
 
     // Create client/http_client.rs - Http client
     let httpclient_rs_content = core::generate_client_http_client_rs()?;
-    fs_utils::write_file(
-        &client_dir.join("http_client.rs"),
+    lockfile.write_file(
+        changes,
+        stripe_dir,
+        "client/http_client.rs",
         httpclient_rs_content,
         force,
-        "stripe/client/http_client.rs",
+    )?;
+
+    // Create client/http_transport.rs - Pluggable HTTP transport
+    let http_transport_rs_content = core::generate_client_http_transport_rs()?;
+    lockfile.write_file(
+        changes,
+        stripe_dir,
+        "client/http_transport.rs",
+        http_transport_rs_content,
+        force,
+    )?;
+
+    // Create client/interceptor.rs - Request and response hooks
+    let interceptor_rs_content = core::generate_client_interceptor_rs()?;
+    lockfile.write_file(
+        changes,
+        stripe_dir,
+        "client/interceptor.rs",
+        interceptor_rs_content,
+        force,
+    )?;
+
+    // Create client/multipart.rs - Multipart bodies for file uploads
+    let multipart_rs_content = core::generate_client_multipart_rs()?;
+    lockfile.write_file(
+        changes,
+        stripe_dir,
+        "client/multipart.rs",
+        multipart_rs_content,
+        force,
     )?;
 
     Ok(())
 }
 
-/// Add the required dependencies to the project's Cargo.toml
-fn add_dependencies(root_dir: &Path) -> Result<()> {
+/// Plan adding the required dependencies and template features to the project's Cargo.toml
+///
+/// Features in `enable` are turned on by default. Returns whether Cargo.toml changes.
+pub fn add_dependencies(changes: &mut ChangeSet, root_dir: &Path, enable: &BTreeSet<&'static str>) -> Result<bool> {
     let cargo_toml_path = root_dir.join("Cargo.toml");
     if !cargo_toml_path.exists() {
         return Err(anyhow::anyhow!(
@@ -201,8 +272,7 @@ fn add_dependencies(root_dir: &Path) -> Result<()> {
     }
 
     // Read the current Cargo.toml
-    let mut cargo_toml_content = String::new();
-    fs::File::open(&cargo_toml_path)?.read_to_string(&mut cargo_toml_content)?;
+    let cargo_toml_content = changes.read_to_string(&cargo_toml_path)?;
 
     // Parse the current Cargo.toml
     let mut cargo_toml: toml::Value =
@@ -210,7 +280,7 @@ fn add_dependencies(root_dir: &Path) -> Result<()> {
 
     // Define the required dependencies
     let dependencies = vec![
-        ("tokio", "1.28", Some(vec!["rt-multi-thread", "macros"])),
+        ("tokio", "1.28", Some(vec!["rt-multi-thread", "macros", "fs"])),
         ("reqwest", "0.11", Some(vec!["json", "rustls-tls"])),
         ("serde", "1.0", Some(vec!["derive"])),
         ("serde_json", "1.0", None),
@@ -239,7 +309,7 @@ fn add_dependencies(root_dir: &Path) -> Result<()> {
         .ok_or_else(|| anyhow::anyhow!("Failed to access dependencies in Cargo.toml"))?;
 
     // Add dependencies if they don't exist or update them
-    let mut added_count = 0;
+    let mut added = Vec::new();
     for (name, version, features) in dependencies {
         if !dependencies_table.contains_key(name) {
             // Add the dependency
@@ -265,29 +335,52 @@ fn add_dependencies(root_dir: &Path) -> Result<()> {
                     .insert(name.to_string(), toml::Value::String(version.to_string()));
             }
 
-            added_count += 1;
-            println!("{} Added dependency: {}", "✓".green(), name);
+            added.push(name);
         }
     }
 
+    // File uploads read files with tokio's `fs`, which an existing tokio may leave out
+    let mut tokio_features = false;
+    if let Some(tokio) = dependencies_table.get_mut("tokio") {
+        let before = tokio.clone();
+        features::add_crate_features(tokio, &["fs"]);
+        tokio_features = *tokio != before;
+    }
+
+    // Declare the features the templates use and turn on the requested ones
+    let manifest = cargo_toml
+        .as_table_mut()
+        .ok_or_else(|| anyhow::anyhow!("Failed to access Cargo.toml"))?;
+    let updated = features::update_manifest(manifest, enable);
+    added.extend(updated.dependencies);
+
+    if added.is_empty() && updated.enabled.is_empty() && !tokio_features {
+        return Ok(false);
+    }
+
     // Write the updated Cargo.toml
-    if added_count > 0 {
-        let updated_content =
-            toml::to_string(&cargo_toml).context("Failed to serialize updated Cargo.toml")?;
+    let updated_content =
+        toml::to_string(&cargo_toml).context("Failed to serialize updated Cargo.toml")?;
+    changes.write(&cargo_toml_path, updated_content);
 
-        fs::write(&cargo_toml_path, updated_content)
-            .context("Failed to write updated Cargo.toml")?;
+    let mut reasons = Vec::new();
+    if !added.is_empty() {
+        reasons.push(format!("added dependencies: {}", added.join(", ")));
+    }
+    if !updated.enabled.is_empty() {
+        reasons.push(format!("enabled features: {}", updated.enabled.join(", ")));
+    }
+    if tokio_features {
+        reasons.push("enabled tokio features: fs".to_string());
+    }
+    changes.set_reason(&cargo_toml_path, reasons.join("; "));
 
-        println!(
-            "{} Updated Cargo.toml with required dependencies",
-            "✓".green()
-        );
-    } else {
-        println!(
-            "{} All required dependencies already present in Cargo.toml",
-            "✓".green()
-        );
+    for name in added {
+        changes.add_dependency(Dependency::Crate { name: name.to_string() });
+    }
+    for name in updated.enabled {
+        changes.add_dependency(Dependency::Feature { name: name.to_string() });
     }
 
-    Ok(())
+    Ok(true)
 }
\ No newline at end of file
@@ -1,2 +1,7 @@
 pub mod add;
+pub mod generate;
 pub mod init;
+pub mod remove;
+pub mod status;
+pub mod sync_components;
+pub mod update;
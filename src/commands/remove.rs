@@ -0,0 +1,344 @@
+use anyhow::{Context, Result, anyhow};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::commands::add;
+use crate::components;
+use crate::dependencies;
+use crate::lockfile::{FileState, Lockfile};
+use crate::utils::changes::{ChangeSet, Mode};
+use crate::utils::fs as fs_utils;
+use crate::utils::output::{self, Event};
+
+/// An installed file belonging to the component being removed
+struct ComponentFile {
+    path: PathBuf,
+    /// Path relative to `src/stripe`, as recorded in the lockfile
+    lock_path: String,
+    /// Path relative to `src/`, for messages
+    relative_path: String,
+    /// Module name in the parent `mod.rs`
+    module: String,
+    /// The `mod.rs` declaring the module
+    mod_rs: PathBuf,
+}
+
+/// Run the remove command to undo `cargo stripe add <component>`
+///
+/// Dependencies the component pulled in are removed too unless another
+/// component recorded in the lockfile needs them. Files another installed
+/// component also owns (e.g. `identity` and `identity_verification_session`)
+/// are kept. Fails if another installed
+/// file imports a type only the removed files define, or if a removed file was
+/// edited since it was written, unless `force` is set, in which case the
+/// importers and edited files are listed as warnings.
+pub fn run(component: &str, target_dir: Option<&PathBuf>, force: bool, mode: Mode) -> Result<String> {
+    let src_dir = fs_utils::find_src_directory(target_dir.map(Path::new))
+        .context("Could not find the src directory. Are you in a Rust project?")?;
+    let stripe_dir = src_dir.join("stripe");
+    if !stripe_dir.exists() {
+        return Err(anyhow!("Stripe SDK not initialized. Nothing to remove."));
+    }
+
+    if !components::is_valid_component(component) {
+        return Err(anyhow!(
+            "Invalid component: '{}'. Run 'cargo stripe list' to see available components.",
+            component
+        ));
+    }
+
+    let resources_dir = stripe_dir.join("resources");
+    let generated_dir = resources_dir.join("generated");
+    let mut lockfile = Lockfile::load(&stripe_dir)?;
+
+    let paths: Vec<String> = match lockfile.components.get(component) {
+        Some(locked) => {
+            let used = lockfile.files_used_by_others(component);
+            locked
+                .files
+                .iter()
+                .chain(&locked.dependencies)
+                .filter(|path| !used.contains(path.as_str()))
+                .cloned()
+                .collect()
+        }
+        // Projects set up before the lockfile existed only know the component mapping
+        None => {
+            let mapping = components::get_component_file_mapping(component)?;
+            let ext_path = mapping.extension_file.map(|ext_file| format!("resources/{}.rs", ext_file));
+            let gen_paths = mapping
+                .generated_files
+                .iter()
+                .map(|gen_file| format!("resources/generated/{}.rs", gen_file));
+            ext_path.into_iter().chain(gen_paths).collect()
+        }
+    };
+
+    let files: Vec<ComponentFile> = paths
+        .iter()
+        .map(|path| component_file(&stripe_dir, path))
+        .filter(|file| file.path.exists())
+        .collect();
+
+    if files.is_empty() && !lockfile.is_installed(component) {
+        return Err(anyhow!("Component '{}' is not installed", component));
+    }
+
+    // Types the component provides, and the remaining files that use them
+    let mut provided: BTreeMap<String, &ComponentFile> = BTreeMap::new();
+    for file in &files {
+        let content = fs::read_to_string(&file.path)
+            .with_context(|| format!("Failed to read {}", file.path.display()))?;
+        for type_name in dependencies::defined_types(&content) {
+            provided.insert(type_name, file);
+        }
+    }
+
+    // typed_event.rs is regenerated from whatever payloads remain
+    let typed_event_path = resources_dir.join("typed_event.rs");
+    let mut removed: Vec<&Path> = files.iter().map(|file| file.path.as_path()).collect();
+    removed.push(&typed_event_path);
+    let remaining = installed_sources(&[&resources_dir, &generated_dir], &removed)?;
+    for (_, content) in &remaining {
+        for type_name in dependencies::defined_types(content) {
+            provided.remove(&type_name);
+        }
+    }
+
+    let mut users = Vec::new();
+    for (path, content) in &remaining {
+        for type_name in dependencies::imported_resources(content).all() {
+            if let Some(file) = provided.get(&type_name) {
+                users.push(format!(
+                    "{} imports {} from {}",
+                    path.strip_prefix(&src_dir).unwrap_or(path).display(),
+                    type_name,
+                    file.relative_path
+                ));
+            }
+        }
+    }
+
+    if !users.is_empty() {
+        if !force {
+            return Err(anyhow!(
+                "Cannot remove component '{}' because other installed files still use it:\n  {}\nRemove those first or pass --force.",
+                component,
+                users.join("\n  ")
+            ));
+        }
+        for user in &users {
+            output::emit(Event::Warning { message: user });
+        }
+    }
+
+    // Files edited since they were written are only deleted when forced
+    let modified: Vec<&str> = files
+        .iter()
+        .filter(|file| lockfile.file_state(&stripe_dir, &file.lock_path) == Some(FileState::Modified))
+        .map(|file| file.relative_path.as_str())
+        .collect();
+
+    if !modified.is_empty() {
+        if !force {
+            return Err(anyhow!(
+                "Cannot remove component '{}' because these files were edited since they were added:\n  {}\nPass --force to delete them anyway.",
+                component,
+                modified.join("\n  ")
+            ));
+        }
+        for path in &modified {
+            output::emit(Event::Warning { message: &format!("Removing edited file {}", path) });
+        }
+    }
+
+    // Every change is planned first, then applied or previewed
+    let root_dir = src_dir.parent().unwrap_or(&src_dir).to_path_buf();
+    let mut changes = ChangeSet::new(mode, &root_dir);
+
+    // Each mod.rs is rewritten once, without the modules of every removed file
+    let mut modules: BTreeMap<&Path, Vec<&str>> = BTreeMap::new();
+    for file in &files {
+        changes.remove_file(&file.path);
+        modules.entry(&file.mod_rs).or_default().push(&file.module);
+        lockfile.forget_file(&file.lock_path);
+    }
+    for (mod_rs, modules) in &modules {
+        remove_module_lines(&mut changes, mod_rs, modules)?;
+    }
+    lockfile.components.remove(component);
+
+    add::update_typed_events(&mut changes, &resources_dir)?;
+    lockfile.stage(&mut changes, &stripe_dir)?;
+
+    let preview = changes.is_preview();
+    changes.finish()?;
+
+    if preview {
+        return Ok("Dry run complete: no files were changed".to_string());
+    }
+    Ok(format!("Successfully removed {} component", component))
+}
+
+/// Describe a file by its path relative to `src/stripe`
+fn component_file(stripe_dir: &Path, path: &str) -> ComponentFile {
+    let full_path = stripe_dir.join(path);
+    let parent = full_path.parent().unwrap_or(stripe_dir);
+    ComponentFile {
+        module: full_path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or_default()
+            .to_string(),
+        mod_rs: parent.join("mod.rs"),
+        lock_path: path.to_string(),
+        relative_path: format!("stripe/{}", path),
+        path: full_path,
+    }
+}
+
+/// Read every `.rs` file (except `mod.rs`) in `dirs` that is not in `excluded`
+fn installed_sources(dirs: &[&Path], excluded: &[&Path]) -> Result<Vec<(PathBuf, String)>> {
+    let mut sources = Vec::new();
+    for dir in dirs {
+        if !dir.exists() {
+            continue;
+        }
+        for entry in fs::read_dir(dir).with_context(|| format!("Failed to read {}", dir.display()))? {
+            let path = entry?.path();
+            let is_source = path.extension().and_then(|ext| ext.to_str()) == Some("rs")
+                && path.file_name().and_then(|name| name.to_str()) != Some("mod.rs");
+            if is_source && !excluded.contains(&path.as_path()) {
+                let content = fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read {}", path.display()))?;
+                sources.push((path, content));
+            }
+        }
+    }
+    Ok(sources)
+}
+
+/// Plan stripping `pub mod {module};` and `pub use self::{module}::*;` from a `mod.rs`
+fn remove_module_lines(changes: &mut ChangeSet, mod_path: &Path, modules: &[&str]) -> Result<()> {
+    if !changes.exists(mod_path) {
+        return Ok(());
+    }
+
+    let content = changes.read_to_string(mod_path)?;
+    let removed: BTreeSet<String> = modules
+        .iter()
+        .flat_map(|module| [format!("pub mod {};", module), format!("pub use self::{}::*;", module)])
+        .collect();
+
+    let updated: String = content
+        .split_inclusive('\n')
+        .filter(|line| !removed.contains(line.trim()))
+        .collect();
+
+    if updated != content {
+        changes.write(mod_path, updated);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEMPLATE: &str = "pub struct Customer {}\n";
+
+    /// A project with the `customer` component installed from `TEMPLATE`
+    fn project() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        let resources_dir = dir.path().join("src/stripe/resources");
+        fs::create_dir_all(resources_dir.join("generated")).unwrap();
+        fs::write(dir.path().join("Cargo.toml"), "[package]\nname = \"host\"\n").unwrap();
+        fs::write(resources_dir.join("mod.rs"), "pub mod generated;\n").unwrap();
+        fs::write(resources_dir.join("generated/mod.rs"), "pub mod customer;\npub use self::customer::*;\n").unwrap();
+        fs::write(resources_dir.join("generated/customer.rs"), TEMPLATE).unwrap();
+
+        let stripe_dir = dir.path().join("src/stripe");
+        let mut lockfile = Lockfile::default();
+        lockfile.record_file("resources/generated/customer.rs", TEMPLATE);
+        lockfile.record_component("customer", vec!["resources/generated/customer.rs".to_string()], Vec::new());
+        lockfile.save(&stripe_dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_remove_unchanged_file() {
+        let dir = project();
+        let customer_rs = dir.path().join("src/stripe/resources/generated/customer.rs");
+
+        run("customer", Some(&dir.path().to_path_buf()), false, Mode::Apply).unwrap();
+        assert!(!customer_rs.exists());
+        assert!(!Lockfile::load(&dir.path().join("src/stripe")).unwrap().is_installed("customer"));
+    }
+
+    #[test]
+    fn test_remove_refuses_modified_file() {
+        let dir = project();
+        let customer_rs = dir.path().join("src/stripe/resources/generated/customer.rs");
+        fs::write(&customer_rs, "pub struct Customer { pub note: String }\n").unwrap();
+
+        let error = run("customer", Some(&dir.path().to_path_buf()), false, Mode::Apply).unwrap_err().to_string();
+        assert!(error.contains("stripe/resources/generated/customer.rs"), "{}", error);
+        assert!(customer_rs.exists());
+        assert!(Lockfile::load(&dir.path().join("src/stripe")).unwrap().is_installed("customer"));
+
+        run("customer", Some(&dir.path().to_path_buf()), true, Mode::Apply).unwrap();
+        assert!(!customer_rs.exists());
+    }
+
+    #[test]
+    fn test_remove_keeps_file_owned_by_another_component() {
+        let dir = project();
+        let stripe_dir = dir.path().join("src/stripe");
+        let mut lockfile = Lockfile::load(&stripe_dir).unwrap();
+        lockfile.record_component("billing", vec!["resources/generated/customer.rs".to_string()], Vec::new());
+        lockfile.save(&stripe_dir).unwrap();
+
+        run("customer", Some(&dir.path().to_path_buf()), false, Mode::Apply).unwrap();
+        assert!(stripe_dir.join("resources/generated/customer.rs").exists());
+        let lockfile = Lockfile::load(&stripe_dir).unwrap();
+        assert!(!lockfile.is_installed("customer"));
+        assert!(lockfile.is_installed("billing"));
+    }
+
+    #[test]
+    fn test_remove_dry_run_changes_nothing() {
+        let dir = project();
+        let stripe_dir = dir.path().join("src/stripe");
+        let mod_rs = fs::read_to_string(stripe_dir.join("resources/generated/mod.rs")).unwrap();
+
+        run("customer", Some(&dir.path().to_path_buf()), false, Mode::DryRun).unwrap();
+        assert!(stripe_dir.join("resources/generated/customer.rs").exists());
+        assert_eq!(fs::read_to_string(stripe_dir.join("resources/generated/mod.rs")).unwrap(), mod_rs);
+        assert!(Lockfile::load(&stripe_dir).unwrap().is_installed("customer"));
+    }
+
+    #[test]
+    fn test_remove_strips_every_module_from_mod_rs() {
+        let dir = project();
+        let stripe_dir = dir.path().join("src/stripe");
+        let generated_dir = stripe_dir.join("resources/generated");
+        let mod_rs = "pub mod customer;\npub use self::customer::*;\n\
+                      pub mod customer_session;\npub use self::customer_session::*;\n\
+                      pub mod coupon;\n";
+        fs::write(generated_dir.join("mod.rs"), mod_rs).unwrap();
+        fs::write(generated_dir.join("customer_session.rs"), "pub struct CustomerSession {}\n").unwrap();
+        let mut lockfile = Lockfile::load(&stripe_dir).unwrap();
+        let files = vec![
+            "resources/generated/customer.rs".to_string(),
+            "resources/generated/customer_session.rs".to_string(),
+        ];
+        lockfile.record_component("customer", files, Vec::new());
+        lockfile.save(&stripe_dir).unwrap();
+
+        run("customer", Some(&dir.path().to_path_buf()), false, Mode::Apply).unwrap();
+        assert!(!generated_dir.join("customer_session.rs").exists());
+        assert_eq!(fs::read_to_string(generated_dir.join("mod.rs")).unwrap(), "pub mod coupon;\n");
+    }
+}
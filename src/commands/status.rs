@@ -0,0 +1,56 @@
+use anyhow::{Context, Result, anyhow};
+use colored::Colorize;
+use std::path::{Path, PathBuf};
+
+use crate::lockfile::{FileState, LOCKFILE_NAME, Lockfile};
+use crate::utils::fs as fs_utils;
+
+/// Run the status command to show installed components and edited template files
+pub fn run(target_dir: Option<&PathBuf>) -> Result<String> {
+    let src_dir = fs_utils::find_src_directory(target_dir.map(Path::new))
+        .context("Could not find the src directory. Are you in a Rust project?")?;
+    let stripe_dir = src_dir.join("stripe");
+    if !stripe_dir.join(LOCKFILE_NAME).exists() {
+        return Err(anyhow!(
+            "No stripe/{} found. Stripe SDK not initialized, or initialized by an older cargo-stripe; rerun 'cargo stripe init' to record it.",
+            LOCKFILE_NAME
+        ));
+    }
+
+    let lockfile = Lockfile::load(&stripe_dir)?;
+    println!("{} (cargo-stripe {})", "Installed components:".bold(), lockfile.version);
+    if lockfile.components.is_empty() {
+        println!("  (none)");
+    }
+    for (name, component) in &lockfile.components {
+        println!(
+            "  • {} ({} files, {} dependencies)",
+            name,
+            component.files.len(),
+            component.dependencies.len()
+        );
+    }
+
+    let mut modified = 0;
+    let mut missing = 0;
+    let mut unchanged = 0;
+    println!("\n{}", "Template files:".bold());
+    for path in lockfile.files.keys() {
+        match lockfile.file_state(&stripe_dir, path) {
+            Some(FileState::Modified) => {
+                modified += 1;
+                println!("  {} modified: stripe/{}", "~".yellow(), path);
+            }
+            Some(FileState::Missing) => {
+                missing += 1;
+                println!("  {} missing:  stripe/{}", "✗".red(), path);
+            }
+            Some(FileState::Unchanged) | None => unchanged += 1,
+        }
+    }
+
+    Ok(format!(
+        "{} unchanged, {} modified, {} missing",
+        unchanged, modified, missing
+    ))
+}
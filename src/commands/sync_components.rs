@@ -0,0 +1,245 @@
+use anyhow::{Context, Result};
+use colored::Colorize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::components::{self, ComponentFiles, ComponentsConfig};
+use crate::dependencies;
+use crate::embedded;
+use crate::openapi::{self, Spec};
+
+/// Generated templates that are never mapped to a component
+///
+/// `notification_event_data` is replaced by the type in `webhook_events.rs`.
+const UNMAPPED_GENERATED_FILES: &[&str] = &["placeholders", "notification_event_data"];
+
+/// Run the sync-components command to regenerate the component -> file mapping
+pub fn run(spec_path: Option<&PathBuf>, output: &Path, fresh: bool) -> Result<String> {
+    let mut components = components::load_components_config()?.components;
+    if fresh {
+        // Extension-only components such as `webhook_events` cannot be derived
+        components.retain(|_, files| files.generated_files.is_empty());
+    }
+
+    // Files already mapped keep their component
+    let mut owners: BTreeMap<String, String> = BTreeMap::new();
+    for (component, files) in &components {
+        for file in &files.generated_files {
+            owners.entry(file.clone()).or_insert_with(|| component.clone());
+        }
+    }
+    let previously_mapped = owners.len();
+
+    // Split the shipped templates into event payloads and everything else
+    let mut events = BTreeMap::new();
+    let mut resources = BTreeSet::new();
+    for (name, content) in embedded::GENERATED_TEMPLATES {
+        if UNMAPPED_GENERATED_FILES.contains(name) {
+            continue;
+        }
+        match event_name(content) {
+            Some(event) => {
+                events.insert(name.to_string(), event.to_string());
+            }
+            None => {
+                resources.insert(name.to_string());
+            }
+        }
+    }
+
+    // Group resource files into components
+    let groups = match spec_path {
+        Some(path) => {
+            let spec = Spec::load(path)?;
+            println!("{} Loaded OpenAPI spec: {}", "✓".green(), path.display());
+            groups_from_spec(&spec, &resources)
+        }
+        None => groups_from_templates(&resources),
+    };
+    for (component, files) in groups {
+        for file in files {
+            assign(&mut components, &mut owners, &component, &file);
+        }
+    }
+
+    // Events follow the component that owns the object they describe
+    for (file, event) in &events {
+        if owners.contains_key(file) {
+            continue;
+        }
+        let object = event
+            .rsplit_once('.')
+            .map_or(event.as_str(), |(object, _)| object)
+            .replace('.', "_");
+        let component = owning_component(&owners, &object).unwrap_or(object);
+        assign(&mut components, &mut owners, &component, file);
+    }
+
+    // Shared helper files follow the first component that imports them
+    let importers = importers(&resources);
+    loop {
+        let mut changed = false;
+        for file in &resources {
+            if owners.contains_key(file) {
+                continue;
+            }
+            let component = importers
+                .get(file)
+                .into_iter()
+                .flatten()
+                .filter_map(|importer| owners.get(importer))
+                .min()
+                .cloned();
+            if let Some(component) = component {
+                assign(&mut components, &mut owners, &component, file);
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    // Anything nothing else imports becomes its own component
+    for file in &resources {
+        if !owners.contains_key(file) {
+            assign(&mut components, &mut owners, file, file);
+        }
+    }
+
+    let config = ComponentsConfig { components };
+    let json = serde_json::to_string_pretty(&config).context("Failed to serialize components")?;
+    fs::write(output, format!("{}\n", json))
+        .with_context(|| format!("Failed to write {}", output.display()))?;
+    println!("{} Written: {}", "✓".green(), output.display());
+
+    Ok(format!(
+        "Mapped {} generated files to {} components ({} newly mapped)",
+        owners.len(),
+        config.components.len(),
+        owners.len() - previously_mapped
+    ))
+}
+
+/// The event type a generated template represents (e.g. `issuing_card.created`)
+fn event_name(content: &str) -> Option<&str> {
+    let marker = "/// The resource representing a Stripe \"";
+    let start = content.find(marker)? + marker.len();
+    let name = &content[start..start + content[start..].find('"')?];
+
+    // Objects are named in CamelCase, events are dotted snake_case
+    if name.contains('.') && !name.contains(char::is_uppercase) {
+        Some(name)
+    } else {
+        None
+    }
+}
+
+/// Add a file to a component, creating the component if needed
+fn assign(
+    components: &mut BTreeMap<String, ComponentFiles>,
+    owners: &mut BTreeMap<String, String>,
+    component: &str,
+    file: &str,
+) {
+    if owners.contains_key(file) {
+        return;
+    }
+
+    let entry = components.entry(component.to_string()).or_insert_with(|| {
+        let ext_file = format!("{}_ext", component);
+        ComponentFiles {
+            extension_file: embedded::resource_template(&ext_file).map(|_| ext_file),
+            generated_files: Vec::new(),
+        }
+    });
+    entry.generated_files.push(file.to_string());
+    owners.insert(file.to_string(), component.to_string());
+}
+
+/// Find the component owning the longest `_`-separated prefix of `object`
+fn owning_component(owners: &BTreeMap<String, String>, object: &str) -> Option<String> {
+    let mut candidate = object;
+    loop {
+        if let Some(component) = owners.get(candidate) {
+            return Some(component.clone());
+        }
+        candidate = candidate.rsplit_once('_')?.0;
+    }
+}
+
+/// Derive component groups from the spec's `/v1/<resource>` path groups
+///
+/// Each group becomes a component named after the singular resource segment and
+/// holds the schemas its operations reference directly.
+fn groups_from_spec(spec: &Spec, resources: &BTreeSet<String>) -> BTreeMap<String, Vec<String>> {
+    let mut groups: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut grouped = BTreeSet::new();
+
+    for (resource, paths) in spec.resource_groups() {
+        let component = singular(&resource);
+
+        let mut refs = BTreeSet::new();
+        for (_, item) in &paths {
+            openapi::find_refs(item, &mut refs);
+        }
+
+        // Prefer the schema named after the group so the main object leads the list
+        let mut files: Vec<String> = refs
+            .iter()
+            .map(|schema| openapi::schema_file_name(schema))
+            .filter(|file| resources.contains(file) && !grouped.contains(file))
+            .collect();
+        files.sort_by_key(|file| (!file.starts_with(&component), file.clone()));
+
+        for file in files {
+            grouped.insert(file.clone());
+            groups.entry(component.clone()).or_default().push(file);
+        }
+    }
+
+    groups
+}
+
+/// Derive component groups from the templates themselves: one per Stripe object
+fn groups_from_templates(resources: &BTreeSet<String>) -> BTreeMap<String, Vec<String>> {
+    resources
+        .iter()
+        .filter(|file| {
+            embedded::generated_template(file).is_some_and(|content| content.contains("\nimpl Object for "))
+        })
+        .map(|file| (file.clone(), vec![file.clone()]))
+        .collect()
+}
+
+/// For every resource file, the generated files importing a type it defines
+fn importers(resources: &BTreeSet<String>) -> BTreeMap<String, BTreeSet<String>> {
+    let mut importers: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+
+    for (name, content) in embedded::GENERATED_TEMPLATES {
+        for type_name in dependencies::imported_resources(content).all() {
+            for provider in dependencies::providers(&type_name) {
+                if resources.contains(provider.name) && provider.name != *name {
+                    importers
+                        .entry(provider.name.to_string())
+                        .or_default()
+                        .insert(name.to_string());
+                }
+            }
+        }
+    }
+
+    importers
+}
+
+/// Singularize a resource path segment (e.g. `payment_intents` -> `payment_intent`)
+fn singular(resource: &str) -> String {
+    if let Some(stem) = resource.strip_suffix("ies") {
+        format!("{}y", stem)
+    } else if resource.ends_with("ss") || resource.ends_with("us") {
+        resource.to_string()
+    } else {
+        resource.strip_suffix('s').unwrap_or(resource).to_string()
+    }
+}
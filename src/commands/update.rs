@@ -0,0 +1,296 @@
+use anyhow::{Context, Result, anyhow};
+use colored::Colorize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::commands::{add, init};
+use crate::components;
+use crate::core;
+use crate::dependencies::{self, TemplateKind};
+use crate::features;
+use crate::lockfile::{FileState, LOCKFILE_NAME, Lockfile};
+use crate::utils::changes::{ChangeSet, Mode};
+use crate::utils::fs as fs_utils;
+
+/// What `update` did with a tracked file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    /// The template did not change, or the file already matches it
+    Unchanged,
+    /// The file had no local edits and was replaced with the new template
+    Updated,
+    /// Local edits and template changes were combined cleanly
+    Merged,
+    /// Local edits and template changes overlap; conflict markers were written
+    Conflicted,
+    /// The file was deleted locally and is left alone
+    Missing,
+    /// This version of cargo-stripe no longer ships the template
+    Retired,
+    /// The file was generated from an OpenAPI spec, not a shipped template
+    Generated,
+}
+
+/// Run the update command to bring installed templates up to this version
+///
+/// Each tracked file is three-way merged: the template it was written from
+/// is the base, the file on disk is "ours" and the new template is "theirs".
+/// Templates this version adds to the core files or to an installed component
+/// are written if the project does not have them yet.
+pub fn run(target_dir: Option<&PathBuf>) -> Result<String> {
+    let src_dir = fs_utils::find_src_directory(target_dir.map(Path::new))
+        .context("Could not find the src directory. Are you in a Rust project?")?;
+    let stripe_dir = src_dir.join("stripe");
+    if !stripe_dir.join(LOCKFILE_NAME).exists() {
+        return Err(anyhow!(
+            "No stripe/{} found. Stripe SDK not initialized, or initialized by an older cargo-stripe; rerun 'cargo stripe init' to record it.",
+            LOCKFILE_NAME
+        ));
+    }
+
+    let mut lockfile = Lockfile::load(&stripe_dir)?;
+    let paths: Vec<String> = lockfile.files.keys().cloned().collect();
+    let mut counts = [0usize; 4];
+
+    for path in paths {
+        let outcome = update_file(&stripe_dir, &mut lockfile, &path)?;
+        let relative_path = format!("stripe/{}", path);
+        match outcome {
+            Outcome::Unchanged => println!("  {} unchanged:  {}", "=".dimmed(), relative_path.dimmed()),
+            Outcome::Updated => println!("  {} updated:    {}", "✓".green(), relative_path),
+            Outcome::Merged => println!("  {} merged:     {}", "✓".green(), relative_path),
+            Outcome::Conflicted => println!("  {} conflicted: {}", "✗".red(), relative_path),
+            Outcome::Missing => println!("  {} missing:    {} (deleted locally, skipped)", "!".yellow(), relative_path),
+            Outcome::Retired => println!("  {} retired:    {} (no longer shipped, kept)", "!".yellow(), relative_path),
+            Outcome::Generated => println!(
+                "  {} generated:  {} (rerun 'cargo stripe generate' to refresh)",
+                "=".dimmed(),
+                relative_path.dimmed()
+            ),
+        }
+        match outcome {
+            Outcome::Unchanged => counts[0] += 1,
+            Outcome::Updated => counts[1] += 1,
+            Outcome::Merged => counts[2] += 1,
+            Outcome::Conflicted => counts[3] += 1,
+            Outcome::Missing | Outcome::Retired | Outcome::Generated => {}
+        }
+    }
+
+    let root_dir = src_dir.parent().unwrap_or(&src_dir).to_path_buf();
+    let mut changes = ChangeSet::new(Mode::Apply, &root_dir);
+    let added = add_new_files(&mut changes, &stripe_dir, &mut lockfile)?;
+    if !added.is_empty() {
+        let enable = lockfile.files.keys().filter_map(|path| features::for_file(path)).collect();
+        init::add_dependencies(&mut changes, &root_dir, &enable)?;
+    }
+    lockfile.stage(&mut changes, &stripe_dir)?;
+    changes.finish()?;
+
+    if counts[3] > 0 {
+        println!(
+            "\n{}: Resolve the <<<<<<< ours / >>>>>>> theirs markers in the conflicted files.",
+            "Hint".yellow().bold()
+        );
+    }
+
+    Ok(format!(
+        "{} unchanged, {} updated, {} merged, {} conflicted, {} added",
+        counts[0],
+        counts[1],
+        counts[2],
+        counts[3],
+        added.len()
+    ))
+}
+
+/// Plan writing the templates this version ships for what the project has
+/// installed but that it does not have yet, and return their paths
+///
+/// Core files are added once `init` has recorded any, and each installed
+/// component gets its current files and dependencies. Files that exist but
+/// are not tracked belong to the user and are left alone.
+fn add_new_files(changes: &mut ChangeSet, stripe_dir: &Path, lockfile: &mut Lockfile) -> Result<Vec<String>> {
+    let mut added = Vec::new();
+    let is_new = |lockfile: &Lockfile, changes: &ChangeSet, path: &str| {
+        !lockfile.files.contains_key(path) && !changes.exists(&stripe_dir.join(path))
+    };
+
+    if core::CORE_FILES.iter().any(|path| lockfile.files.contains_key(*path)) {
+        for path in core::CORE_FILES {
+            let Some(template) = core::template_for_path(path) else {
+                continue;
+            };
+            if is_new(lockfile, changes, path) {
+                lockfile.write_file(changes, stripe_dir, path, template, false)?;
+                added.push(path.to_string());
+            }
+        }
+    }
+
+    let resources_dir = stripe_dir.join("resources");
+    let installed: Vec<String> = lockfile.components.keys().cloned().collect();
+    for component in installed {
+        if !components::is_valid_component(&component) {
+            continue;
+        }
+        let templates = components::get_component_file_mapping(&component)?.templates();
+        let resolution = dependencies::resolve(&templates);
+        let own = templates.iter().map(|template| (template, false));
+        let dependencies = resolution.required.iter().map(|required| (&required.file, true));
+
+        let mut files = Vec::new();
+        let mut dependency_files = Vec::new();
+        let mut extension_modules = Vec::new();
+        let mut generated_modules = Vec::new();
+        for (template, is_dependency) in own.chain(dependencies) {
+            let path = match template.kind {
+                TemplateKind::Extension => format!("resources/{}.rs", template.name),
+                TemplateKind::Generated => format!("resources/generated/{}.rs", template.name),
+            };
+            if !is_new(lockfile, changes, &path) {
+                continue;
+            }
+            lockfile.write_file(changes, stripe_dir, &path, template.content(), false)?;
+            match template.kind {
+                TemplateKind::Extension => extension_modules.push(template.name.to_string()),
+                TemplateKind::Generated => generated_modules.push(template.name.to_string()),
+            }
+            if is_dependency {
+                dependency_files.push(path.clone());
+            } else {
+                files.push(path.clone());
+            }
+            added.push(path);
+        }
+
+        if !files.is_empty() || !dependency_files.is_empty() {
+            add::declare_resource_modules(changes, stripe_dir, &extension_modules, &generated_modules)?;
+            lockfile.record_component(&component, files, dependency_files);
+        }
+    }
+
+    if added.iter().any(|path| path.starts_with("resources/")) {
+        add::update_typed_events(changes, &resources_dir)?;
+    }
+    Ok(added)
+}
+
+/// Bring one tracked file up to date and record the new template as its base
+fn update_file(stripe_dir: &Path, lockfile: &mut Lockfile, path: &str) -> Result<Outcome> {
+    if lockfile.files.get(path).is_some_and(|locked| locked.spec.is_some()) {
+        return Ok(Outcome::Generated);
+    }
+    let Some(template) = core::template_for_path(path) else {
+        return Ok(Outcome::Retired);
+    };
+    let file_path = stripe_dir.join(path);
+    let Ok(current) = fs::read_to_string(&file_path) else {
+        return Ok(Outcome::Missing);
+    };
+
+    // Without a snapshot, an unedited file is its own base
+    let base = lockfile.base(stripe_dir, path).or_else(|| {
+        (lockfile.file_state(stripe_dir, path) == Some(FileState::Unchanged)).then(|| current.clone())
+    });
+
+    let (content, outcome) = match base {
+        _ if current == template => (None, Outcome::Unchanged),
+        Some(base) if base == template => (None, Outcome::Unchanged),
+        Some(base) if base == current => (Some(template.to_string()), Outcome::Updated),
+        // An unknown base makes the whole file one conflict rather than guessing
+        base => match diffy::merge(base.as_deref().unwrap_or_default(), &current, template) {
+            Ok(merged) => (Some(merged), Outcome::Merged),
+            Err(conflicted) => (Some(conflicted), Outcome::Conflicted),
+        },
+    };
+
+    if let Some(content) = content {
+        fs::write(&file_path, content)
+            .with_context(|| format!("Failed to write {}", file_path.display()))?;
+    }
+
+    // A template that did not change keeps its recorded base and version
+    if lockfile.base(stripe_dir, path).as_deref() != Some(template) {
+        lockfile.record_file(path, template);
+    }
+
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A project whose `error.rs` was written from `base` and now contains `current`
+    fn project(base: &str, current: &str) -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        let stripe_dir = dir.path().join("src/stripe");
+        fs::create_dir_all(&stripe_dir).unwrap();
+        fs::write(dir.path().join("Cargo.toml"), "[package]\nname = \"host\"\n").unwrap();
+        fs::write(stripe_dir.join("error.rs"), current).unwrap();
+
+        let mut lockfile = Lockfile::default();
+        lockfile.record_file("error.rs", base);
+        lockfile.save(&stripe_dir).unwrap();
+        dir
+    }
+
+    /// The error.rs template with its first line replaced
+    fn with_first_line(line: &str) -> String {
+        let template = core::generate_error_rs().unwrap();
+        format!("{}\n{}", line, template.split_once('\n').unwrap().1)
+    }
+
+    fn update(dir: &tempfile::TempDir) -> (Outcome, String) {
+        let stripe_dir = dir.path().join("src/stripe");
+        let mut lockfile = Lockfile::load(&stripe_dir).unwrap();
+        let outcome = update_file(&stripe_dir, &mut lockfile, "error.rs").unwrap();
+        assert_eq!(lockfile.base(&stripe_dir, "error.rs").as_deref(), core::generate_error_rs().ok());
+        (outcome, fs::read_to_string(stripe_dir.join("error.rs")).unwrap())
+    }
+
+    #[test]
+    fn test_update_unedited_file() {
+        let old = with_first_line("// old");
+        let dir = project(&old, &old);
+
+        let (outcome, content) = update(&dir);
+        assert_eq!(outcome, Outcome::Updated);
+        assert_eq!(content, core::generate_error_rs().unwrap());
+    }
+
+    #[test]
+    fn test_update_merges_edits() {
+        let old = with_first_line("// old");
+        let edited = format!("{}// local note\n", old);
+        let dir = project(&old, &edited);
+
+        let (outcome, content) = update(&dir);
+        assert_eq!(outcome, Outcome::Merged);
+        assert_eq!(content, format!("{}// local note\n", core::generate_error_rs().unwrap()));
+    }
+
+    #[test]
+    fn test_update_conflicting_edits() {
+        let dir = project(&with_first_line("// old"), &with_first_line("// ours"));
+
+        let (outcome, content) = update(&dir);
+        assert_eq!(outcome, Outcome::Conflicted);
+        assert!(content.contains("<<<<<<< ours\n// ours\n"), "{}", content);
+    }
+
+    #[test]
+    fn test_update_adds_new_core_files() {
+        let template = core::generate_error_rs().unwrap();
+        let dir = project(template, template);
+        let stripe_dir = dir.path().join("src/stripe");
+
+        run(Some(&dir.path().to_path_buf())).unwrap();
+        let request_options = fs::read_to_string(stripe_dir.join("client/request_options.rs")).unwrap();
+        assert_eq!(request_options, core::generate_client_request_options_rs().unwrap());
+        let lockfile = Lockfile::load(&stripe_dir).unwrap();
+        assert_eq!(lockfile.file_state(&stripe_dir, "client/request_options.rs"), Some(FileState::Unchanged));
+        assert_eq!(fs::read_to_string(stripe_dir.join("error.rs")).unwrap(), template);
+    }
+}
@@ -1,9 +1,9 @@
 use anyhow::{Result, anyhow};
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
-use std::fs;
-use std::path::Path;
-use std::str;
+use std::collections::{BTreeMap, HashSet};
+
+use crate::dependencies::TemplateFile;
+use crate::embedded;
 
 /// Component file mapping for both extension and generated files
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -14,34 +14,27 @@ pub struct ComponentFiles {
     pub generated_files: Vec<String>,
 }
 
+impl ComponentFiles {
+    /// The embedded templates for the component's files
+    pub fn templates(&self) -> Vec<TemplateFile> {
+        self.extension_file
+            .iter()
+            .filter_map(|name| TemplateFile::extension(name))
+            .chain(self.generated_files.iter().filter_map(|name| TemplateFile::generated(name)))
+            .collect()
+    }
+}
+
 /// JSON structure for components configuration
 #[derive(Debug, Serialize, Deserialize)]
-struct ComponentsConfig {
-    components: HashMap<String, ComponentFiles>,
+pub struct ComponentsConfig {
+    pub components: BTreeMap<String, ComponentFiles>,
 }
 
-/// Load components configuration from JSON file
-fn load_components_config() -> Result<ComponentsConfig> {
-    // Try to find the components.json file in various locations
-    let possible_paths = vec![
-        "components.json".to_string(),
-        "./components.json".to_string(),
-        "../components.json".to_string(),
-        concat!(env!("CARGO_MANIFEST_DIR"), "/components.json").to_string(),
-    ];
-
-    for path in possible_paths {
-        if Path::new(&path).exists() {
-            let content = fs::read_to_string(&path)?;
-            let config: ComponentsConfig = serde_json::from_str(&content)?;
-            return Ok(config);
-        }
-    }
-
-    // Fallback to a minimal default configuration if file can't be found
-    Ok(ComponentsConfig {
-        components: HashMap::new(),
-    })
+/// Load components configuration from the embedded `components.json`
+pub fn load_components_config() -> Result<ComponentsConfig> {
+    let config: ComponentsConfig = serde_json::from_str(embedded::COMPONENTS_JSON)?;
+    Ok(config)
 }
 
 /// List of supported resource components
@@ -89,69 +82,22 @@ pub fn get_component_file_mapping(component: &str) -> Result<ComponentFiles> {
 }
 
 /// Generate the content for a specific extension file
-pub fn generate_extension_file(component: &str, filename: &str) -> Result<String> {
-    // First check if the file exists in the templates directory
-    let template_path = format!("src/templates/resources/{}.rs", filename);
-
-    if Path::new(&template_path).exists() {
-        let content = fs::read_to_string(&template_path)?;
-        return Ok(content);
-    }
-
-    // If no template exists, generate a default one
-    Ok(format!(
-        "//! Extension methods for the Stripe {} resource\n\nuse crate::stripe::resources::generated::{};\n\n// Extension methods would be defined here\n",
-        component, component
-    ))
+pub fn generate_extension_file(filename: &str) -> Result<String> {
+    embedded::resource_template(filename)
+        .map(str::to_string)
+        .ok_or_else(|| anyhow!("No template found for extension file '{}.rs'", filename))
 }
 
 /// Generate the content for a specific generated file
 pub fn generate_generated_file(filename: &str) -> Result<String> {
-    // First check if the file exists in the templates directory
-    let template_path = format!("src/templates/resources/generated/{}.rs", filename);
-
-    if Path::new(&template_path).exists() {
-        let content = fs::read_to_string(&template_path)?;
-        return Ok(content);
-    }
-
-    // If no template exists, generate a default one
-    let resource_name = filename.replace('_', " ");
-
-    Ok(format!(
-        "//! Generated code for Stripe {} resource\n\n// Resource definition would be here\n",
-        resource_name
-    ))
-}
-
-/// Generate the content for resources/types.rs
-pub fn generate_resource_types_file() -> Result<String> {
-    let template_path = "src/templates/resources/types.rs";
-
-    if Path::new(&template_path).exists() {
-        let content = fs::read_to_string(template_path)?;
-        return Ok(content);
-    }
-
-    Ok(
-        "//! Common types used in Stripe API resources\n\n// Type definitions would be here\n"
-            .to_string(),
-    )
+    embedded::generated_template(filename)
+        .map(str::to_string)
+        .ok_or_else(|| anyhow!("No template found for generated file '{}.rs'", filename))
 }
 
 /// Generate the content for resources/generated.rs
-pub fn generate_resource_generated_file() -> Result<String> {
-    let template_path = "src/templates/resources/generated.rs";
-
-    if Path::new(&template_path).exists() {
-        let content = fs::read_to_string(template_path)?;
-        return Ok(content);
-    }
-
-    Ok(
-        "//! Re-exports all generated resource definitions\n\npub use super::generated::*;\n"
-            .to_string(),
-    )
+pub fn generate_resource_generated_file() -> Result<&'static str> {
+    Ok(include_str!("templates/resources/generated.rs"))
 }
 
 /// Get a list of all available component templates
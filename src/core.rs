@@ -1,5 +1,7 @@
 use anyhow::Result;
 
+use crate::embedded;
+
 // Main files
 
 /// Generate the content for lib.rs
@@ -36,18 +38,8 @@ pub fn generate_params_rs() -> Result<&'static str> {
 }
 
 /// Generate the content for resources/types.rs
-pub fn generate_resource_types_file() -> Result<String> {
-    let template_path = "src/templates/resources/types.rs";
-
-    if std::path::Path::new(&template_path).exists() {
-        let content = std::fs::read_to_string(template_path)?;
-        return Ok(content);
-    }
-
-    Ok(
-        "//! Common types used in Stripe API resources\n\n// Type definitions would be here\n"
-            .to_string(),
-    )
+pub fn generate_resource_types_file() -> Result<&'static str> {
+    Ok(include_str!("templates/resources/types.rs"))
 }
 
 // Client files
@@ -57,6 +49,11 @@ pub fn generate_client_mod_rs() -> Result<&'static str> {
     Ok(include_str!("templates/client/mod.rs"))
 }
 
+/// Generate the content for client/request_options.rs
+pub fn generate_client_request_options_rs() -> Result<&'static str> {
+    Ok(include_str!("templates/client/request_options.rs"))
+}
+
 /// Generate the content for client/request_strategy.rs
 pub fn generate_client_request_strategy_rs() -> Result<&'static str> {
     Ok(include_str!("templates/client/request_strategy.rs"))
@@ -71,3 +68,66 @@ pub fn generate_client_stripe_client_rs() -> Result<&'static str> {
 pub fn generate_client_http_client_rs() -> Result<&'static str> {
     Ok(include_str!("templates/client/http_client.rs"))
 }
+
+/// Generate the content for client/http_transport.rs
+pub fn generate_client_http_transport_rs() -> Result<&'static str> {
+    Ok(include_str!("templates/client/http_transport.rs"))
+}
+
+/// Generate the content for client/interceptor.rs
+pub fn generate_client_interceptor_rs() -> Result<&'static str> {
+    Ok(include_str!("templates/client/interceptor.rs"))
+}
+
+/// Generate the content for client/response_meta.rs
+pub fn generate_client_response_meta_rs() -> Result<&'static str> {
+    Ok(include_str!("templates/client/response_meta.rs"))
+}
+
+/// Generate the content for client/multipart.rs
+pub fn generate_client_multipart_rs() -> Result<&'static str> {
+    Ok(include_str!("templates/client/multipart.rs"))
+}
+
+/// The template files `init` writes and tracks, by path relative to `src/stripe`
+pub const CORE_FILES: &[&str] = &[
+    "error.rs",
+    "ids.rs",
+    "params.rs",
+    "resources/types.rs",
+    "client/mod.rs",
+    "client/request_options.rs",
+    "client/request_strategy.rs",
+    "client/stripe_client.rs",
+    "client/http_client.rs",
+    "client/http_transport.rs",
+    "client/interceptor.rs",
+    "client/response_meta.rs",
+    "client/multipart.rs",
+];
+
+/// The shipped template for a file, by its path relative to `src/stripe`
+pub fn template_for_path(path: &str) -> Option<&'static str> {
+    match path {
+        "error.rs" => generate_error_rs().ok(),
+        "ids.rs" => generate_ids_rs().ok(),
+        "params.rs" => generate_params_rs().ok(),
+        "resources/types.rs" => generate_resource_types_file().ok(),
+        "client/mod.rs" => generate_client_mod_rs().ok(),
+        "client/request_options.rs" => generate_client_request_options_rs().ok(),
+        "client/request_strategy.rs" => generate_client_request_strategy_rs().ok(),
+        "client/stripe_client.rs" => generate_client_stripe_client_rs().ok(),
+        "client/http_client.rs" => generate_client_http_client_rs().ok(),
+        "client/http_transport.rs" => generate_client_http_transport_rs().ok(),
+        "client/interceptor.rs" => generate_client_interceptor_rs().ok(),
+        "client/response_meta.rs" => generate_client_response_meta_rs().ok(),
+        "client/multipart.rs" => generate_client_multipart_rs().ok(),
+        _ => {
+            let name = path.strip_suffix(".rs")?;
+            match name.strip_prefix("resources/generated/") {
+                Some(name) => embedded::generated_template(name),
+                None => embedded::resource_template(name.strip_prefix("resources/")?),
+            }
+        }
+    }
+}
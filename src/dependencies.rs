@@ -0,0 +1,595 @@
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+
+use syn::visit::Visit;
+
+use crate::embedded;
+
+/// Resource templates that are written by `init` and therefore always present
+const CORE_RESOURCE_FILES: &[&str] = &["types"];
+
+/// The modules imports are resolved from
+const RESOURCES_MODULE: &[&str] = &["crate", "stripe", "resources"];
+const IDS_MODULE: &[&str] = &["crate", "stripe", "ids"];
+
+/// Resource templates that never provide types to other templates
+///
+/// `webhook_events.rs` replaces the generated `NotificationEventData`.
+const IGNORED_RESOURCE_FILES: &[&str] = &["generated", "placeholders", "notification_event_data"];
+
+/// Where a template file is written inside `stripe/resources`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TemplateKind {
+    /// A file in `resources/` (extension or shared resource file)
+    Extension,
+    /// A file in `resources/generated/`
+    Generated,
+}
+
+/// A single embedded template file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TemplateFile {
+    pub kind: TemplateKind,
+    pub name: &'static str,
+}
+
+impl TemplateFile {
+    /// Look up an extension template by name
+    pub fn extension(name: &str) -> Option<Self> {
+        embedded::RESOURCE_TEMPLATES
+            .iter()
+            .find(|(stem, _)| *stem == name)
+            .map(|(stem, _)| TemplateFile { kind: TemplateKind::Extension, name: stem })
+    }
+
+    /// Look up a generated template by name
+    pub fn generated(name: &str) -> Option<Self> {
+        embedded::GENERATED_TEMPLATES
+            .iter()
+            .find(|(stem, _)| *stem == name)
+            .map(|(stem, _)| TemplateFile { kind: TemplateKind::Generated, name: stem })
+    }
+
+    /// The template contents
+    pub fn content(&self) -> &'static str {
+        match self.kind {
+            TemplateKind::Extension => embedded::resource_template(self.name),
+            TemplateKind::Generated => embedded::generated_template(self.name),
+        }
+        .unwrap_or_default()
+    }
+}
+
+impl fmt::Display for TemplateFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            TemplateKind::Extension => write!(f, "{}.rs", self.name),
+            TemplateKind::Generated => write!(f, "generated/{}.rs", self.name),
+        }
+    }
+}
+
+/// A file pulled in to satisfy an import of another file
+#[derive(Debug, Clone)]
+pub struct RequiredFile {
+    pub file: TemplateFile,
+    /// The file whose import caused this one to be added
+    pub required_by: TemplateFile,
+    /// The imported type that this file provides
+    pub type_name: String,
+}
+
+/// The result of resolving the imports of a set of template files
+#[derive(Debug, Default)]
+pub struct Resolution {
+    /// Files that were not requested but are needed to compile the requested ones
+    pub required: Vec<RequiredFile>,
+    /// Imported resource types no template defines, with the importing file
+    pub unresolved_types: Vec<(TemplateFile, String)>,
+    /// Imported id types missing from `ids.rs`, with the importing file
+    pub unresolved_ids: Vec<(TemplateFile, String)>,
+}
+
+impl Resolution {
+    /// Whether every import of the resolved files is satisfied
+    pub fn is_complete(&self) -> bool {
+        self.unresolved_types.is_empty() && self.unresolved_ids.is_empty()
+    }
+}
+
+/// Index of every top-level type defined by the embedded templates
+fn type_index() -> &'static HashMap<String, Vec<TemplateFile>> {
+    static INDEX: OnceLock<HashMap<String, Vec<TemplateFile>>> = OnceLock::new();
+
+    INDEX.get_or_init(|| {
+        let mut index: HashMap<String, Vec<TemplateFile>> = HashMap::new();
+        let tables = [
+            (TemplateKind::Extension, embedded::RESOURCE_TEMPLATES),
+            (TemplateKind::Generated, embedded::GENERATED_TEMPLATES),
+        ];
+
+        for (kind, table) in tables {
+            for (name, content) in table {
+                if IGNORED_RESOURCE_FILES.contains(name) {
+                    continue;
+                }
+                let file = TemplateFile { kind, name };
+                for type_name in defined_types(content) {
+                    index.entry(type_name).or_default().push(file);
+                }
+            }
+        }
+
+        index
+    })
+}
+
+/// The template files that define a top-level type with this name
+pub fn providers(type_name: &str) -> &'static [TemplateFile] {
+    type_index().get(type_name).map(Vec::as_slice).unwrap_or_default()
+}
+
+/// Id types defined in the core `ids.rs` template
+fn known_ids() -> &'static HashSet<String> {
+    static IDS: OnceLock<HashSet<String>> = OnceLock::new();
+
+    IDS.get_or_init(|| {
+        let ids_rs = include_str!("templates/ids.rs");
+        let mut ids = HashSet::new();
+        for line in ids_rs.lines().map(str::trim_start) {
+            let name = line
+                .strip_prefix("def_id!(")
+                .or_else(|| line.strip_prefix("enum "))
+                .or_else(|| line.strip_prefix("pub struct "));
+            if let Some(name) = name {
+                ids.insert(leading_ident(name).to_string());
+            }
+        }
+        ids
+    })
+}
+
+/// Take the identifier at the start of `s`
+fn leading_ident(s: &str) -> &str {
+    let end = s
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .unwrap_or(s.len());
+    &s[..end]
+}
+
+/// Top-level `pub struct`, `pub enum` and `pub type` names defined in a source file
+pub fn defined_types(source: &str) -> Vec<String> {
+    source
+        .lines()
+        .filter_map(|line| {
+            line.strip_prefix("pub struct ")
+                .or_else(|| line.strip_prefix("pub enum "))
+                .or_else(|| line.strip_prefix("pub type "))
+        })
+        .map(leading_ident)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// The names a source file takes from one module
+#[derive(Debug, Default, PartialEq)]
+pub struct Imports {
+    /// Names imported explicitly, which must exist
+    pub named: Vec<String>,
+    /// Names the file uses that a glob import of the module may provide
+    pub glob: Vec<String>,
+}
+
+impl Imports {
+    /// Every name, explicit imports first
+    pub fn all(self) -> impl Iterator<Item = String> {
+        self.named.into_iter().chain(self.glob)
+    }
+}
+
+/// Names imported from `module` by the `use` declarations of a source file
+///
+/// The file is parsed, so imports in comments or strings are ignored and nested
+/// groups such as `use crate::stripe::{ids::A, resources::{B, C}};` are followed.
+/// A renamed import (`Foo as Bar`) counts as importing `Foo`. When the file
+/// imports `module::*`, every name it starts a path with (`Foo` in `Foo::new()`
+/// or `Option<Foo>`) is a glob candidate, unless the file defines or imports
+/// that name itself. A file that does not parse imports nothing.
+fn imported_names(source: &str, module: &[&str]) -> Imports {
+    UseCollector::parse(source).imports_from(module)
+}
+
+/// The names a file imports from `ids` and from `resources`
+#[derive(Debug, Default)]
+struct FileImports {
+    ids: Imports,
+    resources: Imports,
+}
+
+impl FileImports {
+    fn parse(source: &str) -> Self {
+        let collector = UseCollector::parse(source);
+        FileImports {
+            ids: collector.imports_from(IDS_MODULE),
+            resources: collector.imports_from(RESOURCES_MODULE),
+        }
+    }
+}
+
+/// The imports of an embedded template, parsed the first time they are needed
+fn template_imports(file: TemplateFile) -> Arc<FileImports> {
+    static CACHE: OnceLock<Mutex<HashMap<TemplateFile, Arc<FileImports>>>> = OnceLock::new();
+
+    let cache = CACHE.get_or_init(Default::default);
+    if let Some(imports) = cache.lock().unwrap_or_else(PoisonError::into_inner).get(&file) {
+        return Arc::clone(imports);
+    }
+    let imports = Arc::new(FileImports::parse(file.content()));
+    cache.lock().unwrap_or_else(PoisonError::into_inner).insert(file, Arc::clone(&imports));
+    imports
+}
+
+/// Collects what the `use` declarations of a file bring in and the names it uses
+#[derive(Default)]
+struct UseCollector {
+    /// The full path of every name brought in by a `use` declaration
+    paths: Vec<Vec<String>>,
+    /// Modules imported with `*`
+    globs: Vec<Vec<String>>,
+    /// Names of the items the file defines
+    defined: Vec<String>,
+    /// The first segment of every path the file mentions, in order
+    used: Vec<String>,
+}
+
+impl UseCollector {
+    /// Visit a source file; a file that does not parse yields nothing
+    fn parse(source: &str) -> Self {
+        let mut collector = UseCollector::default();
+        if let Ok(file) = syn::parse_file(source) {
+            collector.visit_file(&file);
+        }
+        collector
+    }
+
+    /// The names the file takes from `module`, see [`imported_names`]
+    fn imports_from(&self, module: &[&str]) -> Imports {
+        let mut imports = Imports::default();
+        let mut local: HashSet<&str> = self.defined.iter().map(String::as_str).collect();
+        for path in &self.paths {
+            let Some((name, parent)) = path.split_last() else {
+                continue;
+            };
+            if parent == module {
+                imports.named.push(name.clone());
+            }
+            local.insert(name);
+        }
+
+        if self.globs.iter().any(|glob| glob == module) {
+            let mut seen = HashSet::new();
+            imports.glob = self
+                .used
+                .iter()
+                .filter(|name| !local.contains(name.as_str()) && seen.insert(name.as_str()))
+                .cloned()
+                .collect();
+        }
+        imports
+    }
+
+    fn collect(&mut self, prefix: &mut Vec<String>, tree: &syn::UseTree) {
+        match tree {
+            syn::UseTree::Path(path) => {
+                prefix.push(path.ident.to_string());
+                self.collect(prefix, &path.tree);
+                prefix.pop();
+            }
+            syn::UseTree::Name(name) if name.ident != "self" => {
+                self.paths.push(prefix.iter().cloned().chain([name.ident.to_string()]).collect());
+            }
+            syn::UseTree::Rename(rename) => {
+                self.paths.push(prefix.iter().cloned().chain([rename.ident.to_string()]).collect());
+            }
+            syn::UseTree::Group(group) => {
+                for tree in &group.items {
+                    self.collect(prefix, tree);
+                }
+            }
+            syn::UseTree::Glob(_) => self.globs.push(prefix.clone()),
+            syn::UseTree::Name(_) => {}
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for UseCollector {
+    fn visit_item_use(&mut self, item: &'ast syn::ItemUse) {
+        self.collect(&mut Vec::new(), &item.tree);
+    }
+
+    fn visit_item(&mut self, item: &'ast syn::Item) {
+        let ident = match item {
+            syn::Item::Struct(item) => Some(&item.ident),
+            syn::Item::Enum(item) => Some(&item.ident),
+            syn::Item::Type(item) => Some(&item.ident),
+            syn::Item::Union(item) => Some(&item.ident),
+            syn::Item::Trait(item) => Some(&item.ident),
+            syn::Item::Fn(item) => Some(&item.sig.ident),
+            syn::Item::Const(item) => Some(&item.ident),
+            syn::Item::Static(item) => Some(&item.ident),
+            syn::Item::Mod(item) => Some(&item.ident),
+            _ => None,
+        };
+        self.defined.extend(ident.map(ToString::to_string));
+        syn::visit::visit_item(self, item);
+    }
+
+    fn visit_path(&mut self, path: &'ast syn::Path) {
+        if let Some(first) = path.segments.first() {
+            self.used.push(first.ident.to_string());
+        }
+        syn::visit::visit_path(self, path);
+    }
+}
+
+/// Resource types imported via `use crate::stripe::resources::...`
+pub fn imported_resources(source: &str) -> Imports {
+    imported_names(source, RESOURCES_MODULE)
+}
+
+/// Id types imported via `use crate::stripe::ids::...`
+#[cfg(test)]
+fn imported_ids(source: &str) -> Imports {
+    imported_names(source, IDS_MODULE)
+}
+
+/// Convert a CamelCase type name to the snake_case file name it would usually live in
+fn file_name_for_type(type_name: &str) -> String {
+    let mut snake = String::new();
+    for (i, ch) in type_name.chars().enumerate() {
+        if ch.is_ascii_uppercase() {
+            if i > 0 {
+                snake.push('_');
+            }
+            snake.push(ch.to_ascii_lowercase());
+        } else {
+            snake.push(ch);
+        }
+    }
+    snake
+}
+
+/// Pick the file that should provide a type when several templates define it
+fn preferred_provider(type_name: &str, providers: &[TemplateFile]) -> TemplateFile {
+    let file_name = file_name_for_type(type_name);
+    providers
+        .iter()
+        .find(|file| file.name == file_name)
+        .or_else(|| providers.iter().find(|file| file.kind == TemplateKind::Generated))
+        .copied()
+        .unwrap_or(providers[0])
+}
+
+/// Compute the closure of template files needed to compile `roots`
+///
+/// Every `use crate::stripe::resources::{...}` import is matched against the
+/// types defined by the embedded templates, and the providing files are added
+/// until no import is left unsatisfied.
+pub fn resolve(roots: &[TemplateFile]) -> Resolution {
+    let core: Vec<TemplateFile> = CORE_RESOURCE_FILES.iter().filter_map(|name| TemplateFile::extension(name)).collect();
+    resolve_in(roots, &core, type_index(), known_ids(), template_imports)
+}
+
+/// [`resolve`] against an arbitrary set of templates
+fn resolve_in(
+    roots: &[TemplateFile],
+    core: &[TemplateFile],
+    index: &HashMap<String, Vec<TemplateFile>>,
+    ids: &HashSet<String>,
+    imports: impl Fn(TemplateFile) -> Arc<FileImports>,
+) -> Resolution {
+    let mut selected: HashSet<TemplateFile> = roots.iter().chain(core).copied().collect();
+    let mut queue: VecDeque<TemplateFile> = roots.iter().copied().collect();
+    let mut required: BTreeMap<TemplateFile, RequiredFile> = BTreeMap::new();
+    let mut resolution = Resolution::default();
+
+    while let Some(file) = queue.pop_front() {
+        let imports = imports(file);
+
+        for id in &imports.ids.named {
+            if !ids.contains(id) {
+                resolution.unresolved_ids.push((file, id.clone()));
+            }
+        }
+
+        let named = imports.resources.named.iter().map(|name| (name, true));
+        let glob = imports.resources.glob.iter().map(|name| (name, false));
+        for (type_name, explicit) in named.chain(glob) {
+            let Some(providers) = index.get(type_name) else {
+                // A name used next to a glob import may come from anywhere
+                if explicit {
+                    resolution.unresolved_types.push((file, type_name.clone()));
+                }
+                continue;
+            };
+
+            if providers.iter().any(|provider| selected.contains(provider)) {
+                continue;
+            }
+
+            let provider = preferred_provider(type_name, providers);
+            selected.insert(provider);
+            queue.push_back(provider);
+            required.insert(provider, RequiredFile { file: provider, required_by: file, type_name: type_name.clone() });
+        }
+    }
+
+    resolution.required = required.into_values().collect();
+    resolution
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(name: &'static str) -> TemplateFile {
+        TemplateFile { kind: TemplateKind::Generated, name }
+    }
+
+    /// Resolve `roots` against fixture templates given as `(name, content)`
+    fn resolve_fixtures(roots: &[&'static str], templates: &[(&'static str, &'static str)]) -> Resolution {
+        let mut index: HashMap<String, Vec<TemplateFile>> = HashMap::new();
+        for (name, content) in templates {
+            for type_name in defined_types(content) {
+                index.entry(type_name).or_default().push(fixture(name));
+            }
+        }
+        let ids = HashSet::from(["CustomerId".to_string()]);
+        let roots: Vec<TemplateFile> = roots.iter().map(|name| fixture(name)).collect();
+        let imports = |file: TemplateFile| {
+            let content = templates.iter().find(|(name, _)| *name == file.name).map(|(_, content)| *content);
+            Arc::new(FileImports::parse(content.unwrap_or_default()))
+        };
+        resolve_in(&roots, &[], &index, &ids, imports)
+    }
+
+    fn required_names(resolution: &Resolution) -> Vec<&'static str> {
+        resolution.required.iter().map(|required| required.file.name).collect()
+    }
+
+    #[test]
+    fn test_multi_line_imports() {
+        let source = "use crate::stripe::resources::{\n    Address,\n    Currency as Money,\n    Customer,\n};\n";
+        assert_eq!(imported_resources(source).named, ["Address", "Currency", "Customer"]);
+    }
+
+    #[test]
+    fn test_nested_groups() {
+        let source = "use crate::stripe::{ids::{ChargeId, CustomerId}, params::Object, resources::{Charge, generated::Invoice}};\n";
+        assert_eq!(imported_resources(source).named, ["Charge"]);
+        assert_eq!(imported_ids(source).named, ["ChargeId", "CustomerId"]);
+    }
+
+    #[test]
+    fn test_ids_imports() {
+        let source = "use crate::stripe::ids::CustomerId;\nuse crate::stripe::ids::{InvoiceId, PriceId};\n";
+        assert_eq!(imported_ids(source).named, ["CustomerId", "InvoiceId", "PriceId"]);
+        assert!(imported_resources(source).named.is_empty());
+    }
+
+    #[test]
+    fn test_imports_in_comments_and_strings_are_ignored() {
+        let source = r#"
+            // use crate::stripe::resources::{Commented};
+            /// Call `use crate::stripe::resources::Documented;` first
+            const HINT: &str = "use crate::stripe::resources::{Quoted}";
+            use crate::stripe::resources::{Real, *};
+        "#;
+        assert_eq!(imported_resources(source).named, ["Real"]);
+    }
+
+    #[test]
+    fn test_imports_in_nested_modules() {
+        let source = "mod tests {\n    use crate::stripe::resources::Fixture;\n}\n";
+        assert_eq!(imported_resources(source).named, ["Fixture"]);
+    }
+
+    #[test]
+    fn test_glob_imports_use_the_names_in_the_file() {
+        let source = r#"
+            use serde_json::Value;
+            use crate::stripe::resources::*;
+
+            pub enum EventObject {
+                Account(Account),
+                Charge(Box<Charge>),
+            }
+
+            pub struct Local;
+
+            fn parse(value: Value) -> Option<Local> {
+                let _ = Customer::retrieve;
+                None
+            }
+        "#;
+        let imports = imported_resources(source);
+        assert!(imports.named.is_empty());
+        assert_eq!(imports.glob, ["Account", "Box", "Charge", "Option", "Customer", "None"]);
+    }
+
+    #[test]
+    fn test_glob_of_another_module_is_ignored() {
+        let source = "use crate::stripe::params::*;\nfn f(_: Account) {}\n";
+        assert!(imported_resources(source).glob.is_empty());
+    }
+
+    #[test]
+    fn test_resolve_glob_import() {
+        let resolution = resolve_fixtures(
+            &["a"],
+            &[
+                ("a", "use crate::stripe::resources::*;\npub struct A(B, Option<Unknown>);\n"),
+                ("b", "pub struct B;\n"),
+                ("c", "pub struct C;\n"),
+            ],
+        );
+        assert_eq!(required_names(&resolution), ["b"]);
+        assert!(resolution.is_complete());
+    }
+
+    #[test]
+    fn test_resolve_transitive_closure() {
+        let resolution = resolve_fixtures(
+            &["a"],
+            &[
+                ("a", "use crate::stripe::resources::B;\npub struct A;\n"),
+                ("b", "use crate::stripe::resources::C;\npub struct B;\n"),
+                ("c", "use crate::stripe::ids::{CustomerId, MissingId};\npub struct C;\n"),
+                ("d", "pub struct D;\n"),
+            ],
+        );
+        assert_eq!(required_names(&resolution), ["b", "c"]);
+        assert_eq!(resolution.required[1].required_by.name, "b");
+        assert_eq!(resolution.required[1].type_name, "C");
+        assert_eq!(resolution.unresolved_ids, [(fixture("c"), "MissingId".to_string())]);
+        assert!(resolution.unresolved_types.is_empty());
+    }
+
+    #[test]
+    fn test_resolve_cycle() {
+        let resolution = resolve_fixtures(
+            &["a"],
+            &[
+                ("a", "use crate::stripe::resources::B;\npub struct A;\n"),
+                ("b", "use crate::stripe::resources::{A, Unknown};\npub struct B;\n"),
+            ],
+        );
+        assert_eq!(required_names(&resolution), ["b"]);
+        assert_eq!(resolution.unresolved_types, [(fixture("b"), "Unknown".to_string())]);
+        assert!(!resolution.is_complete());
+    }
+
+    #[test]
+    fn test_every_component_resolves() {
+        for component in crate::components::get_all_component_templates() {
+            let mapping = crate::components::get_component_file_mapping(&component).unwrap();
+            let resolution = resolve(&mapping.templates());
+            assert!(
+                resolution.is_complete(),
+                "{}: {:?} {:?}",
+                component,
+                resolution.unresolved_types,
+                resolution.unresolved_ids
+            );
+        }
+    }
+
+    #[test]
+    fn test_every_template_parses() {
+        for (name, content) in embedded::RESOURCE_TEMPLATES.iter().chain(embedded::GENERATED_TEMPLATES) {
+            assert!(syn::parse_file(content).is_ok(), "{} does not parse", name);
+        }
+    }
+}
@@ -0,0 +1,27 @@
+//! Resource templates compiled into the binary.
+//!
+//! The tables are generated by `build.rs` from `src/templates/resources`, so an
+//! installed `cargo-stripe` never depends on the current working directory.
+
+include!(concat!(env!("OUT_DIR"), "/embedded_templates.rs"));
+
+/// The `components.json` mapping the binary was built with
+pub static COMPONENTS_JSON: &str = include_str!("../components.json");
+
+/// Look up a template by name in one of the sorted tables
+fn lookup(table: &'static [(&'static str, &'static str)], name: &str) -> Option<&'static str> {
+    table
+        .binary_search_by(|(stem, _)| (*stem).cmp(name))
+        .ok()
+        .map(|index| table[index].1)
+}
+
+/// Get the template for a file in `resources/` (e.g. `customer_ext`)
+pub fn resource_template(name: &str) -> Option<&'static str> {
+    lookup(RESOURCE_TEMPLATES, name)
+}
+
+/// Get the template for a file in `resources/generated/` (e.g. `customer`)
+pub fn generated_template(name: &str) -> Option<&'static str> {
+    lookup(GENERATED_TEMPLATES, name)
+}
@@ -0,0 +1,247 @@
+//! Cargo features the templates gate code behind.
+//!
+//! The generated code lives in the host crate, so its `#[cfg(feature = ...)]`
+//! gates refer to the host's own `[features]`. `init` and `add` declare every
+//! feature (with the optional dependencies they need) and turn on the ones the
+//! installed files use through `default`.
+
+use std::collections::{BTreeSet, HashMap};
+use std::sync::OnceLock;
+
+use crate::embedded;
+
+/// Dependencies only needed by some features: (name, version, crate features)
+const OPTIONAL_DEPENDENCIES: &[(&str, &str, &[&str])] = &[
+    ("hmac", "0.12", &[]),
+    ("sha2", "0.10", &[]),
+    ("hex", "0.4", &[]),
+    ("chrono", "0.4", &[]),
+    ("uuid", "1.0", &["v4"]),
+    ("tracing", "0.1", &[]),
+];
+
+/// Every feature the templates use, and what each one turns on
+const FEATURES: &[(&str, &[&str])] = &[
+    ("webhook-events", &["dep:hmac", "dep:sha2", "dep:hex", "dep:chrono"]),
+    ("stream", &[]),
+    ("blocking", &[]),
+    ("uuid", &["dep:uuid"]),
+    ("tracing", &["dep:tracing"]),
+    ("checkout", &[]),
+    ("billing", &[]),
+    ("connect", &[]),
+    ("issuing", &[]),
+    ("terminal", &[]),
+    ("fraud", &[]),
+    ("orders", &[]),
+    ("sigma", &[]),
+    ("events", &[]),
+    ("tax-calculation", &[]),
+    ("webhook-endpoints", &[]),
+    (
+        "full",
+        &[
+            "webhook-events",
+            "stream",
+            "uuid",
+            "tracing",
+            "checkout",
+            "billing",
+            "connect",
+            "issuing",
+            "terminal",
+            "fraud",
+            "orders",
+            "sigma",
+            "events",
+            "tax-calculation",
+            "webhook-endpoints",
+        ],
+    ),
+];
+
+/// Features turned on by `init`, for code in the core files
+pub const DEFAULT_FEATURES: &[&str] = &["stream"];
+
+/// The feature `init --blocking` turns on for a synchronous client
+///
+/// It is left out of `full`, since it changes what every request returns.
+pub const BLOCKING_FEATURE: &str = "blocking";
+
+/// Generated modules grouped under a feature in the `resources/generated.rs` template
+fn module_features() -> &'static HashMap<&'static str, &'static str> {
+    static MODULES: OnceLock<HashMap<&'static str, &'static str>> = OnceLock::new();
+
+    MODULES.get_or_init(|| {
+        let mut modules = HashMap::new();
+        let mut feature = None;
+        let mut in_group = false;
+        for line in embedded::resource_template("generated").unwrap_or_default().lines().map(str::trim) {
+            if let Some(name) = line.strip_prefix("#[cfg(feature = \"").and_then(|rest| rest.strip_suffix("\")]")) {
+                feature = Some(name);
+            } else if line == "}" {
+                feature = None;
+                in_group = false;
+            } else if let Some(module) = line.strip_prefix("pub mod ") {
+                match (module.strip_suffix(" {"), module.strip_suffix(';')) {
+                    (Some(_), _) => in_group = feature.is_some(),
+                    (_, Some(module)) => {
+                        if let Some(feature) = feature {
+                            modules.insert(module, feature);
+                        }
+                        // A gate on a single module ends with it
+                        if !in_group {
+                            feature = None;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        modules
+    })
+}
+
+/// The feature a file (relative to `src/stripe`) needs, if any
+pub fn for_file(path: &str) -> Option<&'static str> {
+    if path == "resources/webhook_events.rs" {
+        return Some("webhook-events");
+    }
+    let module = path.strip_prefix("resources/generated/")?.strip_suffix(".rs")?;
+    module_features().get(module).copied()
+}
+
+/// What [`update_manifest`] added to a `Cargo.toml`
+#[derive(Debug, Default)]
+pub struct ManifestChanges {
+    pub dependencies: Vec<&'static str>,
+    pub enabled: Vec<&'static str>,
+}
+
+/// Declare the features and optional dependencies, and enable `enable` by default
+///
+/// Features the project already defines are left as they are. A dependency
+/// the project already has as a regular one stays regular, and the features
+/// leave out its `dep:` entry, since it is always built.
+pub fn update_manifest(manifest: &mut toml::value::Table, enable: &BTreeSet<&'static str>) -> ManifestChanges {
+    let mut changes = ManifestChanges::default();
+
+    let dependencies = table(manifest, "dependencies");
+    let mut required = BTreeSet::new();
+    for (name, version, features) in OPTIONAL_DEPENDENCIES {
+        if let Some(existing) = dependencies.get_mut(*name) {
+            let optional = existing.get("optional").and_then(toml::Value::as_bool).unwrap_or(false);
+            if !optional {
+                required.insert(format!("dep:{}", name));
+            }
+            add_crate_features(existing, features);
+            continue;
+        }
+        let mut dependency = toml::value::Table::new();
+        dependency.insert("version".to_string(), toml::Value::String(version.to_string()));
+        dependency.insert("optional".to_string(), toml::Value::Boolean(true));
+        if !features.is_empty() {
+            dependency.insert("features".to_string(), string_array(features.iter().copied()));
+        }
+        dependencies.insert(name.to_string(), toml::Value::Table(dependency));
+        changes.dependencies.push(name);
+    }
+
+    let features = table(manifest, "features");
+    for (name, enables) in FEATURES {
+        features
+            .entry(name.to_string())
+            .or_insert_with(|| string_array(enables.iter().copied().filter(|entry| !required.contains(*entry))));
+    }
+
+    let default = features
+        .entry("default".to_string())
+        .or_insert_with(|| toml::Value::Array(Vec::new()));
+    if let toml::Value::Array(default) = default {
+        for name in enable {
+            if !default.iter().any(|value| value.as_str() == Some(name)) {
+                default.push(toml::Value::String(name.to_string()));
+                changes.enabled.push(name);
+            }
+        }
+    }
+
+    changes
+}
+
+/// Turn on `features` of an existing dependency, given as a version string or a table
+pub fn add_crate_features(dependency: &mut toml::Value, features: &[&str]) {
+    if features.is_empty() {
+        return;
+    }
+    if let toml::Value::String(version) = dependency {
+        let mut table = toml::value::Table::new();
+        table.insert("version".to_string(), toml::Value::String(version.clone()));
+        *dependency = toml::Value::Table(table);
+    }
+    let Some(table) = dependency.as_table_mut() else {
+        return;
+    };
+    let enabled = table
+        .entry("features".to_string())
+        .or_insert_with(|| toml::Value::Array(Vec::new()));
+    if let toml::Value::Array(enabled) = enabled {
+        for feature in features {
+            if !enabled.iter().any(|value| value.as_str() == Some(feature)) {
+                enabled.push(toml::Value::String(feature.to_string()));
+            }
+        }
+    }
+}
+
+/// Get or create a sub-table
+fn table<'a>(manifest: &'a mut toml::value::Table, name: &str) -> &'a mut toml::value::Table {
+    let value = manifest
+        .entry(name.to_string())
+        .or_insert_with(|| toml::Value::Table(toml::value::Table::new()));
+    if !value.is_table() {
+        *value = toml::Value::Table(toml::value::Table::new());
+    }
+    value.as_table_mut().expect("just made a table")
+}
+
+fn string_array<'a>(items: impl Iterator<Item = &'a str>) -> toml::Value {
+    toml::Value::Array(items.map(|item| toml::Value::String(item.to_string())).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_existing_dependencies_stay_regular() {
+        let mut manifest: toml::value::Table = toml::from_str(
+            r#"
+            [package]
+            name = "host"
+
+            [dependencies]
+            chrono = "0.4"
+            uuid = "1"
+            hex = { version = "0.4", optional = true }
+            "#,
+        )
+        .unwrap();
+
+        let changes = update_manifest(&mut manifest, &BTreeSet::new());
+        assert_eq!(changes.dependencies, ["hmac", "sha2", "tracing"]);
+
+        let dependencies = manifest["dependencies"].as_table().unwrap();
+        assert_eq!(dependencies["chrono"].as_str(), Some("0.4"));
+        assert!(dependencies["uuid"].get("optional").is_none());
+        assert_eq!(dependencies["uuid"]["features"].as_array().unwrap()[0].as_str(), Some("v4"));
+
+        let features = manifest["features"].as_table().unwrap();
+        let enables = |name: &str| -> Vec<&str> {
+            features[name].as_array().unwrap().iter().filter_map(toml::Value::as_str).collect()
+        };
+        assert_eq!(enables("webhook-events"), ["dep:hmac", "dep:sha2", "dep:hex"]);
+        assert!(enables("uuid").is_empty());
+        assert_eq!(enables("tracing"), ["dep:tracing"]);
+    }
+}
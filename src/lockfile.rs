@@ -0,0 +1,300 @@
+//! The `src/stripe/.cargo-stripe.toml` lockfile.
+//!
+//! `init` and `add` record every template file they write, together with the
+//! cargo-stripe version it came from and a hash of the written content, so later
+//! commands can tell which components are installed and which files were edited.
+//! The written template itself is kept in `src/stripe/.cargo-stripe/base/` as the
+//! merge base for `update`.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+
+use crate::utils::changes::ChangeSet;
+
+/// File name of the lockfile inside `src/stripe`
+pub const LOCKFILE_NAME: &str = ".cargo-stripe.toml";
+
+/// Directory inside `src/stripe` holding the template each tracked file was written from
+const BASE_DIR: &str = ".cargo-stripe/base";
+
+/// The version of the templates embedded in this binary
+pub const TEMPLATE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+const HEADER: &str = "# This file is maintained by cargo-stripe. Do not edit it by hand.\n\n";
+
+/// Installed components and template files of a project
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Lockfile {
+    /// cargo-stripe version that last wrote the lockfile
+    pub version: String,
+
+    #[serde(default)]
+    pub components: BTreeMap<String, LockedComponent>,
+
+    /// Template files by path relative to `src/stripe`
+    #[serde(default)]
+    pub files: BTreeMap<String, LockedFile>,
+
+    /// Base snapshots to write on `save`, by path
+    #[serde(skip)]
+    pending_bases: BTreeMap<String, String>,
+
+    /// Files no longer tracked whose snapshots `save` deletes
+    #[serde(skip)]
+    forgotten: BTreeSet<String>,
+}
+
+/// An installed component
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct LockedComponent {
+    /// The component's own extension and generated files
+    #[serde(default)]
+    pub files: Vec<String>,
+
+    /// Files added because the component's files import types from them
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dependencies: Vec<String>,
+}
+
+/// A template file written into the project
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockedFile {
+    /// cargo-stripe version whose template was written
+    pub template_version: String,
+
+    /// `sha256:<hex>` of the template the file was written from
+    pub hash: String,
+
+    /// The OpenAPI spec `generate` wrote the file from, instead of a shipped template
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spec: Option<String>,
+}
+
+/// How a tracked file compares to what was written
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileState {
+    Unchanged,
+    Modified,
+    Missing,
+}
+
+impl Lockfile {
+    /// Load the lockfile from `stripe_dir`, or an empty one if there is none
+    pub fn load(stripe_dir: &Path) -> Result<Self> {
+        let path = stripe_dir.join(LOCKFILE_NAME);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        toml::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    /// Write the lockfile and any new base snapshots to `stripe_dir`
+    ///
+    /// Commands stage the lockfile with their other changes instead.
+    #[cfg(test)]
+    pub fn save(&mut self, stripe_dir: &Path) -> Result<()> {
+        let mut changes = ChangeSet::new(crate::utils::changes::Mode::Apply, stripe_dir);
+        self.stage(&mut changes, stripe_dir)?;
+        changes.finish()
+    }
+
+    /// Plan writing the lockfile and any new base snapshots to `stripe_dir`
+    pub fn stage(&mut self, changes: &mut ChangeSet, stripe_dir: &Path) -> Result<()> {
+        let base_dir = stripe_dir.join(BASE_DIR);
+        for (path, content) in std::mem::take(&mut self.pending_bases) {
+            changes.write_hidden(&base_dir.join(path), content);
+        }
+        for path in std::mem::take(&mut self.forgotten) {
+            changes.remove_file_hidden(&base_dir.join(path));
+        }
+
+        self.version = TEMPLATE_VERSION.to_string();
+        let content = toml::to_string_pretty(self).context("Failed to serialize the lockfile")?;
+        changes.write_hidden(&stripe_dir.join(LOCKFILE_NAME), format!("{}{}", HEADER, content));
+        Ok(())
+    }
+
+    /// Record a template file written from `content`
+    pub fn record_file(&mut self, path: &str, content: &str) {
+        self.files.insert(
+            path.to_string(),
+            LockedFile { template_version: TEMPLATE_VERSION.to_string(), hash: hash(content), spec: None },
+        );
+        self.forgotten.remove(path);
+        self.pending_bases.insert(path.to_string(), content.to_string());
+    }
+
+    /// Mark a tracked file as generated from `spec`, so `update` leaves it alone
+    pub fn mark_generated(&mut self, path: &str, spec: &str) {
+        if let Some(locked) = self.files.get_mut(path) {
+            locked.spec = Some(spec.to_string());
+        }
+    }
+
+    /// Stop tracking a file and drop its base snapshot
+    pub fn forget_file(&mut self, path: &str) {
+        self.files.remove(path);
+        self.pending_bases.remove(path);
+        self.forgotten.insert(path.to_string());
+    }
+
+    /// The template a tracked file was written from, if its snapshot is intact
+    pub fn base(&self, stripe_dir: &Path, path: &str) -> Option<String> {
+        let locked = self.files.get(path)?;
+        let content = match self.pending_bases.get(path) {
+            Some(content) => content.clone(),
+            None => fs::read_to_string(stripe_dir.join(BASE_DIR).join(path)).ok()?,
+        };
+        (hash(&content) == locked.hash).then_some(content)
+    }
+
+    /// Plan writing a template file into `stripe_dir` and record it
+    ///
+    /// A file the user declined to overwrite is still recorded if it was not
+    /// tracked before, so it shows up as modified rather than unknown.
+    pub fn write_file(
+        &mut self,
+        changes: &mut ChangeSet,
+        stripe_dir: &Path,
+        path: &str,
+        content: &str,
+        force: bool,
+    ) -> Result<bool> {
+        let written = changes.write_file(&stripe_dir.join(path), content, force, &format!("stripe/{}", path))?;
+        if written || !self.files.contains_key(path) {
+            self.record_file(path, content);
+        }
+        Ok(written)
+    }
+
+    /// Record an installed component, merging with what an earlier `add` recorded
+    pub fn record_component(&mut self, name: &str, files: Vec<String>, dependencies: Vec<String>) {
+        let entry = self.components.entry(name.to_string()).or_default();
+        let files: BTreeSet<String> = entry.files.drain(..).chain(files).collect();
+        let dependencies: BTreeSet<String> = entry
+            .dependencies
+            .drain(..)
+            .chain(dependencies)
+            .filter(|dependency| !files.contains(dependency))
+            .collect();
+        entry.files = files.into_iter().collect();
+        entry.dependencies = dependencies.into_iter().collect();
+    }
+
+    /// Whether a component has been added
+    pub fn is_installed(&self, component: &str) -> bool {
+        self.components.contains_key(component)
+    }
+
+    /// Every file some component other than `component` owns or depends on
+    pub fn files_used_by_others(&self, component: &str) -> BTreeSet<&str> {
+        self.components
+            .iter()
+            .filter(|(name, _)| *name != component)
+            .flat_map(|(_, locked)| locked.files.iter().chain(&locked.dependencies))
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Compare a tracked file on disk with the recorded hash
+    pub fn file_state(&self, stripe_dir: &Path, path: &str) -> Option<FileState> {
+        let locked = self.files.get(path)?;
+        let state = match fs::read_to_string(stripe_dir.join(path)) {
+            Ok(content) if hash(&content) == locked.hash => FileState::Unchanged,
+            Ok(_) => FileState::Modified,
+            Err(_) => FileState::Missing,
+        };
+        Some(state)
+    }
+}
+
+/// The `sha256:<hex>` hash recorded for file content
+pub fn hash(content: &str) -> String {
+    format!("sha256:{:x}", Sha256::digest(content.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::changes::Mode;
+    use crate::utils::fs::Overwrite;
+
+    const TEMPLATE: &str = "pub struct Customer {}\n";
+
+    /// A stripe directory with `customer.rs` written from `TEMPLATE` and recorded
+    fn stripe_dir() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("customer.rs"), TEMPLATE).unwrap();
+
+        let mut lockfile = Lockfile::default();
+        lockfile.record_file("customer.rs", TEMPLATE);
+        lockfile.record_component("customer", vec!["customer.rs".to_string()], vec!["ids.rs".to_string()]);
+        lockfile.save(dir.path()).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_lockfile_round_trip() {
+        let dir = stripe_dir();
+
+        let lockfile = Lockfile::load(dir.path()).unwrap();
+        assert_eq!(lockfile.version, TEMPLATE_VERSION);
+        assert_eq!(
+            lockfile.files["customer.rs"],
+            LockedFile { template_version: TEMPLATE_VERSION.to_string(), hash: hash(TEMPLATE), spec: None }
+        );
+        assert_eq!(lockfile.components["customer"].files, ["customer.rs"]);
+        assert_eq!(lockfile.components["customer"].dependencies, ["ids.rs"]);
+        assert_eq!(lockfile.base(dir.path(), "customer.rs").as_deref(), Some(TEMPLATE));
+    }
+
+    #[test]
+    fn test_file_state() {
+        let dir = stripe_dir();
+        let lockfile = Lockfile::load(dir.path()).unwrap();
+        assert_eq!(lockfile.file_state(dir.path(), "customer.rs"), Some(FileState::Unchanged));
+        assert_eq!(lockfile.file_state(dir.path(), "ids.rs"), None);
+
+        fs::write(dir.path().join("customer.rs"), "pub struct Customer { pub note: String }\n").unwrap();
+        assert_eq!(lockfile.file_state(dir.path(), "customer.rs"), Some(FileState::Modified));
+
+        fs::remove_file(dir.path().join("customer.rs")).unwrap();
+        assert_eq!(lockfile.file_state(dir.path(), "customer.rs"), Some(FileState::Missing));
+    }
+
+    #[test]
+    fn test_missing_or_stale_base() {
+        let dir = stripe_dir();
+        let lockfile = Lockfile::load(dir.path()).unwrap();
+        let base_path = dir.path().join(BASE_DIR).join("customer.rs");
+
+        fs::write(&base_path, "pub struct Other {}\n").unwrap();
+        assert_eq!(lockfile.base(dir.path(), "customer.rs"), None);
+
+        fs::remove_file(&base_path).unwrap();
+        assert_eq!(lockfile.base(dir.path(), "customer.rs"), None);
+        assert_eq!(lockfile.file_state(dir.path(), "customer.rs"), Some(FileState::Unchanged));
+    }
+
+    #[test]
+    fn test_write_file_records_declined_untracked_file() {
+        let dir = stripe_dir();
+        let mut lockfile = Lockfile::load(dir.path()).unwrap();
+        fs::write(dir.path().join("ids.rs"), "// mine\n").unwrap();
+
+        let mut changes = ChangeSet::new(Mode::Apply, dir.path()).with_overwrite(Overwrite::Skip);
+        let written = lockfile.write_file(&mut changes, dir.path(), "ids.rs", "// template\n", false).unwrap();
+        changes.finish().unwrap();
+
+        assert!(!written);
+        assert_eq!(fs::read_to_string(dir.path().join("ids.rs")).unwrap(), "// mine\n");
+        assert_eq!(lockfile.file_state(dir.path(), "ids.rs"), Some(FileState::Modified));
+    }
+}
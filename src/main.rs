@@ -1,11 +1,21 @@
-use clap::{CommandFactory, Parser, Subcommand};
+use clap::{Args, CommandFactory, Parser, Subcommand};
 use colored::Colorize;
 use std::path::PathBuf;
 use std::process;
 
+use utils::changes::Mode;
+use utils::fs::Overwrite;
+use utils::output::{self, Event, MessageFormat};
+
 mod commands;
+mod codegen;
 mod components;
 mod core;
+mod dependencies;
+mod embedded;
+mod features;
+mod lockfile;
+mod openapi;
 // mod templates;
 mod utils;
 
@@ -21,6 +31,32 @@ mod utils;
 struct Cli {
     #[clap(subcommand)]
     command: Option<Commands>,
+
+    /// Print messages as colored text or as one JSON event per line (init, add and list)
+    #[clap(long, global = true, value_enum, value_name = "FMT", default_value_t = MessageFormat::Human)]
+    message_format: MessageFormat,
+}
+
+/// What to do with files that already exist
+#[derive(Args)]
+struct OverwriteArgs {
+    /// Overwrite existing files without asking
+    #[clap(short, long, conflicts_with_all = ["no_overwrite", "skip_existing"])]
+    yes: bool,
+
+    /// Fail instead of overwriting an existing file
+    #[clap(long, conflicts_with = "skip_existing")]
+    no_overwrite: bool,
+
+    /// Keep existing files and only write new ones (the default when stdin is not a terminal)
+    #[clap(long)]
+    skip_existing: bool,
+}
+
+impl OverwriteArgs {
+    fn policy(&self) -> Overwrite {
+        Overwrite::from_flags(self.yes, self.no_overwrite, self.skip_existing)
+    }
 }
 
 #[derive(Subcommand)]
@@ -34,6 +70,21 @@ enum Commands {
         /// Force overwriting existing files
         #[clap(short, long)]
         force: bool,
+
+        /// Set up a synchronous client whose requests return their results directly
+        #[clap(long)]
+        blocking: bool,
+
+        /// Print the planned changes without writing anything
+        #[clap(long)]
+        dry_run: bool,
+
+        /// Like --dry-run, but show unified diffs against the existing files
+        #[clap(long)]
+        diff: bool,
+
+        #[clap(flatten)]
+        overwrite: OverwriteArgs,
     },
 
     /// Add a Stripe API component to your project
@@ -49,11 +100,93 @@ enum Commands {
         /// Force overwriting existing files
         #[clap(short, long)]
         force: bool,
+
+        /// Print the planned changes without writing anything
+        #[clap(long)]
+        dry_run: bool,
+
+        /// Like --dry-run, but show unified diffs against the existing files
+        #[clap(long)]
+        diff: bool,
+
+        #[clap(flatten)]
+        overwrite: OverwriteArgs,
+    },
+
+    /// Remove a previously added Stripe API component from your project
+    Remove {
+        /// Name of the component to remove (e.g., 'customer')
+        #[clap(value_name = "COMPONENT")]
+        component: String,
+
+        /// Target directory (defaults to current directory)
+        #[clap(value_name = "DIR")]
+        dir: Option<PathBuf>,
+
+        /// Remove the component even if other installed files still use its types or its files were edited
+        #[clap(short, long)]
+        force: bool,
+
+        /// Print the planned changes without writing anything
+        #[clap(long)]
+        dry_run: bool,
+
+        /// Like --dry-run, but show unified diffs against the existing files
+        #[clap(long)]
+        diff: bool,
+    },
+
+    /// Update installed files to this version's templates, merging local edits
+    Update {
+        /// Target directory (defaults to current directory)
+        #[clap(value_name = "DIR")]
+        dir: Option<PathBuf>,
     },
 
     /// List all available Stripe API components
     List,
 
+    /// Show installed components and template files edited since they were written
+    Status {
+        /// Target directory (defaults to current directory)
+        #[clap(value_name = "DIR")]
+        dir: Option<PathBuf>,
+    },
+
+    /// Regenerate components.json so every generated template belongs to a component
+    SyncComponents {
+        /// Stripe OpenAPI spec (JSON or YAML) whose resource groups define the components
+        #[clap(long, value_name = "FILE")]
+        spec: Option<PathBuf>,
+
+        /// Where to write the mapping
+        #[clap(short, long, value_name = "FILE", default_value = "components.json")]
+        output: PathBuf,
+
+        /// Rebuild every generated file assignment instead of extending the current mapping
+        #[clap(long)]
+        fresh: bool,
+    },
+
+    /// Generate resource bindings from a Stripe OpenAPI spec
+    Generate {
+        /// Stripe OpenAPI spec (JSON or YAML)
+        #[clap(long, value_name = "FILE")]
+        spec: PathBuf,
+
+        /// Stripe module directory to write to (defaults to the project's src/stripe)
+        #[clap(long, value_name = "DIR")]
+        out: Option<PathBuf>,
+
+        /// Only generate the schemas these `/v1/<resource>` path groups depend on (e.g. 'customers')
+        #[clap(long = "resource", value_name = "RESOURCE")]
+        resources: Vec<String>,
+
+        /// Force overwriting existing files
+        #[clap(short, long)]
+        force: bool,
+    },
+
     /// Display usage examples for this tool
     Examples,
 }
@@ -72,31 +205,77 @@ async fn main() {
     } else {
         Cli::parse()
     };
+    output::set_format(cli.message_format);
 
     let result = match cli.command {
-        Some(Commands::Init { dir, force }) => commands::init::run(dir.as_ref(), force),
+        Some(Commands::Init {
+            dir,
+            force,
+            blocking,
+            dry_run,
+            diff,
+            overwrite,
+        }) => commands::init::run(
+            dir.as_ref(),
+            force,
+            blocking,
+            Mode::from_flags(dry_run, diff),
+            overwrite.policy(),
+        ),
         Some(Commands::Add {
             component,
             dir,
             force,
-        }) => commands::add::run(&component, dir.as_ref(), force),
+            dry_run,
+            diff,
+            overwrite,
+        }) => commands::add::run(
+            &component,
+            dir.as_ref(),
+            force,
+            Mode::from_flags(dry_run, diff),
+            overwrite.policy(),
+        ),
+        Some(Commands::Remove {
+            component,
+            dir,
+            force,
+            dry_run,
+            diff,
+        }) => commands::remove::run(&component, dir.as_ref(), force, Mode::from_flags(dry_run, diff)),
+        Some(Commands::SyncComponents {
+            spec,
+            output,
+            fresh,
+        }) => commands::sync_components::run(spec.as_ref(), &output, fresh),
+        Some(Commands::Generate {
+            spec,
+            out,
+            resources,
+            force,
+        }) => commands::generate::run(&spec, out.as_ref(), &resources, force),
+        Some(Commands::Update { dir }) => commands::update::run(dir.as_ref()),
+        Some(Commands::Status { dir }) => commands::status::run(dir.as_ref()),
         Some(Commands::List) => {
-            // Display all available components
+            // Display all available components, marking the ones this project has added
             let components = components::get_all_component_templates();
-            println!("{}", "Available Stripe API components:".bold());
-            println!(
+            let installed = utils::fs::find_src_directory(None)
+                .and_then(|src_dir| lockfile::Lockfile::load(&src_dir.join("stripe")))
+                .unwrap_or_default();
+            output::info("Available Stripe API components:".bold());
+            output::info(
                 "These components include both extension files and generated resource definitions.\n"
             );
 
             for component in &components {
-                println!("  • {}", component);
+                output::emit(Event::Component { name: component, installed: installed.is_installed(component) });
             }
 
-            println!("\n{}", "Special options:".bold());
-            println!("  • all - Add all components at once (generates complete API)");
+            output::info(format!("\n{}", "Special options:".bold()));
+            output::info("  • all - Add all components at once (generates complete API)");
 
-            println!("\n{}", "Usage:".bold());
-            println!("  cargo stripe add <component>");
+            output::info(format!("\n{}", "Usage:".bold()));
+            output::info("  cargo stripe add <component>");
             return;
         }
         Some(Commands::Examples) => {
@@ -113,6 +292,12 @@ async fn main() {
             println!("\n{}", "4. List available components:".bold());
             println!("   cargo stripe list");
 
+            println!("\n{}", "5. Remove a component:".bold());
+            println!("   cargo stripe remove payment_intent");
+
+            println!("\n{}", "6. Preview what a command would change:".bold());
+            println!("   cargo stripe add payment_intent --diff");
+
             println!("\n{}", "Common errors:".bold());
             println!("   Using 'cargo run add ...' instead of 'cargo stripe add ...'");
             println!(
@@ -129,7 +314,11 @@ async fn main() {
     };
 
     match result {
-        Ok(msg) => println!("{}", msg.green()),
+        Ok(msg) => output::emit(Event::Finished { message: &msg }),
+        Err(err) if output::is_json() => {
+            output::emit(Event::Error { message: &format!("{:#}", err) });
+            process::exit(1);
+        }
         Err(err) => {
             eprintln!("{}: {}", "Error".red().bold(), err);
 
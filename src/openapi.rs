@@ -0,0 +1,173 @@
+use anyhow::{Context, Result, anyhow};
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fs;
+use std::path::Path;
+
+const SCHEMA_REF_PREFIX: &str = "#/components/schemas/";
+
+/// A loaded Stripe OpenAPI specification
+pub struct Spec {
+    pub document: Value,
+}
+
+impl Spec {
+    /// Load a JSON or YAML OpenAPI file
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read OpenAPI spec: {}", path.display()))?;
+
+        let is_json = path.extension().and_then(|ext| ext.to_str()) == Some("json")
+            || content.trim_start().starts_with('{');
+        let document: Value = if is_json {
+            serde_json::from_str(&content).context("Failed to parse OpenAPI spec as JSON")?
+        } else {
+            serde_yaml::from_str(&content).context("Failed to parse OpenAPI spec as YAML")?
+        };
+
+        if document.pointer("/components/schemas").is_none() || document.get("paths").is_none() {
+            return Err(anyhow!(
+                "Invalid OpenAPI structure: missing 'paths' or 'components/schemas'"
+            ));
+        }
+
+        Ok(Self { document })
+    }
+
+    /// The `components/schemas` table
+    pub fn schemas(&self) -> &Map<String, Value> {
+        self.document
+            .pointer("/components/schemas")
+            .and_then(Value::as_object)
+            .expect("validated in Spec::load")
+    }
+
+    /// Every event type webhook endpoints can subscribe to (e.g. `customer.created`)
+    ///
+    /// Stripe lists them in the `enabled_events` enum of `POST /v1/webhook_endpoints`.
+    pub fn event_types(&self) -> Vec<String> {
+        let mut events = BTreeSet::new();
+        if let Some(operation) = self.document.pointer("/paths/~1v1~1webhook_endpoints/post") {
+            collect_enabled_events(operation, &mut events);
+        }
+        events.remove("*");
+        events.into_iter().collect()
+    }
+
+    /// Every error code the API reports, from the `code` enum of the `api_errors` schema
+    pub fn error_codes(&self) -> Vec<String> {
+        self.document
+            .pointer("/components/schemas/api_errors/properties/code/enum")
+            .and_then(Value::as_array)
+            .map(|codes| codes.iter().filter_map(Value::as_str).map(str::to_string).collect())
+            .unwrap_or_default()
+    }
+
+    /// Resolve every schema `initial` depends on, including `initial` itself
+    ///
+    /// Performs a breadth-first search through schema references, skipping
+    /// references to schemas the spec does not define.
+    pub fn dependent_schemas(&self, initial: &BTreeSet<String>) -> BTreeSet<String> {
+        let schemas = self.schemas();
+        let mut required = initial.clone();
+        let mut queue: VecDeque<String> = initial.iter().cloned().collect();
+
+        while let Some(name) = queue.pop_front() {
+            let Some(schema) = schemas.get(&name) else {
+                continue;
+            };
+
+            let mut refs = BTreeSet::new();
+            find_refs(schema, &mut refs);
+            for reference in refs {
+                if required.insert(reference.clone()) {
+                    queue.push_back(reference);
+                }
+            }
+        }
+
+        required.retain(|name| schemas.contains_key(name));
+        required
+    }
+
+    /// Group the `/v1/...` paths by the segment after `/v1/`
+    ///
+    /// `/v1/customers/{customer}/sources` is grouped under `customers`,
+    /// `/v1/issuing/cards` under `issuing`.
+    pub fn resource_groups(&self) -> BTreeMap<String, Vec<(&str, &Value)>> {
+        let mut groups: BTreeMap<String, Vec<(&str, &Value)>> = BTreeMap::new();
+
+        let Some(paths) = self.document.get("paths").and_then(Value::as_object) else {
+            return groups;
+        };
+
+        for (path, item) in paths {
+            let Some(rest) = path.strip_prefix("/v1/") else {
+                continue;
+            };
+            let Some(resource) = rest.split('/').next().filter(|s| !s.is_empty()) else {
+                continue;
+            };
+            // Skip paths whose main segment is a parameter
+            if resource.contains('{') {
+                continue;
+            }
+            groups.entry(resource.to_string()).or_default().push((path, item));
+        }
+
+        groups
+    }
+}
+
+/// Collect the names of all `#/components/schemas/...` references inside `value`
+pub fn find_refs(value: &Value, refs: &mut BTreeSet<String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                match (key.as_str(), value) {
+                    ("$ref", Value::String(reference)) => {
+                        if let Some(name) = reference.strip_prefix(SCHEMA_REF_PREFIX) {
+                            refs.insert(name.to_string());
+                        }
+                    }
+                    _ => find_refs(value, refs),
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                find_refs(item, refs);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Collect the values of every `enabled_events` enum inside `value`
+fn collect_enabled_events(value: &Value, events: &mut BTreeSet<String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                let enabled_events = match key.as_str() {
+                    "enabled_events" => value.pointer("/items/enum").and_then(Value::as_array),
+                    _ => None,
+                };
+                match enabled_events {
+                    Some(values) => events.extend(values.iter().filter_map(Value::as_str).map(str::to_string)),
+                    None => collect_enabled_events(value, events),
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                collect_enabled_events(item, events);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// The generated file name for a schema (e.g. `issuing.card` -> `issuing_card`)
+pub fn schema_file_name(schema: &str) -> String {
+    schema.replace('.', "_").to_lowercase()
+}
@@ -1,19 +1,110 @@
-use std::future::{self, Future};
 use std::pin::Pin;
 
+use futures_util::Stream;
+
 use crate::stripe::error::StripeError;
 
-/// A response future for async operations
-pub type Response<T> = Pin<Box<dyn Future<Output = Result<T, StripeError>> + Send>>;
+/// The chunks of a downloaded file, in order
+pub type ByteStream = Pin<Box<dyn Stream<Item = Result<Vec<u8>, StripeError>> + Send>>;
+
+#[cfg(not(feature = "blocking"))]
+mod flavour {
+    use std::future::{self, Future};
+    use std::pin::Pin;
+
+    use super::{ByteStream, StripeError};
+
+    /// A response future for async operations
+    pub type Response<T> = Pin<Box<dyn Future<Output = Result<T, StripeError>> + Send>>;
+
+    /// Helper to create successful responses
+    #[inline(always)]
+    pub(crate) fn ok<T: Send + 'static>(ok: T) -> Response<T> {
+        Box::pin(future::ready(Ok(ok)))
+    }
 
-/// Helper to create successful responses
-#[inline(always)]
-pub(crate) fn ok<T: Send + 'static>(ok: T) -> Response<T> {
-    Box::pin(future::ready(Ok(ok)))
+    /// Helper to create error responses
+    #[inline(always)]
+    pub(crate) fn err<T: Send + 'static>(err: StripeError) -> Response<T> {
+        Box::pin(future::ready(Err(err)))
+    }
+
+    /// Helper to map the value of a response once it arrives
+    pub(crate) fn map<T: Send + 'static, U>(
+        response: Response<T>,
+        f: impl FnOnce(T) -> U + Send + 'static,
+    ) -> Response<U> {
+        Box::pin(async move { response.await.map(f) })
+    }
+
+    /// The contents of a downloaded file, streamed as they arrive
+    pub type Download = ByteStream;
 }
 
-/// Helper to create error responses
-#[inline(always)]
-pub(crate) fn err<T: Send + 'static>(err: StripeError) -> Response<T> {
-    Box::pin(future::ready(Err(err)))
+#[cfg(feature = "blocking")]
+mod flavour {
+    use std::io::{self, Read};
+    use std::sync::Arc;
+
+    use futures_util::StreamExt;
+    use tokio::runtime::Runtime;
+
+    use super::{ByteStream, StripeError};
+
+    /// The result of a blocking request
+    pub type Response<T> = Result<T, StripeError>;
+
+    /// Helper to create successful responses
+    #[inline(always)]
+    pub(crate) fn ok<T: Send + 'static>(ok: T) -> Response<T> {
+        Ok(ok)
+    }
+
+    /// Helper to create error responses
+    #[inline(always)]
+    pub(crate) fn err<T: Send + 'static>(err: StripeError) -> Response<T> {
+        Err(err)
+    }
+
+    /// Helper to map the value of a response
+    pub(crate) fn map<T, U>(response: Response<T>, f: impl FnOnce(T) -> U) -> Response<U> {
+        response.map(f)
+    }
+
+    /// The contents of a downloaded file, read as they arrive
+    pub struct Download {
+        body: ByteStream,
+        runtime: Arc<Runtime>,
+        chunk: Vec<u8>,
+        pos: usize,
+    }
+
+    impl Download {
+        pub(crate) fn new(body: ByteStream, runtime: Arc<Runtime>) -> Self {
+            Download { body, runtime, chunk: Vec::new(), pos: 0 }
+        }
+    }
+
+    impl Read for Download {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            while self.pos == self.chunk.len() {
+                match self.runtime.block_on(self.body.next()) {
+                    Some(Ok(chunk)) => {
+                        self.chunk = chunk;
+                        self.pos = 0;
+                    }
+                    Some(Err(e)) => return Err(io::Error::other(e)),
+                    None => return Ok(0),
+                }
+            }
+
+            let len = buf.len().min(self.chunk.len() - self.pos);
+            buf[..len].copy_from_slice(&self.chunk[self.pos..self.pos + len]);
+            self.pos += len;
+            Ok(len)
+        }
+    }
 }
+
+pub use flavour::{Download, Response};
+pub(crate) use flavour::{err, map, ok};
@@ -0,0 +1,281 @@
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+use futures_util::stream::{self, Stream};
+use reqwest::{
+    header::{HeaderMap, HeaderValue, AUTHORIZATION},
+    Client as ReqwestClient, Method, RequestBuilder, StatusCode, Url,
+};
+
+use crate::stripe::error::StripeError;
+
+/// A request as the client hands it to the transport
+///
+/// Its `Debug` output redacts the `authorization` header.
+#[derive(Clone)]
+pub struct HttpRequest {
+    pub method: Method,
+    /// The full URL, query string included
+    pub url: Url,
+    pub headers: HeaderMap,
+    /// The urlencoded form, for `POST` requests
+    pub body: Option<Vec<u8>>,
+    /// Timeout for this request, in place of the transport's own
+    pub timeout: Option<Duration>,
+}
+
+impl fmt::Debug for HttpRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut headers = self.headers.clone();
+        if headers.contains_key(AUTHORIZATION) {
+            headers.insert(AUTHORIZATION, HeaderValue::from_static("[redacted]"));
+        }
+        f.debug_struct("HttpRequest")
+            .field("method", &self.method)
+            .field("url", &self.url.as_str())
+            .field("headers", &headers)
+            .field("body", &self.body.as_deref().map(String::from_utf8_lossy))
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+/// A response as the transport hands it back
+#[derive(Clone, Debug)]
+pub struct HttpResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+/// A response whose body is still arriving
+pub struct StreamingResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: BodyStream,
+}
+
+/// The chunks of a response body, in order
+pub type BodyStream = Pin<Box<dyn Stream<Item = Result<Vec<u8>, TransportError>> + Send>>;
+
+/// Why a request got no response
+#[derive(Debug)]
+pub enum TransportError {
+    /// The request timed out
+    Timeout,
+    /// The connection failed or broke off
+    Connection(String),
+}
+
+/// The future a transport returns
+pub type TransportFuture = Pin<Box<dyn Future<Output = Result<HttpResponse, TransportError>> + Send>>;
+
+/// The future a transport returns for a streamed response
+pub type StreamingFuture = Pin<Box<dyn Future<Output = Result<StreamingResponse, TransportError>> + Send>>;
+
+/// Sends requests for a [`StripeClient`](super::Client)
+///
+/// The client builds each request and handles retries, idempotency keys and
+/// response parsing, so a transport only has to move bytes. Implement it to
+/// bring your own connection pool, proxy or TLS settings, or to fake Stripe
+/// in tests.
+pub trait HttpTransport: Send + Sync {
+    /// Send one request and return the response, whatever its status
+    fn send(&self, request: HttpRequest) -> TransportFuture;
+
+    /// Send one request and return the response as soon as its headers arrive
+    ///
+    /// File downloads use this. The default waits for [`send`](Self::send)
+    /// and hands the whole body over as one chunk.
+    fn send_streaming(&self, request: HttpRequest) -> StreamingFuture {
+        let response = self.send(request);
+        Box::pin(async move {
+            let HttpResponse { status, headers, body } = response.await?;
+            let body: BodyStream = Box::pin(stream::once(async move { Ok(body) }));
+            Ok(StreamingResponse { status, headers, body })
+        })
+    }
+}
+
+/// The default transport, backed by a `reqwest::Client`
+#[derive(Clone)]
+pub struct ReqwestTransport {
+    client: ReqwestClient,
+}
+
+impl ReqwestTransport {
+    /// A transport with a 30s request timeout and a 10s connect timeout
+    pub fn new() -> Result<Self, StripeError> {
+        let client = ReqwestClient::builder()
+            .timeout(Duration::from_secs(30))
+            .connect_timeout(Duration::from_secs(10))
+            .pool_idle_timeout(Some(Duration::from_secs(60)))
+            .build()
+            .map_err(|e| StripeError::ClientError(format!("Failed to create HTTP client: {}", e)))?;
+        Ok(Self { client })
+    }
+
+    /// A transport using an existing `reqwest::Client`
+    pub fn from_client(client: ReqwestClient) -> Self {
+        Self { client }
+    }
+
+    fn builder(&self, request: HttpRequest) -> RequestBuilder {
+        let mut builder = self.client.request(request.method, request.url).headers(request.headers);
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+        if let Some(timeout) = request.timeout {
+            builder = builder.timeout(timeout);
+        }
+        builder
+    }
+}
+
+impl HttpTransport for ReqwestTransport {
+    fn send(&self, request: HttpRequest) -> TransportFuture {
+        let builder = self.builder(request);
+        Box::pin(async move {
+            let response = builder.send().await.map_err(transport_error)?;
+            let status = response.status();
+            let headers = response.headers().clone();
+            let body = response.bytes().await.map_err(transport_error)?.to_vec();
+            Ok(HttpResponse { status, headers, body })
+        })
+    }
+
+    fn send_streaming(&self, request: HttpRequest) -> StreamingFuture {
+        let builder = self.builder(request);
+        Box::pin(async move {
+            let response = builder.send().await.map_err(transport_error)?;
+            let status = response.status();
+            let headers = response.headers().clone();
+            let body: BodyStream = Box::pin(stream::try_unfold(response, |mut response| async move {
+                let chunk = response.chunk().await.map_err(transport_error)?;
+                Ok(chunk.map(|chunk| (chunk.to_vec(), response)))
+            }));
+            Ok(StreamingResponse { status, headers, body })
+        })
+    }
+}
+
+fn transport_error(err: reqwest::Error) -> TransportError {
+    if err.is_timeout() {
+        TransportError::Timeout
+    } else {
+        TransportError::Connection(format!("HTTP request error: {}", err))
+    }
+}
+
+/// A fake transport and helpers for the tests of the client and resources
+#[cfg(test)]
+pub(crate) mod testing {
+    use std::collections::VecDeque;
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::stripe::client::{Client, Response};
+
+    /// Answers with the queued responses, then with `200 {}`, and records the requests
+    ///
+    /// Clones share their queue and requests, so a test can keep one after
+    /// handing the other to a client.
+    #[derive(Clone, Default)]
+    pub(crate) struct FakeTransport {
+        responses: Arc<Mutex<VecDeque<Option<(u16, String)>>>>,
+        requests: Arc<Mutex<Vec<HttpRequest>>>,
+    }
+
+    impl FakeTransport {
+        pub(crate) fn new() -> Self {
+            Self::default()
+        }
+
+        /// Queue a response
+        pub(crate) fn respond(self, status: u16, body: &str) -> Self {
+            self.responses.lock().unwrap().push_back(Some((status, body.to_string())));
+            self
+        }
+
+        /// Queue a response that never arrives
+        pub(crate) fn hang(self) -> Self {
+            self.responses.lock().unwrap().push_back(None);
+            self
+        }
+
+        /// A client sending its requests through this transport
+        pub(crate) fn client(&self) -> Client {
+            Client::with_transport("sk_test_12345", self.clone()).unwrap()
+        }
+
+        /// The requests sent so far
+        pub(crate) fn requests(&self) -> Vec<HttpRequest> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    impl HttpTransport for FakeTransport {
+        fn send(&self, request: HttpRequest) -> TransportFuture {
+            let mut requests = self.requests.lock().unwrap();
+            requests.push(request);
+            let response = self.responses.lock().unwrap().pop_front().unwrap_or(Some((200, "{}".to_string())));
+            let Some((status, body)) = response else {
+                return Box::pin(futures_util::future::pending());
+            };
+            let mut headers = HeaderMap::new();
+            headers.insert("request-id", HeaderValue::from_str(&format!("req_{}", requests.len())).unwrap());
+            let response = HttpResponse { status: StatusCode::from_u16(status).unwrap(), headers, body: body.into_bytes() };
+            Box::pin(async move { Ok(response) })
+        }
+    }
+
+    /// Wait for a response from the async client
+    #[cfg(not(feature = "blocking"))]
+    pub(crate) fn wait<T>(response: Response<T>) -> Result<T, StripeError> {
+        tokio::runtime::Runtime::new().unwrap().block_on(response)
+    }
+
+    /// A blocking response is already there
+    #[cfg(feature = "blocking")]
+    pub(crate) fn wait<T>(response: Response<T>) -> Result<T, StripeError> {
+        response
+    }
+
+    /// The one request `call` sends, as `METHOD /path` and form body
+    pub(crate) fn request<T>(call: impl FnOnce(&Client) -> Response<T>) -> (String, String) {
+        let transport = FakeTransport::new();
+        // The empty response body may not deserialize; only the request matters
+        let _ = wait(call(&transport.client()));
+        let mut requests = transport.requests();
+        assert_eq!(requests.len(), 1);
+        let request = requests.pop().unwrap();
+        let body = String::from_utf8(request.body.unwrap_or_default()).unwrap();
+        (format!("{} {}", request.method, request.url.path()), body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debug_redacts_authorization() {
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, HeaderValue::from_static("Bearer sk_test_12345"));
+        headers.insert("stripe-version", HeaderValue::from_static("2024-06-20"));
+        let request = HttpRequest {
+            method: Method::GET,
+            url: Url::parse("https://api.stripe.com/v1/customers").unwrap(),
+            headers,
+            body: None,
+            timeout: None,
+        };
+
+        let debug = format!("{:?}", request);
+        assert!(!debug.contains("sk_test_12345"));
+        assert!(debug.contains("[redacted]"));
+        assert!(debug.contains("2024-06-20"));
+    }
+}
@@ -0,0 +1,30 @@
+use std::time::Duration;
+
+use crate::stripe::error::StripeError;
+
+use super::http_transport::{HttpRequest, HttpResponse};
+
+/// Hooks into every request a [`StripeClient`](super::Client) makes
+///
+/// Register one with [`StripeClient::with_interceptor`](super::Client::with_interceptor).
+/// Every method has an empty default, so implement only the ones you need.
+/// Attempts are numbered from 1, and retries of a request share its idempotency key.
+///
+/// The `Debug` output of [`HttpRequest`] redacts the `authorization` header,
+/// so requests can be logged as they are.
+pub trait Interceptor: Send + Sync {
+    /// Called before each attempt is sent; changes to `request` apply to this attempt only
+    fn before_request(&self, request: &mut HttpRequest, attempt: u32) {
+        let _ = (request, attempt);
+    }
+
+    /// Called with every response, whatever its status, and how long the attempt took
+    fn after_response(&self, request: &HttpRequest, response: &HttpResponse, elapsed: Duration) {
+        let _ = (request, response, elapsed);
+    }
+
+    /// Called when an attempt failed with `error` and will be retried after `delay`
+    fn on_retry(&self, request: &HttpRequest, attempt: u32, error: &StripeError, delay: Option<Duration>) {
+        let _ = (request, attempt, error, delay);
+    }
+}
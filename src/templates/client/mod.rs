@@ -1,12 +1,29 @@
 mod http_client;
+mod http_transport;
+mod interceptor;
+mod multipart;
+mod request_options;
 mod request_strategy;
+mod response_meta;
 mod stripe_client;
 
-pub use http_client::Response;
-pub use request_strategy::RequestStrategy;
+pub use http_client::{ByteStream, Download, Response};
+pub use http_transport::{
+    BodyStream, HttpRequest, HttpResponse, HttpTransport, ReqwestTransport, StreamingFuture, StreamingResponse,
+    TransportError, TransportFuture,
+};
+pub use interceptor::Interceptor;
+pub use multipart::Multipart;
+pub use request_options::RequestOptions;
+pub use request_strategy::{RequestStrategy, RetryPolicy};
+pub use response_meta::ResponseMeta;
 pub use stripe_client::StripeClient as Client;
 
 // Re-export helpers for internal use
 pub(crate) mod config {
     pub(crate) use super::http_client::{err, ok};
+    pub(crate) use super::request_strategy::random_u64;
 }
+
+#[cfg(test)]
+pub(crate) use http_transport::testing;
@@ -0,0 +1,96 @@
+use super::request_strategy::random_u64;
+
+/// A `multipart/form-data` body, for uploads to `files.stripe.com`
+///
+/// ```ignore
+/// let form = Multipart::new()
+///     .text("purpose", "dispute_evidence")
+///     .file("file", "receipt.pdf", "application/pdf", bytes);
+/// let file: File = client.post_multipart("/files", form).await?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct Multipart {
+    boundary: String,
+    body: Vec<u8>,
+}
+
+impl Multipart {
+    /// An empty form with a random boundary
+    pub fn new() -> Self {
+        Self { boundary: format!("stripe-rs-{:016x}{:016x}", random_u64(), random_u64()), body: Vec::new() }
+    }
+
+    /// Add a text field
+    pub fn text(mut self, name: &str, value: impl AsRef<str>) -> Self {
+        self.part_header(name, None);
+        self.body.extend_from_slice(b"\r\n");
+        self.body.extend_from_slice(value.as_ref().as_bytes());
+        self.body.extend_from_slice(b"\r\n");
+        self
+    }
+
+    /// Add a file field
+    pub fn file(mut self, name: &str, filename: &str, content_type: &str, data: impl AsRef<[u8]>) -> Self {
+        self.part_header(name, Some(filename));
+        self.body.extend_from_slice(format!("Content-Type: {}\r\n\r\n", content_type).as_bytes());
+        self.body.extend_from_slice(data.as_ref());
+        self.body.extend_from_slice(b"\r\n");
+        self
+    }
+
+    /// The `Content-Type` header to send the form with
+    pub fn content_type(&self) -> String {
+        format!("multipart/form-data; boundary={}", self.boundary)
+    }
+
+    /// The encoded form, closing boundary included
+    pub fn into_body(mut self) -> Vec<u8> {
+        self.body.extend_from_slice(format!("--{}--\r\n", self.boundary).as_bytes());
+        self.body
+    }
+
+    fn part_header(&mut self, name: &str, filename: Option<&str>) {
+        let mut header = format!("--{}\r\nContent-Disposition: form-data; name=\"{}\"", self.boundary, escape(name));
+        if let Some(filename) = filename {
+            header.push_str(&format!("; filename=\"{}\"", escape(filename)));
+        }
+        header.push_str("\r\n");
+        self.body.extend_from_slice(header.as_bytes());
+    }
+}
+
+impl Default for Multipart {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Quote a field or file name the way browsers do
+fn escape(name: &str) -> String {
+    name.replace('"', "%22").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multipart_body() {
+        let form = Multipart::new()
+            .text("purpose", "dispute_evidence")
+            .file("file", "a\"b.txt", "text/plain", "hello");
+        let boundary = form.boundary.clone();
+        assert_eq!(form.content_type(), format!("multipart/form-data; boundary={}", boundary));
+
+        let body = String::from_utf8(form.into_body()).unwrap();
+        assert_eq!(
+            body,
+            format!(
+                "--{b}\r\nContent-Disposition: form-data; name=\"purpose\"\r\n\r\ndispute_evidence\r\n\
+                 --{b}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"a%22b.txt\"\r\n\
+                 Content-Type: text/plain\r\n\r\nhello\r\n--{b}--\r\n",
+                b = boundary
+            )
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+use crate::stripe::{resources::ApiVersion, AccountId};
+
+/// Settings for individual requests, layered over the client's own
+///
+/// Pass them to [`StripeClient::with_options`](super::Client::with_options) to
+/// get a client whose requests all use them:
+///
+/// ```ignore
+/// let connected = client.with_options(RequestOptions::new().stripe_account(account_id));
+/// let customer = Customer::create(&connected, params).await?;
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct RequestOptions {
+    /// Sent as `Idempotency-Key`, in place of the one from the request strategy
+    pub idempotency_key: Option<String>,
+    /// Sent as `Stripe-Account`, to act on a connected account
+    pub stripe_account: Option<AccountId>,
+    /// Sent as `Stripe-Version`, in place of the client's API version
+    pub stripe_version: Option<ApiVersion>,
+    /// Timeout for each attempt, in place of the client's 30 seconds
+    ///
+    /// Retries start a fresh timeout, so a retried request can take longer in total.
+    pub timeout: Option<Duration>,
+    /// Extra headers, sent after the standard ones
+    pub headers: Vec<(String, String)>,
+}
+
+impl RequestOptions {
+    /// Options that change nothing
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the idempotency key
+    pub fn idempotency_key(mut self, key: impl Into<String>) -> Self {
+        self.idempotency_key = Some(key.into());
+        self
+    }
+
+    /// Make the requests on behalf of a connected account
+    pub fn stripe_account(mut self, account: AccountId) -> Self {
+        self.stripe_account = Some(account);
+        self
+    }
+
+    /// Pin the API version
+    pub fn stripe_version(mut self, version: ApiVersion) -> Self {
+        self.stripe_version = Some(version);
+        self
+    }
+
+    /// Set the timeout for each attempt
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Add an extra header
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Layer `other` over these options: its settings win and its headers are added
+    pub fn merge(mut self, other: RequestOptions) -> Self {
+        self.idempotency_key = other.idempotency_key.or(self.idempotency_key);
+        self.stripe_account = other.stripe_account.or(self.stripe_account);
+        self.stripe_version = other.stripe_version.or(self.stripe_version);
+        self.timeout = other.timeout.or(self.timeout);
+        self.headers.extend(other.headers);
+        self
+    }
+}
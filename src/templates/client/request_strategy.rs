@@ -1,4 +1,6 @@
-use std::time::Duration;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use reqwest::StatusCode;
 
@@ -15,7 +17,87 @@ pub enum RequestStrategy {
     Retry(u32),
     
     /// Retry with exponential backoff up to n times using a random idempotency key
+    ///
+    /// Delays double from 1s up to 8s, with full jitter.
     ExponentialBackoff(u32),
+
+    /// Retry as the policy describes, using a random idempotency key
+    Policy(RetryPolicy),
+}
+
+/// How often and how long to wait before retrying a request
+///
+/// HTTP responses and network errors (failed connections, timeouts) have
+/// separate retry budgets. Delays double from `base_delay` up to `max_delay`;
+/// with `jitter` each delay is a random duration between zero and that value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Retries after a response that can be retried (409, 429 or 5xx)
+    pub max_retries: u32,
+    /// Retries after a network error
+    pub max_network_retries: u32,
+    /// Delay before the first retry
+    pub base_delay: Duration,
+    /// Longest single delay, `Retry-After` included
+    pub max_delay: Duration,
+    /// Randomize each delay ("full jitter") so clients do not retry in lockstep
+    pub jitter: bool,
+}
+
+impl RetryPolicy {
+    /// Retry up to `max_retries` times after responses and after network errors,
+    /// starting at 500ms and waiting at most 8s, with full jitter
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            max_network_retries: max_retries,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(8),
+            jitter: true,
+        }
+    }
+
+    /// Set the number of retries after network errors
+    pub fn network_retries(mut self, max_network_retries: u32) -> Self {
+        self.max_network_retries = max_network_retries;
+        self
+    }
+
+    /// Set the delay before the first retry
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Set the longest single delay
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Turn full jitter on or off
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// The delay before retry number `retry` (starting at 0)
+    ///
+    /// A `Retry-After` from the server replaces the computed delay, capped at `max_delay`.
+    pub fn delay(&self, retry: u32, retry_after: Option<Duration>) -> Duration {
+        if let Some(retry_after) = retry_after {
+            return retry_after.min(self.max_delay);
+        }
+        let delay = self
+            .base_delay
+            .saturating_mul(2_u32.saturating_pow(retry))
+            .min(self.max_delay);
+        if self.jitter {
+            delay.mul_f64(random_fraction())
+        } else {
+            delay
+        }
+    }
 }
 
 impl RequestStrategy {
@@ -25,6 +107,17 @@ impl RequestStrategy {
         status: Option<StatusCode>,
         stripe_should_retry: Option<bool>,
         retry_count: u32,
+    ) -> Outcome {
+        self.test_response(status, stripe_should_retry, None, retry_count)
+    }
+
+    /// Like [`test`](Self::test), also honoring the response's `Retry-After`
+    pub fn test_response(
+        &self,
+        status: Option<StatusCode>,
+        stripe_should_retry: Option<bool>,
+        retry_after: Option<Duration>,
+        retry_count: u32,
     ) -> Outcome {
         // If Stripe explicitly says not to retry, then don't
         if !stripe_should_retry.unwrap_or(true) {
@@ -35,13 +128,19 @@ impl RequestStrategy {
             // A strategy of once or idempotent should run once
             (RequestStrategy::Once | RequestStrategy::Idempotent(_), _, 0) => Outcome::Continue(None),
 
-            // Requests with client errors usually cannot be solved with retries
-            (_, Some(c), _) if c.is_client_error() => Outcome::Stop,
+            // Other client errors cannot be solved with retries; conflicts on
+            // an idempotency key and rate limits can, as can anything Stripe
+            // says is safe to retry
+            (_, Some(c), _) if !is_retryable(c, stripe_should_retry) => Outcome::Stop,
 
             // Retry strategies should retry up to their max number of times
-            (RequestStrategy::Retry(n), _, x) if x < *n => Outcome::Continue(None),
+            (RequestStrategy::Retry(n), _, x) if x < *n => Outcome::Continue(retry_after),
             (RequestStrategy::ExponentialBackoff(n), _, x) if x < *n => {
-                Outcome::Continue(Some(calculate_backoff(x)))
+                Outcome::Continue(Some(backoff_policy(*n).delay(x, retry_after)))
+            }
+            (RequestStrategy::Policy(_), _, 0) => Outcome::Continue(None),
+            (RequestStrategy::Policy(policy), _, x) if x <= policy.max_retries => {
+                Outcome::Continue(Some(policy.delay(x - 1, retry_after)))
             }
 
             // Unknown cases should be stopped to prevent infinite loops
@@ -49,17 +148,32 @@ impl RequestStrategy {
         }
     }
 
+    /// Test whether to retry after `network_errors` requests failed without a response
+    pub fn test_network_error(&self, network_errors: u32) -> Outcome {
+        match self {
+            RequestStrategy::Once | RequestStrategy::Idempotent(_) => Outcome::Stop,
+            RequestStrategy::Retry(n) if network_errors < *n => Outcome::Continue(None),
+            RequestStrategy::ExponentialBackoff(n) if network_errors < *n => {
+                Outcome::Continue(Some(backoff_policy(*n).delay(network_errors, None)))
+            }
+            RequestStrategy::Policy(policy) if (1..=policy.max_network_retries).contains(&network_errors) => {
+                Outcome::Continue(Some(policy.delay(network_errors - 1, None)))
+            }
+            _ => Outcome::Stop,
+        }
+    }
+
     /// Get an idempotency key for this strategy, if applicable
+    ///
+    /// Retrying strategies get a new random key on every call; the client asks
+    /// once per request and sends the same key with every retry.
     pub fn get_key(&self) -> Option<String> {
         match self {
             RequestStrategy::Once => None,
             RequestStrategy::Idempotent(key) => Some(key.clone()),
-            #[cfg(feature = "uuid")]
-            RequestStrategy::Retry(_) | RequestStrategy::ExponentialBackoff(_) => {
-                Some(uuid::Uuid::new_v4().to_string())
+            RequestStrategy::Retry(_) | RequestStrategy::ExponentialBackoff(_) | RequestStrategy::Policy(_) => {
+                Some(random_idempotency_key())
             }
-            #[cfg(not(feature = "uuid"))]
-            RequestStrategy::Retry(_) | RequestStrategy::ExponentialBackoff(_) => None,
         }
     }
 
@@ -71,9 +185,45 @@ impl RequestStrategy {
     }
 }
 
-/// Calculate exponential backoff duration
-fn calculate_backoff(retry_count: u32) -> Duration {
-    Duration::from_secs(2_u64.saturating_pow(retry_count))
+/// Whether a failed response is worth retrying
+fn is_retryable(status: StatusCode, stripe_should_retry: Option<bool>) -> bool {
+    stripe_should_retry == Some(true)
+        || status == StatusCode::CONFLICT
+        || status == StatusCode::TOO_MANY_REQUESTS
+        || !status.is_client_error()
+}
+
+/// The policy `ExponentialBackoff(n)` waits by: 1s doubling up to the default cap, with full jitter
+fn backoff_policy(max_retries: u32) -> RetryPolicy {
+    RetryPolicy::new(max_retries).base_delay(Duration::from_secs(1))
+}
+
+/// A random idempotency key: 128 random bits formatted like a version 4 UUID
+pub fn random_idempotency_key() -> String {
+    let high = (random_u64() & 0xffff_ffff_ffff_0fff) | 0x4000;
+    let low = (random_u64() & 0x3fff_ffff_ffff_ffff) | 0x8000_0000_0000_0000;
+    format!(
+        "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+        high >> 32,
+        (high >> 16) & 0xffff,
+        high & 0xffff,
+        low >> 48,
+        low & 0xffff_ffff_ffff
+    )
+}
+
+/// A random number in `[0, 1)`
+fn random_fraction() -> f64 {
+    (random_u64() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// 64 random bits from the standard library's randomly seeded hasher
+///
+/// Every `RandomState` gets new keys, so no two calls hash alike.
+pub(crate) fn random_u64() -> u64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_nanos());
+    hasher.finish()
 }
 
 /// The outcome of testing a request strategy
@@ -118,10 +268,20 @@ mod tests {
     #[test]
     fn test_backoff_strategy() {
         let strategy = RequestStrategy::ExponentialBackoff(3);
-        assert_eq!(strategy.test(None, None, 0), Outcome::Continue(Some(Duration::from_secs(1))));
-        assert_eq!(strategy.test(None, None, 1), Outcome::Continue(Some(Duration::from_secs(2))));
-        assert_eq!(strategy.test(None, None, 2), Outcome::Continue(Some(Duration::from_secs(4))));
+        for (retry, max) in [(0, 1), (1, 2), (2, 4)] {
+            match strategy.test(None, None, retry) {
+                Outcome::Continue(Some(delay)) => assert!(delay <= Duration::from_secs(max)),
+                other => panic!("expected a delay, got {:?}", other),
+            }
+        }
         assert_eq!(strategy.test(None, None, 3), Outcome::Stop);
+
+        // Delays stop doubling at 8s, however many retries are allowed
+        let strategy = RequestStrategy::ExponentialBackoff(100);
+        match strategy.test_network_error(64) {
+            Outcome::Continue(Some(delay)) => assert!(delay <= Duration::from_secs(8)),
+            other => panic!("expected a delay, got {:?}", other),
+        }
     }
 
     #[test]
@@ -129,4 +289,75 @@ mod tests {
         let strategy = RequestStrategy::Retry(3);
         assert_eq!(strategy.test(None, Some(false), 0), Outcome::Stop);
     }
+
+    #[test]
+    fn test_random_keys() {
+        let strategy = RequestStrategy::Retry(3);
+        let key = strategy.get_key().unwrap();
+        assert_eq!(key.len(), 36);
+        assert_eq!(&key[14..15], "4");
+        assert_ne!(strategy.get_key(), Some(key));
+    }
+
+    #[test]
+    fn test_conflict_and_rate_limit_are_retried() {
+        let strategy = RequestStrategy::Retry(3);
+        assert_eq!(strategy.test(Some(StatusCode::CONFLICT), None, 1), Outcome::Continue(None));
+        assert_eq!(strategy.test(Some(StatusCode::TOO_MANY_REQUESTS), None, 1), Outcome::Continue(None));
+        assert_eq!(strategy.test(Some(StatusCode::BAD_REQUEST), None, 1), Outcome::Stop);
+        assert_eq!(strategy.test(Some(StatusCode::BAD_REQUEST), Some(true), 1), Outcome::Continue(None));
+        assert_eq!(strategy.test(Some(StatusCode::TOO_MANY_REQUESTS), Some(false), 1), Outcome::Stop);
+    }
+
+    #[test]
+    fn test_retry_after() {
+        let retry_after = Some(Duration::from_secs(7));
+        let strategy = RequestStrategy::ExponentialBackoff(3);
+        assert_eq!(
+            strategy.test_response(Some(StatusCode::TOO_MANY_REQUESTS), None, retry_after, 1),
+            Outcome::Continue(retry_after)
+        );
+
+        let policy = RetryPolicy::new(3).max_delay(Duration::from_secs(5));
+        assert_eq!(
+            RequestStrategy::Policy(policy).test_response(Some(StatusCode::TOO_MANY_REQUESTS), None, retry_after, 1),
+            Outcome::Continue(Some(Duration::from_secs(5)))
+        );
+    }
+
+    #[test]
+    fn test_policy_backoff() {
+        let policy = RetryPolicy::new(4)
+            .base_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_millis(300))
+            .jitter(false);
+        let strategy = RequestStrategy::Policy(policy);
+        assert_eq!(strategy.test(None, None, 0), Outcome::Continue(None));
+        assert_eq!(strategy.test(None, None, 1), Outcome::Continue(Some(Duration::from_millis(100))));
+        assert_eq!(strategy.test(None, None, 2), Outcome::Continue(Some(Duration::from_millis(200))));
+        assert_eq!(strategy.test(None, None, 3), Outcome::Continue(Some(Duration::from_millis(300))));
+        assert_eq!(strategy.test(None, None, 4), Outcome::Continue(Some(Duration::from_millis(300))));
+        assert_eq!(strategy.test(None, None, 5), Outcome::Stop);
+    }
+
+    #[test]
+    fn test_policy_jitter() {
+        let policy = RetryPolicy::new(3).base_delay(Duration::from_secs(1)).max_delay(Duration::from_secs(2));
+        for retry in 0..10 {
+            assert!(policy.delay(retry, None) <= Duration::from_secs(2));
+        }
+    }
+
+    #[test]
+    fn test_network_retries() {
+        assert_eq!(RequestStrategy::Once.test_network_error(1), Outcome::Stop);
+        assert_eq!(RequestStrategy::Retry(2).test_network_error(1), Outcome::Continue(None));
+        assert_eq!(RequestStrategy::Retry(2).test_network_error(2), Outcome::Stop);
+
+        let policy = RetryPolicy::new(3).network_retries(1).jitter(false);
+        let strategy = RequestStrategy::Policy(policy);
+        assert_eq!(strategy.test_network_error(1), Outcome::Continue(Some(Duration::from_millis(500))));
+        assert_eq!(strategy.test_network_error(2), Outcome::Stop);
+        assert_eq!(strategy.test(Some(StatusCode::SERVICE_UNAVAILABLE), None, 2), Outcome::Continue(Some(Duration::from_secs(1))));
+    }
 }
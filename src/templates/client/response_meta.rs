@@ -0,0 +1,66 @@
+use std::time::Duration;
+
+use reqwest::header::HeaderMap;
+
+/// What Stripe sent back about a request, besides the body
+///
+/// Failed requests carry it on [`RequestError`](crate::stripe::RequestError).
+/// For successful ones, wrap the call in
+/// [`StripeClient::with_meta`](super::Client::with_meta).
+#[derive(Clone, Debug, Default)]
+pub struct ResponseMeta {
+    /// The HTTP status
+    pub status: u16,
+    /// The `Request-Id` header, which Stripe support asks for
+    pub request_id: Option<String>,
+    /// The idempotency key the request was sent with
+    pub idempotency_key: Option<String>,
+    /// The `Stripe-Version` the request was handled with
+    pub stripe_version: Option<String>,
+    /// The `Stripe-Rate-Limited-Reason` header, on rate-limited requests
+    pub rate_limited_reason: Option<String>,
+    /// How long Stripe asked to wait before retrying
+    pub retry_after: Option<Duration>,
+    /// Every response header, for anything not picked out above
+    pub headers: HeaderMap,
+}
+
+impl ResponseMeta {
+    /// Read the metadata of a response
+    pub fn new(status: u16, headers: &HeaderMap, idempotency_key: Option<String>) -> Self {
+        let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok()).map(str::to_string);
+        Self {
+            status,
+            request_id: header("request-id"),
+            idempotency_key,
+            stripe_version: header("stripe-version"),
+            rate_limited_reason: header("stripe-rate-limited-reason"),
+            retry_after: header("retry-after")
+                .and_then(|value| value.trim().parse::<u64>().ok())
+                .map(Duration::from_secs),
+            headers: headers.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_response_meta() {
+        let mut headers = HeaderMap::new();
+        headers.insert("request-id", "req_123".parse().unwrap());
+        headers.insert("stripe-version", "2024-06-20".parse().unwrap());
+        headers.insert("stripe-rate-limited-reason", "global-rate".parse().unwrap());
+        headers.insert("retry-after", "2".parse().unwrap());
+
+        let meta = ResponseMeta::new(429, &headers, Some("key-1".to_string()));
+        assert_eq!(meta.status, 429);
+        assert_eq!(meta.request_id.as_deref(), Some("req_123"));
+        assert_eq!(meta.idempotency_key.as_deref(), Some("key-1"));
+        assert_eq!(meta.stripe_version.as_deref(), Some("2024-06-20"));
+        assert_eq!(meta.rate_limited_reason.as_deref(), Some("global-rate"));
+        assert_eq!(meta.retry_after, Some(Duration::from_secs(2)));
+    }
+}
@@ -1,6 +1,12 @@
-use std::time::Duration;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
 
-use reqwest::{Client as ReqwestClient, Method, StatusCode, RequestBuilder, Url};
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    Method, Url,
+};
+use futures_util::TryStreamExt;
 use serde::{de::DeserializeOwned, Serialize};
 use tokio::time::sleep;
 
@@ -12,46 +18,91 @@ use crate::stripe::{
 };
 
 use super::{
+    http_transport::{
+        BodyStream, HttpRequest, HttpResponse, HttpTransport, ReqwestTransport, StreamingResponse, TransportError,
+    },
+    interceptor::Interceptor,
+    multipart::Multipart,
+    request_options::RequestOptions,
     request_strategy::{Outcome, RequestStrategy},
-    http_client::{Response, err, ok},
+    response_meta::ResponseMeta,
+    http_client::{ByteStream, Download, Response, err, map},
 };
 
 /// Client agent identifier
 static USER_AGENT: &str = concat!("Stripe/v1 RustBindings/", env!("CARGO_PKG_VERSION"));
 
+/// Base URL of the Stripe API
+static API_BASE: &str = "https://api.stripe.com/";
+
+/// Base URL for file uploads and downloads
+static UPLOADS_BASE: &str = "https://files.stripe.com/";
+
 /// Main client for interacting with the Stripe API
+///
+/// With the `blocking` feature every request returns its result directly. The
+/// client then runs its requests on a runtime of its own, so it must not be
+/// used from inside another async runtime.
 #[derive(Clone)]
 pub struct StripeClient {
-    client: ReqwestClient,
+    transport: Arc<dyn HttpTransport>,
+    interceptors: Vec<Arc<dyn Interceptor>>,
+    #[cfg(feature = "blocking")]
+    runtime: Arc<tokio::runtime::Runtime>,
     secret_key: String,
     headers: Headers,
     strategy: RequestStrategy,
+    options: RequestOptions,
+    /// Where to keep the metadata of each response, inside `with_meta`
+    last_meta: Option<Arc<Mutex<Option<ResponseMeta>>>>,
     app_info: Option<AppInfo>,
     api_base: Url,
+    uploads_base: Url,
     api_root: String,
 }
 
 impl StripeClient {
     /// Create a new client with the given secret key
     pub fn new(secret_key: impl Into<String>) -> Result<Self, StripeError> {
-        Self::from_url("https://api.stripe.com/", secret_key)
+        Self::with_transport(secret_key, ReqwestTransport::new()?)
     }
 
     /// Create a new client pointed at a specific URL (useful for testing)
+    ///
+    /// File uploads and downloads go to the same URL.
     pub fn from_url<'a>(url: impl Into<&'a str>, secret_key: impl Into<String>) -> Result<Self, StripeError> {
-        let client = ReqwestClient::builder()
-            .timeout(Duration::from_secs(30))
-            .connect_timeout(Duration::from_secs(10))
-            .pool_idle_timeout(Some(Duration::from_secs(60)))
-            .user_agent(USER_AGENT)
-            .build()
-            .map_err(|e| StripeError::ClientError(format!("Failed to create HTTP client: {}", e)))?;
+        Self::from_url_with_transport(url, secret_key, ReqwestTransport::new()?)
+    }
 
-        let api_base = Url::parse(url.into())
-            .map_err(|e| StripeError::ClientError(format!("Invalid URL: {}", e)))?;
+    /// Create a new client that sends its requests through `transport`
+    pub fn with_transport(
+        secret_key: impl Into<String>,
+        transport: impl HttpTransport + 'static,
+    ) -> Result<Self, StripeError> {
+        let mut client = Self::from_url_with_transport(API_BASE, secret_key, transport)?;
+        client.uploads_base = parse_url(UPLOADS_BASE)?;
+        Ok(client)
+    }
+
+    /// Create a new client pointed at a specific URL that sends its requests through `transport`
+    pub fn from_url_with_transport<'a>(
+        url: impl Into<&'a str>,
+        secret_key: impl Into<String>,
+        transport: impl HttpTransport + 'static,
+    ) -> Result<Self, StripeError> {
+        let api_base = parse_url(url.into())?;
+
+        #[cfg(feature = "blocking")]
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| StripeError::ClientError(format!("Failed to create runtime: {}", e)))?;
 
         Ok(Self {
-            client,
+            transport: Arc::new(transport),
+            interceptors: Vec::new(),
+            #[cfg(feature = "blocking")]
+            runtime: Arc::new(runtime),
             secret_key: secret_key.into(),
             headers: Headers {
                 stripe_version: ApiVersion::default(),
@@ -60,7 +111,10 @@ impl StripeClient {
                 stripe_account: None,
             },
             strategy: RequestStrategy::Once,
+            options: RequestOptions::default(),
+            last_meta: None,
             app_info: None,
+            uploads_base: api_base.clone(),
             api_base,
             api_root: "v1".to_string(),
         })
@@ -84,6 +138,45 @@ impl StripeClient {
         self
     }
 
+    /// Add an interceptor, called around every request after the ones already added
+    pub fn with_interceptor(mut self, interceptor: impl Interceptor + 'static) -> Self {
+        self.interceptors.push(Arc::new(interceptor));
+        self
+    }
+
+    /// A view of the client that sends `options` with every request
+    ///
+    /// The view shares the connection pool and can be passed anywhere a client
+    /// is expected, so the generated `create`/`update`/`list` helpers pick the
+    /// options up. Options already set on this client are kept unless `options`
+    /// overrides them.
+    pub fn with_options(&self, options: RequestOptions) -> Self {
+        let mut client = self.clone();
+        client.options = client.options.merge(options);
+        client
+    }
+
+    /// Make the requests in `call`, returning its result with the metadata of the last response
+    ///
+    /// This works with any generated helper:
+    ///
+    /// ```ignore
+    /// let (customer, meta) = client.with_meta(|client| Customer::create(client, params)).await?;
+    /// println!("created in request {:?}", meta.request_id);
+    /// ```
+    pub fn with_meta<T: Send + 'static>(
+        &self,
+        call: impl FnOnce(&Self) -> Response<T>,
+    ) -> Response<(T, ResponseMeta)> {
+        let slot = Arc::new(Mutex::new(None));
+        let mut client = self.clone();
+        client.last_meta = Some(Arc::clone(&slot));
+        map(call(&client), move |value| {
+            let meta = slot.lock().ok().and_then(|mut meta| meta.take()).unwrap_or_default();
+            (value, meta)
+        })
+    }
+
     /// Set the application info for the client
     pub fn with_app_info(
         mut self,
@@ -99,156 +192,251 @@ impl StripeClient {
 
     /// Make a `GET` http request with just a path
     pub fn get<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Response<T> {
-        let url = self.url(path);
-        self.execute(self.create_request(Method::GET, url, None::<&()>))
+        self.send(self.create_request(Method::GET, path, None::<&()>, None::<&()>))
     }
 
     /// Make a `GET` http request with url query parameters
-    pub fn get_query<T: DeserializeOwned + Send + 'static, P: Serialize + Send + 'static>(
+    ///
+    /// `params` may be passed by value or by reference.
+    pub fn get_query<T: DeserializeOwned + Send + 'static, P: Serialize>(
         &self,
         path: &str,
-        params: &P,
+        params: P,
     ) -> Response<T> {
-        let request = match self.create_query_request(Method::GET, path, params) {
-            Ok(req) => req,
-            Err(e) => return super::http_client::err(e),
-        };
-        self.execute(request)
+        self.send(self.create_request(Method::GET, path, Some(&params), None::<&()>))
     }
 
     /// Make a `DELETE` http request with just a path
     pub fn delete<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Response<T> {
-        let url = self.url(path);
-        self.execute(self.create_request(Method::DELETE, url, None::<&()>))
+        self.send(self.create_request(Method::DELETE, path, None::<&()>, None::<&()>))
     }
 
     /// Make a `DELETE` http request with url query parameters
-    pub fn delete_query<T: DeserializeOwned + Send + 'static, P: Serialize + Send + 'static>(
+    pub fn delete_query<T: DeserializeOwned + Send + 'static, P: Serialize>(
         &self,
         path: &str,
-        params: &P,
+        params: P,
     ) -> Response<T> {
-        let request = match self.create_query_request(Method::DELETE, path, params) {
-            Ok(req) => req,
-            Err(e) => return super::http_client::err(e),
-        };
-        self.execute(request)
+        self.send(self.create_request(Method::DELETE, path, Some(&params), None::<&()>))
     }
 
     /// Make a `POST` http request with just a path
     pub fn post<T: DeserializeOwned + Send + 'static>(&self, path: &str) -> Response<T> {
-        let url = self.url(path);
-        self.execute(self.create_request(Method::POST, url, None::<&()>))
+        self.send(self.create_request(Method::POST, path, None::<&()>, None::<&()>))
     }
 
     /// Make a `POST` http request with urlencoded body
-    pub fn post_form<T: DeserializeOwned + Send + 'static, F: Serialize + Send + 'static>(
+    pub fn post_form<T: DeserializeOwned + Send + 'static, F: Serialize>(
         &self,
         path: &str,
-        form: &F,
+        form: F,
     ) -> Response<T> {
-        let url = self.url(path);
-        let request = self.create_request(Method::POST, url, Some(form))
-            .header("content-type", "application/x-www-form-urlencoded");
-        self.execute(request)
+        self.send(self.create_request(Method::POST, path, None::<&()>, Some(&form)))
+    }
+
+    /// Make a `POST` http request with a multipart body to the uploads API
+    pub fn post_multipart<T: DeserializeOwned + Send + 'static>(&self, path: &str, form: Multipart) -> Response<T> {
+        let request = self.create_request(Method::POST, path, None::<&()>, None::<&()>).and_then(|mut request| {
+            request.url = self.uploads_url(path);
+            let content_type = HeaderValue::from_str(&form.content_type())
+                .map_err(|e| StripeError::ClientError(format!("Invalid multipart boundary: {}", e)))?;
+            request.headers.insert("content-type", content_type);
+            request.body = Some(form.into_body());
+            Ok(request)
+        });
+        self.send(request)
+    }
+
+    /// Make a `GET` http request to the uploads API, streaming the body
+    pub fn download(&self, path: &str) -> Response<Download> {
+        let mut request = match self.create_request(Method::GET, path, None::<&()>, None::<&()>) {
+            Ok(request) => request,
+            Err(e) => return err(e),
+        };
+        request.url = self.uploads_url(path);
+
+        let body = self.execute_with(request, Decode::Stream(|body| body));
+        #[cfg(feature = "blocking")]
+        let body = {
+            let runtime = Arc::clone(&self.runtime);
+            map(body, move |body| Download::new(body, runtime))
+        };
+        body
     }
 
     /// Create a URL for the given path
     fn url(&self, path: &str) -> Url {
-        let mut url = self.api_base.clone();
+        self.url_on(&self.api_base, path)
+    }
+
+    /// Create a URL for the given path on the uploads API
+    fn uploads_url(&self, path: &str) -> Url {
+        self.url_on(&self.uploads_base, path)
+    }
+
+    fn url_on(&self, base: &Url, path: &str) -> Url {
+        let mut url = base.clone();
         url.set_path(&format!("{}/{}", self.api_root, path.trim_start_matches('/')));
         url
     }
 
-    /// Create a request builder with the appropriate headers and parameters
-    fn create_request<P: Serialize + ?Sized>(
+    /// Create a request with the appropriate headers, query string and form body
+    fn create_request<Q: Serialize, F: Serialize>(
         &self,
         method: Method,
-        url: Url,
-        params: Option<&P>,
-    ) -> RequestBuilder {
-        let mut builder = self.client.request(method, url)
-            .header("authorization", format!("Bearer {}", self.secret_key))
-            .header("stripe-version", self.headers.stripe_version.as_str())
-            .header("user-agent", &self.headers.user_agent);
+        path: &str,
+        query: Option<&Q>,
+        form: Option<&F>,
+    ) -> Result<HttpRequest, StripeError> {
+        let options = &self.options;
+        let stripe_version = options.stripe_version.unwrap_or(self.headers.stripe_version);
+
+        let mut headers = vec![
+            ("authorization", format!("Bearer {}", self.secret_key)),
+            ("stripe-version", stripe_version.as_str().to_string()),
+            ("user-agent", self.headers.user_agent.clone()),
+        ];
 
         // Set optional headers
         if let Some(client_id) = &self.headers.client_id {
-            builder = builder.header("client-id", client_id.as_str());
+            headers.push(("client-id", client_id.as_str().to_string()));
         }
-        if let Some(account) = &self.headers.stripe_account {
-            builder = builder.header("stripe-account", account.as_str());
+        if let Some(account) = options.stripe_account.as_ref().or(self.headers.stripe_account.as_ref()) {
+            headers.push(("stripe-account", account.as_str().to_string()));
+        }
+        if form.is_some() {
+            headers.push(("content-type", "application/x-www-form-urlencoded".to_string()));
         }
 
-        // If idempotency key is set in the request strategy, add it
-        if let Some(key) = self.strategy.get_key() {
-            builder = builder.header("idempotency-key", key);
+        let mut header_map = HeaderMap::new();
+        let extra = options.headers.iter().map(|(name, value)| (name.as_str(), value.clone()));
+        for (name, value) in headers.into_iter().chain(extra) {
+            let name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|e| StripeError::ClientError(format!("Invalid header name {}: {}", name, e)))?;
+            let value = HeaderValue::from_str(&value)
+                .map_err(|e| StripeError::ClientError(format!("Invalid value for header {}: {}", name, e)))?;
+            header_map.append(name, value);
         }
 
-        // Add parameters if provided
-        if let Some(params) = params {
-            builder = builder.form(params);
+        let mut url = self.url(path);
+        if let Some(query) = query {
+            let query = encode(query)?;
+            if !query.is_empty() {
+                url.set_query(Some(&query));
+            }
         }
+        let body = form.map(encode).transpose()?.map(String::into_bytes);
 
-        builder
+        Ok(HttpRequest { method, url, headers: header_map, body, timeout: options.timeout })
     }
 
-    /// Create a request with query parameters
-    fn create_query_request<P: Serialize>(
-        &self,
-        method: Method,
-        path: &str,
-        params: &P,
-    ) -> Result<RequestBuilder, StripeError> {
-        let url = self.url(path);
-        let request = self.create_request(method, url, None::<&()>);
-        
-        Ok(request.query(params))
+    /// Execute a request that was built, or fail with the error building it
+    fn send<T: DeserializeOwned + Send + 'static>(&self, request: Result<HttpRequest, StripeError>) -> Response<T> {
+        match request {
+            Ok(request) => self.execute(request),
+            Err(e) => err(e),
+        }
     }
 
-    /// Execute a request with the configured strategy
-    fn execute<T: DeserializeOwned + Send + 'static>(
-        &self,
-        request: RequestBuilder,
-    ) -> Response<T> {
+    /// Execute a request with the configured strategy, parsing the JSON response
+    fn execute<T: DeserializeOwned + Send + 'static>(&self, request: HttpRequest) -> Response<T> {
+        self.execute_with(request, Decode::Body(|body| {
+            // Use serde_path_to_error to get better error messages with paths
+            let json_deserializer = &mut serde_json::Deserializer::from_slice(&body);
+            serde_path_to_error::deserialize(json_deserializer).map_err(StripeError::JSONSerialize)
+        }))
+    }
+
+    /// Execute a request with the configured strategy, handing a successful body to `decode`
+    ///
+    /// The idempotency key is picked once here, so every retry sends the same one.
+    fn execute_with<T: Send + 'static>(&self, mut request: HttpRequest, decode: Decode<T>) -> Response<T> {
         let strategy = self.strategy.clone();
+        let transport = Arc::clone(&self.transport);
+
+        // An explicit idempotency key wins over the request strategy's
+        let idempotency_key = self.options.idempotency_key.clone().or_else(|| strategy.get_key());
+        if let Some(key) = &idempotency_key {
+            match HeaderValue::from_str(key) {
+                Ok(value) => {
+                    request.headers.insert("idempotency-key", value);
+                }
+                Err(e) => return err(StripeError::ClientError(format!("Invalid idempotency key: {}", e))),
+            }
+        }
+
+        let interceptors = self.interceptors.clone();
+        let last_meta = self.last_meta.clone();
+        #[cfg(feature = "tracing")]
+        let span = tracing::info_span!(
+            "stripe.request",
+            method = %request.method,
+            path = request.url.path(),
+            attempt = tracing::field::Empty,
+            status = tracing::field::Empty,
+            request_id = tracing::field::Empty,
+            elapsed_ms = tracing::field::Empty,
+        );
+
+        let call = async move {
+            let started = Instant::now();
 
-        Box::pin(async move {
+            // Attempts answered with an HTTP response, and attempts that failed
+            // without one; each has its own retry budget
             let mut tries = 0;
-            let mut last_status: Option<StatusCode> = None;
-            let mut last_retry_header: Option<bool> = None;
+            let mut network_errors = 0;
+            let mut outcome = Outcome::Continue(None);
             let mut last_error = StripeError::ClientError("Invalid strategy".to_string());
 
             loop {
-                match strategy.test(last_status, last_retry_header, tries) {
-                    Outcome::Stop => return Err(last_error),
+                match outcome {
+                    Outcome::Stop => {
+                        record("elapsed_ms", started.elapsed().as_millis() as u64);
+                        return Err(last_error);
+                    }
                     Outcome::Continue(duration) => {
                         if let Some(duration) = duration {
                             sleep(duration).await;
                         }
 
-                        // Clone the request for this attempt
-                        // We need a new clone for each iteration since send() consumes the builder
-                        let request_clone = request.try_clone()
-                            .ok_or_else(|| StripeError::ClientError("Failed to clone request".to_string()))?;
+                        // Interceptors may change this attempt, but not the ones after it
+                        let attempt = tries + network_errors + 1;
+                        let mut sent = request.clone();
+                        for interceptor in &interceptors {
+                            interceptor.before_request(&mut sent, attempt);
+                        }
+                        record("attempt", attempt);
 
                         // Send the request
-                        let response = match request_clone.send().await {
-                            Ok(response) => response,
-                            Err(err) => {
-                                last_error = if err.is_timeout() {
-                                    StripeError::Timeout
-                                } else {
-                                    StripeError::ClientError(format!("HTTP request error: {}", err))
-                                };
-                                tries += 1;
+                        let attempt_started = Instant::now();
+                        let fetched = fetch(transport.as_ref(), sent.clone(), matches!(decode, Decode::Stream(_))).await;
+                        let (response, stream) = match fetched {
+                            Ok(fetched) => fetched,
+                            Err(error) => {
+                                last_error = stripe_error(error, idempotency_key.clone());
+                                network_errors += 1;
+                                outcome = strategy.test_network_error(network_errors);
+                                notify_retry(&interceptors, &sent, attempt, &last_error, &outcome);
                                 continue;
                             }
                         };
+                        for interceptor in &interceptors {
+                            interceptor.after_response(&sent, &response, attempt_started.elapsed());
+                        }
 
-                        let status = response.status();
+                        let status = response.status;
+                        let meta = ResponseMeta::new(status.as_u16(), &response.headers, idempotency_key.clone());
+                        record("status", status.as_u16());
+                        if let Some(request_id) = &meta.request_id {
+                            record("request_id", request_id.as_str());
+                        }
+                        if let Some(slot) = &last_meta {
+                            if let Ok(mut slot) = slot.lock() {
+                                *slot = Some(meta.clone());
+                            }
+                        }
                         let retry = response
-                            .headers()
+                            .headers
                             .get("stripe-should-retry")
                             .and_then(|s| s.to_str().ok())
                             .and_then(|s| s.parse::<bool>().ok());
@@ -256,59 +444,147 @@ impl StripeClient {
                         // Check for error responses
                         if !status.is_success() {
                             tries += 1;
-                            
-                            // Attempt to parse the error response
-                            let bytes = match response.bytes().await {
-                                Ok(bytes) => bytes,
-                                Err(e) => {
-                                    last_error = StripeError::ClientError(format!(
-                                        "HTTP error {} and failed to read body: {}", status, e
-                                    ));
-                                    last_status = Some(status);
-                                    last_retry_header = retry;
-                                    continue;
-                                }
-                            };
-                            
+                            outcome = strategy.test_response(Some(status), retry, meta.retry_after, tries);
+
                             // Use serde_path_to_error for better error messages
-                            let json_deserializer = &mut serde_json::Deserializer::from_slice(&bytes);
+                            let json_deserializer = &mut serde_json::Deserializer::from_slice(&response.body);
                             match serde_path_to_error::deserialize::<_, ErrorResponse>(json_deserializer) {
                                 Ok(mut err_response) => {
                                     err_response.error.http_status = status.as_u16();
+                                    err_response.error.idempotency_key = idempotency_key.clone();
+                                    err_response.error.request_id = meta.request_id.clone();
+                                    err_response.error.meta = meta;
                                     last_error = StripeError::Stripe(err_response.error);
                                 }
                                 Err(_) => {
                                     // Failed to parse the response as JSON
-                                    let text = String::from_utf8_lossy(&bytes);
+                                    let text = String::from_utf8_lossy(&response.body);
                                     last_error = StripeError::ClientError(format!(
                                         "HTTP error {}: {}", status, text
                                     ));
                                 }
                             }
-                            
-                            last_status = Some(status);
-                            last_retry_header = retry;
+                            notify_retry(&interceptors, &sent, attempt, &last_error, &outcome);
                             continue;
                         }
 
-                        // Successfully received response
-                        let bytes = response.bytes().await
-                            .map_err(|e| StripeError::ClientError(format!("Failed to get response body: {}", e)))?;
-                            
-                        // Use serde_path_to_error to get better error messages with paths
-                        let json_deserializer = &mut serde_json::Deserializer::from_slice(&bytes);
-                        return serde_path_to_error::deserialize(json_deserializer)
-                            .map_err(StripeError::JSONSerialize);
+                        record("elapsed_ms", started.elapsed().as_millis() as u64);
+                        return match (decode, stream) {
+                            (Decode::Stream(decode), Some(stream)) => {
+                                let key = idempotency_key.clone();
+                                let stream: ByteStream =
+                                    Box::pin(stream.map_err(move |error| stripe_error(error, key.clone())));
+                                Ok(decode(stream))
+                            }
+                            (Decode::Body(decode), _) => decode(response.body),
+                            (Decode::Stream(_), None) => unreachable!("successful streamed responses keep their body"),
+                        };
                     }
                 }
             }
-        })
+        };
+
+        #[cfg(feature = "tracing")]
+        let call = tracing::Instrument::instrument(call, span);
+        self.run(call)
+    }
+
+    /// Hand the request to the caller as a future
+    #[cfg(not(feature = "blocking"))]
+    fn run<T: Send + 'static>(
+        &self,
+        request: impl Future<Output = Result<T, StripeError>> + Send + 'static,
+    ) -> Response<T> {
+        Box::pin(request)
+    }
+
+    /// Run the request to completion on the client's runtime
+    #[cfg(feature = "blocking")]
+    fn run<T>(&self, request: impl Future<Output = Result<T, StripeError>>) -> Response<T> {
+        self.runtime.block_on(request)
     }
 }
 
+/// What to do with the body of a successful response
+enum Decode<T> {
+    /// Wait for the whole body and decode it
+    Body(fn(Vec<u8>) -> Result<T, StripeError>),
+    /// Hand the body over as it arrives
+    Stream(fn(ByteStream) -> T),
+}
+
+/// Send one attempt of a request
+///
+/// With `stream` set, the body of a successful response is returned
+/// unread, and the response passed to interceptors has an empty body.
+async fn fetch(
+    transport: &dyn HttpTransport,
+    request: HttpRequest,
+    stream: bool,
+) -> Result<(HttpResponse, Option<BodyStream>), TransportError> {
+    if !stream {
+        return Ok((transport.send(request).await?, None));
+    }
+
+    let StreamingResponse { status, headers, body } = transport.send_streaming(request).await?;
+    if status.is_success() {
+        return Ok((HttpResponse { status, headers, body: Vec::new() }, Some(body)));
+    }
+    let body = body.try_concat().await?;
+    Ok((HttpResponse { status, headers, body }, None))
+}
+
+/// The error for a request that got no response
+fn stripe_error(error: TransportError, idempotency_key: Option<String>) -> StripeError {
+    match error {
+        TransportError::Timeout => StripeError::Timeout { idempotency_key },
+        TransportError::Connection(message) => StripeError::Connection { message, idempotency_key },
+    }
+}
+
+/// Tell the interceptors, and the trace, that a failed attempt will be retried
+fn notify_retry(
+    interceptors: &[Arc<dyn Interceptor>],
+    request: &HttpRequest,
+    attempt: u32,
+    error: &StripeError,
+    outcome: &Outcome,
+) {
+    if let Outcome::Continue(delay) = outcome {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(attempt, ?delay, %error, "retrying Stripe request");
+        for interceptor in interceptors {
+            interceptor.on_retry(request, attempt, error, *delay);
+        }
+    }
+}
+
+/// Record a field on the span of the request being made
+#[cfg(feature = "tracing")]
+fn record<V: tracing::Value>(field: &str, value: V) {
+    tracing::Span::current().record(field, value);
+}
+
+#[cfg(not(feature = "tracing"))]
+fn record<V>(_field: &str, _value: V) {}
+
+fn parse_url(url: &str) -> Result<Url, StripeError> {
+    Url::parse(url).map_err(|e| StripeError::ClientError(format!("Invalid URL: {}", e)))
+}
+
+/// Encode parameters the way Stripe expects, with `a[b]=c` for nested values
+fn encode<P: Serialize>(params: &P) -> Result<String, StripeError> {
+    serde_qs::to_string(params)
+        .map_err(|e| StripeError::ClientError(format!("Failed to encode parameters: {}", e)))
+}
+
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use super::*;
+    use crate::stripe::client::testing::{wait, FakeTransport};
+    use crate::stripe::client::{HttpResponse, RetryPolicy};
     use crate::stripe::AccountId;
 
     #[test]
@@ -351,12 +627,148 @@ mod tests {
         assert_eq!(url.as_str(), "https://api.stripe.com/v1/customers");
     }
 
+    #[test]
+    fn test_uploads_url() {
+        let client = StripeClient::new("sk_test_12345").unwrap();
+        assert_eq!(client.uploads_url("/files").as_str(), "https://files.stripe.com/v1/files");
+
+        let client = StripeClient::from_url("http://localhost:12111/", "sk_test_12345").unwrap();
+        assert_eq!(client.uploads_url("/files").as_str(), "http://localhost:12111/v1/files");
+    }
+
     #[test]
     fn test_stripe_account_header() {
         let account_id = "acct_12345".parse::<AccountId>().unwrap();
         let client = StripeClient::new("sk_test_12345").unwrap()
-            .with_stripe_account(account_id);
+            .with_stripe_account(account_id.clone());
         
         assert_eq!(client.headers.stripe_account, Some(account_id));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_with_options() {
+        let account_id = "acct_12345".parse::<AccountId>().unwrap();
+        let client = StripeClient::new("sk_test_12345").unwrap();
+        let scoped = client
+            .with_options(RequestOptions::new().idempotency_key("key-1").timeout(Duration::from_secs(5)))
+            .with_options(RequestOptions::new().idempotency_key("key-2").stripe_account(account_id.clone()));
+
+        assert_eq!(scoped.options.idempotency_key.as_deref(), Some("key-2"));
+        assert_eq!(scoped.options.stripe_account, Some(account_id));
+        assert_eq!(scoped.options.timeout, Some(Duration::from_secs(5)));
+        assert!(client.options.idempotency_key.is_none());
+    }
+
+    /// A client retrying a 429 once, and its transport
+    fn retrying_client() -> (StripeClient, FakeTransport) {
+        let transport = FakeTransport::new()
+            .respond(429, r#"{"error": {"type": "rate_limit_error"}}"#)
+            .respond(200, r#"{"id": "cus_123"}"#);
+        let policy = RetryPolicy::new(2).base_delay(Duration::from_millis(1));
+        let client = transport.client().with_strategy(RequestStrategy::Policy(policy));
+        (client, transport)
+    }
+
+    fn assert_retried_with_same_key(requests: &[HttpRequest]) {
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].body.as_deref(), Some("name=Jenny+Rosen".as_bytes()));
+        assert!(requests[0].headers.contains_key("idempotency-key"));
+        assert_eq!(requests[0].headers["idempotency-key"], requests[1].headers["idempotency-key"]);
+    }
+
+    #[test]
+    fn test_transport_retries_with_same_key() {
+        let (client, transport) = retrying_client();
+
+        let form = std::collections::BTreeMap::from([("name", "Jenny Rosen")]);
+        let customer: serde_json::Value = wait(client.post_form("customers", &form)).unwrap();
+        assert_eq!(customer["id"], "cus_123");
+        assert_retried_with_same_key(&transport.requests());
+    }
+
+    /// Records what it is called with
+    #[derive(Default)]
+    struct RecordingInterceptor {
+        calls: Arc<std::sync::Mutex<Vec<String>>>,
+    }
+
+    impl Interceptor for RecordingInterceptor {
+        fn before_request(&self, request: &mut HttpRequest, attempt: u32) {
+            request.headers.insert("x-attempt", HeaderValue::from(attempt));
+            self.calls.lock().unwrap().push(format!("before {}", attempt));
+        }
+
+        fn after_response(&self, _request: &HttpRequest, response: &HttpResponse, _elapsed: Duration) {
+            self.calls.lock().unwrap().push(format!("after {}", response.status.as_u16()));
+        }
+
+        fn on_retry(&self, _request: &HttpRequest, attempt: u32, _error: &StripeError, _delay: Option<Duration>) {
+            self.calls.lock().unwrap().push(format!("retry {}", attempt));
+        }
+    }
+
+    #[test]
+    fn test_interceptor_hooks() {
+        let (client, transport) = retrying_client();
+        let interceptor = RecordingInterceptor::default();
+        let calls = Arc::clone(&interceptor.calls);
+        let client = client.with_interceptor(interceptor);
+
+        let _: serde_json::Value = wait(client.get("customers/cus_123")).unwrap();
+        assert_eq!(*calls.lock().unwrap(), ["before 1", "after 429", "retry 1", "before 2", "after 200"]);
+
+        let requests = transport.requests();
+        assert_eq!(requests[0].headers["x-attempt"], "1");
+        assert_eq!(requests[1].headers["x-attempt"], "2");
+    }
+
+    #[test]
+    fn test_with_meta() {
+        let (client, _) = retrying_client();
+
+        let (customer, meta): (serde_json::Value, _) =
+            wait(client.with_meta(|client| client.get("customers/cus_123"))).unwrap();
+        assert_eq!(customer["id"], "cus_123");
+        assert_eq!(meta.status, 200);
+        assert_eq!(meta.request_id.as_deref(), Some("req_2"));
+        assert!(meta.idempotency_key.is_some());
+    }
+
+    /// Read a download to the end with the async client
+    #[cfg(not(feature = "blocking"))]
+    fn read_all(download: Download) -> Vec<u8> {
+        tokio::runtime::Runtime::new().unwrap().block_on(download.try_concat()).unwrap()
+    }
+
+    /// Read a download to the end with the blocking client
+    #[cfg(feature = "blocking")]
+    fn read_all(mut download: Download) -> Vec<u8> {
+        let mut contents = Vec::new();
+        std::io::Read::read_to_end(&mut download, &mut contents).unwrap();
+        contents
+    }
+
+    #[test]
+    fn test_download_streams_body() {
+        let (client, transport) = retrying_client();
+
+        let download = wait(client.download("files/file_123/contents")).unwrap();
+        assert_eq!(read_all(download), br#"{"id": "cus_123"}"#);
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].url.as_str(), "https://files.stripe.com/v1/files/file_123/contents");
+    }
+
+    #[test]
+    fn test_error_request_id() {
+        let client = FakeTransport::new().respond(400, r#"{"error": {"type": "invalid_request_error"}}"#).client();
+
+        let error = wait(client.get::<serde_json::Value>("customers/cus_123")).unwrap_err();
+        assert_eq!(error.request_id(), Some("req_1"));
+        match error {
+            StripeError::Stripe(error) => assert_eq!(error.meta.status, 400),
+            other => panic!("expected a Stripe error, got {:?}", other),
+        }
+    }
+}
@@ -3,6 +3,7 @@ use std::num::ParseIntError;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::stripe::client::ResponseMeta;
 use crate::stripe::params::to_snakecase;
 
 /// An error encountered when communicating with the Stripe API.
@@ -18,8 +19,57 @@ pub enum StripeError {
     UnsupportedVersion,
     #[error("error communicating with stripe: {0}")]
     ClientError(String),
+    #[error("error connecting to stripe: {message}")]
+    Connection {
+        message: String,
+        /// The idempotency key the request was sent with, to replay it
+        idempotency_key: Option<String>,
+    },
     #[error("timeout communicating with stripe")]
-    Timeout,
+    Timeout {
+        /// The idempotency key the request was sent with, to replay it
+        idempotency_key: Option<String>,
+    },
+}
+
+impl StripeError {
+    /// The idempotency key of the failed request, if it had one
+    ///
+    /// Sending the request again with the same key (see `RequestOptions`)
+    /// cannot apply it twice.
+    pub fn idempotency_key(&self) -> Option<&str> {
+        match self {
+            StripeError::Stripe(error) => error.idempotency_key.as_deref(),
+            StripeError::Connection { idempotency_key, .. } | StripeError::Timeout { idempotency_key } => {
+                idempotency_key.as_deref()
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether the card issuer declined the payment
+    pub fn is_card_decline(&self) -> bool {
+        matches!(self, StripeError::Stripe(error) if error.is_card_decline())
+    }
+
+    /// Whether the request may succeed if it is sent again unchanged
+    ///
+    /// Network errors and timeouts count, since the request may not have reached Stripe.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            StripeError::Stripe(error) => error.is_retryable(),
+            StripeError::Connection { .. } | StripeError::Timeout { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// The `Request-Id` Stripe gave the failed request, to quote to Stripe support
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            StripeError::Stripe(error) => error.request_id.as_deref(),
+            _ => None,
+        }
+    }
 }
 
 /// The list of possible values for a RequestError's type.
@@ -52,95 +102,316 @@ impl std::fmt::Display for ErrorType {
     }
 }
 
-/// The list of possible values for a RequestError's code.
-#[derive(Clone, Copy, Debug, Deserialize, Serialize, Eq, PartialEq, Hash)]
-#[serde(rename_all = "snake_case")]
-#[non_exhaustive]
-pub enum ErrorCode {
-    AccountAlreadyExists,
-    AccountCountryInvalidAddress,
-    AccountInvalid,
-    AccountNumberInvalid,
-    AlipayUpgradeRequired,
-    AmountTooLarge,
-    AmountTooSmall,
-    ApiKeyExpired,
-    BalanceInsufficient,
-    BankAccountExists,
-    BankAccountUnusable,
-    BankAccountUnverified,
-    BankAccountVerificationFailed,
-    BitcoinUpgradeRequired,
-    CardDeclined,
-    ChargeAlreadyCaptured,
-    ChargeAlreadyRefunded,
-    ChargeDisputed,
-    ChargeExpiredForCapture,
-    CountryUnsupported,
-    CouponExpired,
-    CustomerMaxSubscriptions,
-    EmailInvalid,
-    ExpiredCard,
-    IdempotencyKeyInUse,
-    IncorrectAddress,
-    IncorrectCvc,
-    IncorrectNumber,
-    IncorrectZip,
-    InstantPayoutsUnsupported,
-    InvalidCardType,
-    InvalidChargeAmount,
-    InvalidCvc,
-    InvalidExpiryMonth,
-    InvalidExpiryYear,
-    InvalidNumber,
-    InvalidSourceUsage,
-    InvoiceNoCustomerLineItems,
-    InvoiceNoSubscriptionLineItems,
-    InvoiceNotEditable,
-    InvoiceUpcomingNone,
-    LivemodeMismatch,
-    Missing,
-    OrderCreationFailed,
-    OrderRequiredSettings,
-    OrderStatusInvalid,
-    OrderUpstreamTimeout,
-    OutOfInventory,
-    ParameterInvalidEmpty,
-    ParameterInvalidInteger,
-    ParameterInvalidStringBlank,
-    ParameterInvalidStringEmpty,
-    ParameterMissing,
-    ParameterUnknown,
-    PaymentMethodUnactivated,
-    PaymentIntentUnexpectedState,
-    PayoutsNotAllowed,
-    PlatformApiKeyExpired,
-    PostalCodeInvalid,
-    ProcessingError,
-    ProductInactive,
-    RateLimit,
-    ResourceAlreadyExists,
-    ResourceMissing,
-    RoutingNumberInvalid,
-    SecretKeyRequired,
-    SepaUnsupportedAccount,
-    ShippingCalculationFailed,
-    SkuInactive,
-    StateUnsupported,
-    TaxIdInvalid,
-    TaxesCalculationFailed,
-    TestmodeChargesOnly,
-    TlsVersionUnsupported,
-    TokenAlreadyUsed,
-    TokenInUse,
-    TransfersNotAllowed,
-    UpstreamOrderCreationFailed,
-    UrlInvalid,
+/// Define a string enum with a variant per known value and an `Unknown`
+/// fallback, so values Stripe adds later still deserialize
+macro_rules! string_enum {
+    ($(#[$meta:meta])* $name:ident { $($variant:ident => $value:literal,)* }) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+        #[non_exhaustive]
+        pub enum $name {
+            $($variant,)*
+            /// A value these bindings do not know yet
+            Unknown(String),
+        }
+
+        impl $name {
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)*
+                    $name::Unknown(value) => value,
+                }
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = std::convert::Infallible;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                Ok(match value {
+                    $($value => $name::$variant,)*
+                    _ => $name::Unknown(value.to_string()),
+                })
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = String::deserialize(deserializer)?;
+                Ok(value.parse().unwrap_or_else(|never| match never {}))
+            }
+        }
+    };
 }
 
-impl std::fmt::Display for ErrorCode {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", to_snakecase(&format!("{:?}", self)))
+string_enum!(
+    /// The list of possible values for a RequestError's code.
+    ///
+    /// Generated from the `code` enum of the spec's `api_errors` schema.
+    ErrorCode {
+        AccountClosed => "account_closed",
+        AccountCountryInvalidAddress => "account_country_invalid_address",
+        AccountErrorCountryChangeRequiresAdditionalSteps => "account_error_country_change_requires_additional_steps",
+        AccountInformationMismatch => "account_information_mismatch",
+        AccountInvalid => "account_invalid",
+        AccountNumberInvalid => "account_number_invalid",
+        AcssDebitSessionIncomplete => "acss_debit_session_incomplete",
+        AlipayUpgradeRequired => "alipay_upgrade_required",
+        AmountTooLarge => "amount_too_large",
+        AmountTooSmall => "amount_too_small",
+        ApiKeyExpired => "api_key_expired",
+        ApplicationFeesNotAllowed => "application_fees_not_allowed",
+        AuthenticationRequired => "authentication_required",
+        BalanceInsufficient => "balance_insufficient",
+        BalanceInvalidParameter => "balance_invalid_parameter",
+        BankAccountBadRoutingNumbers => "bank_account_bad_routing_numbers",
+        BankAccountDeclined => "bank_account_declined",
+        BankAccountExists => "bank_account_exists",
+        BankAccountRestricted => "bank_account_restricted",
+        BankAccountUnusable => "bank_account_unusable",
+        BankAccountUnverified => "bank_account_unverified",
+        BankAccountVerificationFailed => "bank_account_verification_failed",
+        BillingInvalidMandate => "billing_invalid_mandate",
+        BitcoinUpgradeRequired => "bitcoin_upgrade_required",
+        CaptureChargeAuthorizationExpired => "capture_charge_authorization_expired",
+        CaptureUnauthorizedPayment => "capture_unauthorized_payment",
+        CardDeclineRateLimitExceeded => "card_decline_rate_limit_exceeded",
+        CardDeclined => "card_declined",
+        CardholderPhoneNumberRequired => "cardholder_phone_number_required",
+        ChargeAlreadyCaptured => "charge_already_captured",
+        ChargeAlreadyRefunded => "charge_already_refunded",
+        ChargeDisputed => "charge_disputed",
+        ChargeExceedsSourceLimit => "charge_exceeds_source_limit",
+        ChargeExceedsTransactionLimit => "charge_exceeds_transaction_limit",
+        ChargeExpiredForCapture => "charge_expired_for_capture",
+        ChargeInvalidParameter => "charge_invalid_parameter",
+        ChargeNotRefundable => "charge_not_refundable",
+        ClearingCodeUnsupported => "clearing_code_unsupported",
+        CountryCodeInvalid => "country_code_invalid",
+        CountryUnsupported => "country_unsupported",
+        CouponExpired => "coupon_expired",
+        CustomerMaxPaymentMethods => "customer_max_payment_methods",
+        CustomerMaxSubscriptions => "customer_max_subscriptions",
+        CustomerTaxLocationInvalid => "customer_tax_location_invalid",
+        DebitNotAuthorized => "debit_not_authorized",
+        EmailInvalid => "email_invalid",
+        ExpiredCard => "expired_card",
+        FinancialConnectionsAccountInactive => "financial_connections_account_inactive",
+        FinancialConnectionsNoSuccessfulTransactionRefresh => "financial_connections_no_successful_transaction_refresh",
+        ForwardingApiInactive => "forwarding_api_inactive",
+        ForwardingApiInvalidParameter => "forwarding_api_invalid_parameter",
+        ForwardingApiRetryableUpstreamError => "forwarding_api_retryable_upstream_error",
+        ForwardingApiUpstreamConnectionError => "forwarding_api_upstream_connection_error",
+        ForwardingApiUpstreamConnectionTimeout => "forwarding_api_upstream_connection_timeout",
+        IdempotencyKeyInUse => "idempotency_key_in_use",
+        IncorrectAddress => "incorrect_address",
+        IncorrectCvc => "incorrect_cvc",
+        IncorrectNumber => "incorrect_number",
+        IncorrectZip => "incorrect_zip",
+        InstantPayoutsConfigDisabled => "instant_payouts_config_disabled",
+        InstantPayoutsCurrencyDisabled => "instant_payouts_currency_disabled",
+        InstantPayoutsLimitExceeded => "instant_payouts_limit_exceeded",
+        InstantPayoutsUnsupported => "instant_payouts_unsupported",
+        InsufficientFunds => "insufficient_funds",
+        IntentInvalidState => "intent_invalid_state",
+        IntentVerificationMethodMissing => "intent_verification_method_missing",
+        InvalidCardType => "invalid_card_type",
+        InvalidCharacters => "invalid_characters",
+        InvalidChargeAmount => "invalid_charge_amount",
+        InvalidCvc => "invalid_cvc",
+        InvalidExpiryMonth => "invalid_expiry_month",
+        InvalidExpiryYear => "invalid_expiry_year",
+        InvalidMandateReferencePrefixFormat => "invalid_mandate_reference_prefix_format",
+        InvalidNumber => "invalid_number",
+        InvalidSourceUsage => "invalid_source_usage",
+        InvalidTaxLocation => "invalid_tax_location",
+        InvoiceNoCustomerLineItems => "invoice_no_customer_line_items",
+        InvoiceNoPaymentMethodTypes => "invoice_no_payment_method_types",
+        InvoiceNoSubscriptionLineItems => "invoice_no_subscription_line_items",
+        InvoiceNotEditable => "invoice_not_editable",
+        InvoiceOnBehalfOfNotEditable => "invoice_on_behalf_of_not_editable",
+        InvoicePaymentIntentRequiresAction => "invoice_payment_intent_requires_action",
+        InvoiceUpcomingNone => "invoice_upcoming_none",
+        LivemodeMismatch => "livemode_mismatch",
+        LockTimeout => "lock_timeout",
+        Missing => "missing",
+        NoAccount => "no_account",
+        NotAllowedOnStandardAccount => "not_allowed_on_standard_account",
+        OutOfInventory => "out_of_inventory",
+        OwnershipDeclarationNotAllowed => "ownership_declaration_not_allowed",
+        ParameterInvalidEmpty => "parameter_invalid_empty",
+        ParameterInvalidInteger => "parameter_invalid_integer",
+        ParameterInvalidStringBlank => "parameter_invalid_string_blank",
+        ParameterInvalidStringEmpty => "parameter_invalid_string_empty",
+        ParameterMissing => "parameter_missing",
+        ParameterUnknown => "parameter_unknown",
+        ParametersExclusive => "parameters_exclusive",
+        PaymentIntentActionRequired => "payment_intent_action_required",
+        PaymentIntentAuthenticationFailure => "payment_intent_authentication_failure",
+        PaymentIntentIncompatiblePaymentMethod => "payment_intent_incompatible_payment_method",
+        PaymentIntentInvalidParameter => "payment_intent_invalid_parameter",
+        PaymentIntentKonbiniRejectedConfirmationNumber => "payment_intent_konbini_rejected_confirmation_number",
+        PaymentIntentMandateInvalid => "payment_intent_mandate_invalid",
+        PaymentIntentPaymentAttemptExpired => "payment_intent_payment_attempt_expired",
+        PaymentIntentPaymentAttemptFailed => "payment_intent_payment_attempt_failed",
+        PaymentIntentUnexpectedState => "payment_intent_unexpected_state",
+        PaymentMethodBankAccountAlreadyVerified => "payment_method_bank_account_already_verified",
+        PaymentMethodBankAccountBlocked => "payment_method_bank_account_blocked",
+        PaymentMethodBillingDetailsAddressMissing => "payment_method_billing_details_address_missing",
+        PaymentMethodConfigurationFailures => "payment_method_configuration_failures",
+        PaymentMethodCurrencyMismatch => "payment_method_currency_mismatch",
+        PaymentMethodCustomerDecline => "payment_method_customer_decline",
+        PaymentMethodInvalidParameter => "payment_method_invalid_parameter",
+        PaymentMethodInvalidParameterTestmode => "payment_method_invalid_parameter_testmode",
+        PaymentMethodMicrodepositFailed => "payment_method_microdeposit_failed",
+        PaymentMethodMicrodepositVerificationAmountsInvalid => "payment_method_microdeposit_verification_amounts_invalid",
+        PaymentMethodMicrodepositVerificationAmountsMismatch => "payment_method_microdeposit_verification_amounts_mismatch",
+        PaymentMethodMicrodepositVerificationAttemptsExceeded => "payment_method_microdeposit_verification_attempts_exceeded",
+        PaymentMethodMicrodepositVerificationDescriptorCodeMismatch => "payment_method_microdeposit_verification_descriptor_code_mismatch",
+        PaymentMethodMicrodepositVerificationTimeout => "payment_method_microdeposit_verification_timeout",
+        PaymentMethodNotAvailable => "payment_method_not_available",
+        PaymentMethodProviderDecline => "payment_method_provider_decline",
+        PaymentMethodProviderTimeout => "payment_method_provider_timeout",
+        PaymentMethodUnactivated => "payment_method_unactivated",
+        PaymentMethodUnexpectedState => "payment_method_unexpected_state",
+        PaymentMethodUnsupportedType => "payment_method_unsupported_type",
+        PayoutReconciliationNotReady => "payout_reconciliation_not_ready",
+        PayoutsLimitExceeded => "payouts_limit_exceeded",
+        PayoutsNotAllowed => "payouts_not_allowed",
+        PlatformAccountRequired => "platform_account_required",
+        PlatformApiKeyExpired => "platform_api_key_expired",
+        PostalCodeInvalid => "postal_code_invalid",
+        ProcessingError => "processing_error",
+        ProductInactive => "product_inactive",
+        ProgressiveOnboardingLimitExceeded => "progressive_onboarding_limit_exceeded",
+        RateLimit => "rate_limit",
+        ReferToCustomer => "refer_to_customer",
+        RefundDisputedPayment => "refund_disputed_payment",
+        ResourceAlreadyExists => "resource_already_exists",
+        ResourceMissing => "resource_missing",
+        ReturnIntentAlreadyProcessed => "return_intent_already_processed",
+        RoutingNumberInvalid => "routing_number_invalid",
+        SecretKeyRequired => "secret_key_required",
+        SepaUnsupportedAccount => "sepa_unsupported_account",
+        SetupAttemptFailed => "setup_attempt_failed",
+        SetupIntentAuthenticationFailure => "setup_intent_authentication_failure",
+        SetupIntentInvalidParameter => "setup_intent_invalid_parameter",
+        SetupIntentMandateInvalid => "setup_intent_mandate_invalid",
+        SetupIntentMobileWalletUnsupported => "setup_intent_mobile_wallet_unsupported",
+        SetupIntentSetupAttemptExpired => "setup_intent_setup_attempt_expired",
+        SetupIntentUnexpectedState => "setup_intent_unexpected_state",
+        ShippingAddressInvalid => "shipping_address_invalid",
+        ShippingCalculationFailed => "shipping_calculation_failed",
+        SkuInactive => "sku_inactive",
+        StateUnsupported => "state_unsupported",
+        StatusTransitionInvalid => "status_transition_invalid",
+        StripeTaxInactive => "stripe_tax_inactive",
+        TaxIdInvalid => "tax_id_invalid",
+        TaxesCalculationFailed => "taxes_calculation_failed",
+        TerminalLocationCountryUnsupported => "terminal_location_country_unsupported",
+        TerminalReaderBusy => "terminal_reader_busy",
+        TerminalReaderHardwareFault => "terminal_reader_hardware_fault",
+        TerminalReaderInvalidLocationForActivation => "terminal_reader_invalid_location_for_activation",
+        TerminalReaderInvalidLocationForPayment => "terminal_reader_invalid_location_for_payment",
+        TerminalReaderOffline => "terminal_reader_offline",
+        TerminalReaderTimeout => "terminal_reader_timeout",
+        TestmodeChargesOnly => "testmode_charges_only",
+        TlsVersionUnsupported => "tls_version_unsupported",
+        TokenAlreadyUsed => "token_already_used",
+        TokenCardNetworkInvalid => "token_card_network_invalid",
+        TokenInUse => "token_in_use",
+        TransferSourceBalanceParametersMismatch => "transfer_source_balance_parameters_mismatch",
+        TransfersNotAllowed => "transfers_not_allowed",
+        UrlInvalid => "url_invalid",
+    }
+);
+
+string_enum!(
+    /// The reason a card issuer gave for declining a payment.
+    ///
+    /// For more details see <https://stripe.com/docs/declines/codes>.
+    DeclineCode {
+        AuthenticationRequired => "authentication_required",
+        ApproveWithId => "approve_with_id",
+        CallIssuer => "call_issuer",
+        CardNotSupported => "card_not_supported",
+        CardVelocityExceeded => "card_velocity_exceeded",
+        CurrencyNotSupported => "currency_not_supported",
+        DoNotHonor => "do_not_honor",
+        DoNotTryAgain => "do_not_try_again",
+        DuplicateTransaction => "duplicate_transaction",
+        ExpiredCard => "expired_card",
+        Fraudulent => "fraudulent",
+        GenericDecline => "generic_decline",
+        IncorrectCvc => "incorrect_cvc",
+        IncorrectNumber => "incorrect_number",
+        IncorrectPin => "incorrect_pin",
+        IncorrectZip => "incorrect_zip",
+        InsufficientFunds => "insufficient_funds",
+        InvalidAccount => "invalid_account",
+        InvalidAmount => "invalid_amount",
+        InvalidCvc => "invalid_cvc",
+        InvalidExpiryMonth => "invalid_expiry_month",
+        InvalidExpiryYear => "invalid_expiry_year",
+        InvalidNumber => "invalid_number",
+        InvalidPin => "invalid_pin",
+        IssuerNotAvailable => "issuer_not_available",
+        LostCard => "lost_card",
+        MerchantBlacklist => "merchant_blacklist",
+        NewAccountInformationAvailable => "new_account_information_available",
+        NoActionTaken => "no_action_taken",
+        NotPermitted => "not_permitted",
+        OfflinePinRequired => "offline_pin_required",
+        OnlineOrOfflinePinRequired => "online_or_offline_pin_required",
+        PickupCard => "pickup_card",
+        PinTryExceeded => "pin_try_exceeded",
+        ProcessingError => "processing_error",
+        ReenterTransaction => "reenter_transaction",
+        RestrictedCard => "restricted_card",
+        RevocationOfAllAuthorizations => "revocation_of_all_authorizations",
+        RevocationOfAuthorization => "revocation_of_authorization",
+        SecurityViolation => "security_violation",
+        ServiceNotAllowed => "service_not_allowed",
+        StolenCard => "stolen_card",
+        StopPaymentOrder => "stop_payment_order",
+        TestmodeDecline => "testmode_decline",
+        TransactionNotAllowed => "transaction_not_allowed",
+        TryAgainLater => "try_again_later",
+        WithdrawalCountLimitExceeded => "withdrawal_count_limit_exceeded",
+    }
+);
+
+impl ErrorCode {
+    /// Whether the request may succeed if it is sent again unchanged
+    pub fn is_retryable(&self) -> bool {
+        // By value, since the variants are regenerated from the spec
+        matches!(self.as_str(), "lock_timeout" | "rate_limit")
+    }
+}
+
+impl DeclineCode {
+    /// Whether the issuer may approve the payment if it is tried again later
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            DeclineCode::ApproveWithId
+                | DeclineCode::IssuerNotAvailable
+                | DeclineCode::ProcessingError
+                | DeclineCode::ReenterTransaction
+                | DeclineCode::TryAgainLater
+        )
     }
 }
 
@@ -165,15 +436,88 @@ pub struct RequestError {
     #[serde(default)]
     pub message: Option<String>,
 
-    /// For card errors, a value describing the kind of card error that occured.
+    /// For some errors that could be handled programmatically, a short string
+    /// indicating the [error code](https://stripe.com/docs/error-codes) reported.
     pub code: Option<ErrorCode>,
 
-    /// For card errors resulting from a bank decline, a string indicating the
-    /// bank's reason for the decline if they provide one.
-    pub decline_code: Option<String>,
+    /// For card errors resulting from a card issuer decline, the card issuer's
+    /// [reason for the decline](https://stripe.com/docs/declines#issuer-declines).
+    pub decline_code: Option<DeclineCode>,
+
+    /// For card errors resulting from a card issuer decline, a short string indicating
+    /// [how to proceed](https://stripe.com/docs/declines#retrying-issuer-declines).
+    pub advice_code: Option<String>,
+
+    /// For card errors resulting from a card issuer decline, the 2 digit advice code
+    /// the card network gave on how to proceed.
+    pub network_advice_code: Option<String>,
+
+    /// For card errors resulting from a card issuer decline, the card brand's
+    /// code for why the authorization failed.
+    pub network_decline_code: Option<String>,
 
-    /// The ID of the failed charge, if applicable.
+    /// For card errors, the ID of the failed charge.
     pub charge: Option<String>,
+
+    /// If the error is parameter-specific, the parameter related to the error.
+    pub param: Option<String>,
+
+    /// A URL to more information about the error code reported.
+    pub doc_url: Option<String>,
+
+    /// A URL to the request log entry in your dashboard.
+    pub request_log_url: Option<String>,
+
+    /// If the error is specific to the type of payment method, the payment method type that had a problem.
+    pub payment_method_type: Option<String>,
+
+    /// The PaymentIntent object for errors returned on a request involving a PaymentIntent.
+    ///
+    /// Kept as JSON, since the `payment_intent` component may not be installed;
+    /// deserialize it into a `PaymentIntent` if it is.
+    pub payment_intent: Option<serde_json::Value>,
+
+    /// The SetupIntent object for errors returned on a request involving a SetupIntent, as JSON.
+    pub setup_intent: Option<serde_json::Value>,
+
+    /// The PaymentMethod object for errors returned on a request involving a PaymentMethod, as JSON.
+    pub payment_method: Option<serde_json::Value>,
+
+    /// The source object for errors returned on a request involving a source, as JSON.
+    #[serde(rename = "source")]
+    pub payment_source: Option<serde_json::Value>,
+
+    /// The idempotency key the request was sent with.
+    #[serde(skip_deserializing)]
+    pub idempotency_key: Option<String>,
+
+    /// The `Request-Id` of the failed request, for Stripe support.
+    #[serde(skip_deserializing)]
+    pub request_id: Option<String>,
+
+    /// The rest of what the response said, such as its `Stripe-Version` and rate-limit headers.
+    #[serde(skip_deserializing)]
+    pub meta: ResponseMeta,
+}
+
+impl RequestError {
+    /// Whether the card issuer declined the payment
+    pub fn is_card_decline(&self) -> bool {
+        self.error_type == ErrorType::Card
+            && (self.decline_code.is_some() || self.code.as_ref().is_some_and(|code| code.as_str() == "card_declined"))
+    }
+
+    /// Whether the request may succeed if it is sent again unchanged
+    ///
+    /// True for rate limits, lock timeouts, idempotency conflicts, server errors
+    /// and declines the issuer asks to try again.
+    pub fn is_retryable(&self) -> bool {
+        self.http_status == 409
+            || self.http_status == 429
+            || self.http_status >= 500
+            || self.code.as_ref().is_some_and(ErrorCode::is_retryable)
+            || self.decline_code.as_ref().is_some_and(DeclineCode::is_retryable)
+    }
 }
 
 /// The structure of the json body when an error is included in
@@ -197,3 +541,39 @@ pub enum WebhookError {
     #[error("error parsing event object")]
     BadParse(#[from] serde_json::Error),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_card_decline() {
+        let body = r#"{"error": {
+            "type": "card_error",
+            "code": "card_declined",
+            "decline_code": "try_again_later",
+            "param": "payment_method",
+            "doc_url": "https://stripe.com/docs/error-codes/card-declined",
+            "payment_intent": {"id": "pi_123", "object": "payment_intent"}
+        }}"#;
+        let error = serde_json::from_str::<ErrorResponse>(body).unwrap().error;
+        assert_eq!(error.code, Some(ErrorCode::CardDeclined));
+        assert_eq!(error.decline_code, Some(DeclineCode::TryAgainLater));
+        assert_eq!(error.param.as_deref(), Some("payment_method"));
+        assert_eq!(error.payment_intent.as_ref().unwrap()["id"], "pi_123");
+        assert!(error.is_retryable());
+
+        let error = StripeError::Stripe(serde_json::from_str::<ErrorResponse>(body).unwrap().error);
+        assert!(error.is_card_decline());
+    }
+
+    #[test]
+    fn test_unknown_codes() {
+        let body = r#"{"error": {"type": "invalid_request_error", "code": "brand_new_code", "decline_code": "brand_new_decline"}}"#;
+        let error = serde_json::from_str::<ErrorResponse>(body).unwrap().error;
+        assert_eq!(error.code, Some(ErrorCode::Unknown("brand_new_code".to_string())));
+        assert_eq!(error.code.as_ref().unwrap().to_string(), "brand_new_code");
+        assert_eq!(error.decline_code, Some(DeclineCode::Unknown("brand_new_decline".to_string())));
+        assert!(!error.is_card_decline());
+    }
+}
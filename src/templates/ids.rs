@@ -467,11 +467,18 @@ impl std::error::Error for ParseIdError {
 
 def_id!(AccountId, "acct_");
 def_id!(AlipayAccountId, "aliacc_");
+def_id!(ApplePayDomainId, "apwc_");
 def_id!(ApplicationFeeId, "fee_");
 def_id!(ApplicationId, "ca_");
 def_id!(ApplicationFeeRefundId, "fr_");
+def_id!(AppsSecretId, "appsecret_");
 def_id!(BalanceTransactionId, "txn_");
 def_id!(BankAccountId, "ba_" | "card_");
+def_id!(BillingAlertId, "alrt_");
+def_id!(BillingCreditBalanceTransactionId: String);
+def_id!(BillingCreditGrantId, "credgr_");
+def_id!(BillingMeterId, "mtr_");
+def_id!(BillingMeterEventSummaryId: String);
 def_id!(BillingPortalSessionId, "bps_");
 def_id!(BillingPortalConfigurationId, "bpc_");
 def_id!(BankTokenId, "btok_");
@@ -492,24 +499,42 @@ def_id!(
         TransferReversal(TransferReversalId),
     }
 );
+def_id!(CapabilityId: String); // N.B. A capability id is the capability's name, e.g. `card_payments`
 def_id!(CardId, "card_");
 def_id!(CardTokenId, "tok_");
 def_id!(ChargeId, "ch_" | "py_"); // TODO: Understand (and then document) why "py_" is a valid charge id
 def_id!(CheckoutSessionId, "cs_");
 def_id!(CheckoutSessionItemId, "li_");
+def_id!(ClimateOrderId, "climorder_");
+def_id!(ClimateProductId, "climsku_");
+def_id!(ClimateSupplierId, "climsup_");
+def_id!(ConfirmationTokenId, "ctoken_");
 def_id!(ConnectCollectionTransferId, "connct_");
 def_id!(ConnectTokenId, "ct_");
+def_id!(CountrySpecId: String); // N.B. A country spec id is a two-letter country code
 def_id!(CouponId: String); // N.B. A coupon id can be user-provided so can be any arbitrary string
 def_id!(CreditNoteId, "cn_");
 def_id!(CreditNoteLineItemId, "cnli_");
 def_id!(CustomerBalanceTransactionId, "cbtxn_");
+def_id!(CustomerCashBalanceTransactionId, "ccsbtxn_");
 def_id!(CustomerId, "cus_");
 def_id!(DiscountId, "di_");
 def_id!(DisputeId, "dp_" | "du_" | "pdp_");
+def_id!(EntitlementsActiveEntitlementId: String);
+def_id!(EntitlementsFeatureId, "feat_");
 def_id!(EphemeralKeyId, "ephkey_");
 def_id!(EventId, "evt_");
+def_id!(ExchangeRateId: String); // N.B. An exchange rate id is a three-letter currency code
 def_id!(FileId, "file_");
 def_id!(FileLinkId, "link_");
+def_id!(FinancialConnectionsAccountId, "fca_");
+def_id!(FinancialConnectionsAccountOwnerId, "fcaowner_");
+def_id!(FinancialConnectionsAccountOwnershipId, "fcaowns_");
+def_id!(FinancialConnectionsSessionId, "fcsess_");
+def_id!(FinancialConnectionsTransactionId, "fctxn_");
+def_id!(ForwardingRequestId, "fwdreq_");
+def_id!(IdentityVerificationReportId, "vr_");
+def_id!(IdentityVerificationSessionId, "vs_");
 def_id!(InvoiceId, "in_", { _ });
 def_id!(InvoiceItemId, "ii_");
 def_id!(InvoiceLineItemIdWebhook, "il_");
@@ -522,10 +547,14 @@ def_id!(
         InvoiceLineItemIdWebhook(InvoiceLineItemIdWebhook),
     }
 );
+def_id!(InvoicePaymentId, "inpay_");
+def_id!(InvoiceRenderingTemplateId, "inrtem_");
 def_id!(IssuingAuthorizationId, "iauth_");
 def_id!(IssuingCardId, "ic_");
 def_id!(IssuingCardholderId, "ich_");
 def_id!(IssuingDisputeId, "idp_");
+def_id!(IssuingPersonalizationDesignId: String);
+def_id!(IssuingPhysicalBundleId: String);
 def_id!(IssuingTransactionId, "ipi_");
 def_id!(IssuingTokenId: String);
 def_id!(OrderId, "or_");
@@ -534,6 +563,7 @@ def_id!(MandateId, "mandate_");
 def_id!(PaymentMethodConfigurationId: String);
 def_id!(PaymentIntentId, "pi_");
 def_id!(PaymentLinkId, "plink_");
+def_id!(PaymentMethodDomainId, "pmd_");
 def_id!(PaymentMethodId, "pm_" | "card_" | "src_" | "ba_");
 def_id!(
     enum PaymentSourceId {
@@ -557,11 +587,17 @@ def_id!(PersonId, "person_");
 def_id!(PlanId: String); // N.B. A plan id can be user-provided so can be any arbitrary string
 def_id!(PlatformTaxFeeId, "ptf");
 def_id!(PriceId: String); // N.B. A price id can be user-provided so can be any arbitrary string
+def_id!(ProductFeatureId, "prodft_");
 def_id!(ProductId: String); // N.B. A product id can be user-provided so can be any arbitrary string
 def_id!(PromotionCodeId, "promo_");
 def_id!(QuoteId, "qt_");
+def_id!(RadarEarlyFraudWarningId, "issfr_");
+def_id!(RadarValueListId, "rsl_");
+def_id!(RadarValueListItemId, "rsli_");
 def_id!(RecipientId: String); // FIXME: This doesn't seem to be documented yet
 def_id!(RefundId, "re_" | "pyr_");
+def_id!(ReportingReportRunId, "frr_");
+def_id!(ReportingReportTypeId: String); // N.B. A report type id is its name, e.g. `balance.summary.1`
 def_id!(ReserveTransactionId, "rtx_");
 def_id!(ReviewId, "prv_");
 def_id!(ScheduledQueryRunId, "sqr_");
@@ -570,6 +606,7 @@ def_id!(SetupIntentId, "seti_");
 def_id!(SkuId, "sku_");
 def_id!(ShippingRateId, "shr_");
 def_id!(SourceId, "src_");
+def_id!(SourceMandateNotificationId, "srcmn_");
 def_id!(SubscriptionId, "sub_");
 def_id!(SubscriptionItemId, "si_");
 def_id!(SubscriptionLineId, "sli_");
@@ -580,6 +617,9 @@ def_id!(TaxCalculationLineItemId: String);
 def_id!(TaxCodeId, "txcd_");
 def_id!(TaxDeductedAtSourceId, "itds");
 def_id!(TaxRateId, "txr_");
+def_id!(TaxRegistrationId, "taxreg_");
+def_id!(TaxTransactionId, "tax_");
+def_id!(TaxTransactionLineItemId, "tax_li_");
 def_id!(TerminalConfigurationId, "tmc_");
 def_id!(TerminalLocationId, "tml_");
 def_id!(TerminalReaderId, "tmr_");
@@ -595,6 +635,16 @@ def_id!(
 def_id!(TopupId, "tu_");
 def_id!(TransferId, "tr_");
 def_id!(TransferReversalId, "trr_");
+def_id!(TreasuryCreditReversalId, "credrev_");
+def_id!(TreasuryDebitReversalId, "debrev_");
+def_id!(TreasuryFinancialAccountId, "fa_");
+def_id!(TreasuryInboundTransferId, "ibt_");
+def_id!(TreasuryOutboundPaymentId, "obp_");
+def_id!(TreasuryOutboundTransferId, "obt_");
+def_id!(TreasuryReceivedCreditId, "rc_");
+def_id!(TreasuryReceivedDebitId, "rd_");
+def_id!(TreasuryTransactionId, "trxn_");
+def_id!(TreasuryTransactionEntryId, "trxne_");
 def_id!(UsageRecordId, "mbur_");
 def_id!(UsageRecordSummaryId, "urs_" | "sis_");
 def_id!(WebhookEndpointId, "we_");
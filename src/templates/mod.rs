@@ -35,8 +35,13 @@
 //!                               times with a timeout. The idempotency key is generated  automatically and is
 //!                               stable across retries.
 //! - [`RequestStrategy::ExponentialBackoff`]: Make a request to the Stripe API and, if the request fails, retry
-//!                                            it up to n times with exponential backoff. The idempotency key is
-//!                                            generated automatically and is stable across retries.
+//!                                            it up to n times with exponential backoff: delays double from 1s up
+//!                                            to 8s, with full jitter. The idempotency key is generated
+//!                                            automatically and is stable across retries.
+//! - [`RequestStrategy::Policy`]: Retry as a [`RetryPolicy`] describes: separate budgets for failed responses
+//!                                and network errors, delays doubling from a base delay up to a cap, and
+//!                                full jitter. Rate limits (429) and idempotency conflicts (409) are retried,
+//!                                waiting for `Retry-After` when Stripe sends it.
 //!
 //! > Want to implement your own? If it is a common strategy, please consider opening a PR to add it to the library.
 //! > Otherwise, we are open to turning this into an open trait so that you can implement your own strategy.
@@ -62,12 +67,14 @@ mod resources;
 // N.B. Ideally we would support both a blocking client and
 //      an async client without a feature flag, but the originally
 //      discussed solution requires Generic Associated Types--
-//      instead we provide an async client only a feature flag.
+//      instead the `blocking` feature switches the client over:
+//      `Response<T>` becomes `Result<T, StripeError>` and the same
+//      resource methods return their results directly.
 //
 // See https://github.com/wyyerd/stripe-rs/issues/24#issuecomment-451514187
 // See https://github.com/rust-lang/rust/issues/44265
 pub use client::*;
-pub use error::{ErrorCode, ErrorType, RequestError, StripeError, WebhookError};
+pub use error::{DeclineCode, ErrorCode, ErrorType, RequestError, StripeError, WebhookError};
 pub use ids::*;
 pub use params::{
     Expandable, Headers, IdOrCreate, List, Metadata, Object, RangeBounds, RangeQuery, SearchList,
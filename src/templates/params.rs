@@ -363,8 +363,8 @@ where
     /// # }
     /// ```
     ///
-    /// Requires `feature = ["stream"]`.
-    #[cfg(feature = "stream")]
+    /// Requires `feature = ["stream"]`, and is not available with `feature = ["blocking"]`.
+    #[cfg(all(feature = "stream", not(feature = "blocking")))]
     pub fn stream(
         mut self,
         client: &Client,
@@ -379,7 +379,7 @@ where
     }
 
     /// unfold a single item from the stream
-    #[cfg(feature = "stream")]
+    #[cfg(all(feature = "stream", not(feature = "blocking")))]
     async fn unfold_stream(
         state: Option<(Self, Client)>,
     ) -> Option<(Result<T::O, StripeError>, Option<(Self, Client)>)> {
@@ -444,10 +444,17 @@ where
     /// Pin a new future which maps the result inside the page future into
     /// a ListPaginator
 
+    #[cfg(not(feature = "blocking"))]
     fn create_paginator(page: Response<T>, params: P) -> Response<Self> {
         use futures_util::FutureExt;
         Box::pin(page.map(|page| page.map(|page| ListPaginator { page, params })))
     }
+
+    /// Map the fetched page into a ListPaginator
+    #[cfg(feature = "blocking")]
+    fn create_paginator(page: Response<T>, params: P) -> Response<Self> {
+        page.map(|page| ListPaginator { page, params })
+    }
 }
 
 pub type CurrencyMap<V> = HashMap<Currency, V>;
@@ -564,6 +571,7 @@ mod tests {
         assert_eq!(to_snakecase("lower").as_str(), "lower");
     }
 
+    #[cfg(not(feature = "blocking"))]
     #[tokio::test]
     async fn list() {
         use httpmock::Method::GET;
@@ -636,6 +644,7 @@ mod tests {
         next_item.assert_hits_async(1).await;
     }
 
+    #[cfg(not(feature = "blocking"))]
     #[tokio::test]
     async fn list_multiple() {
         use httpmock::Method::GET;
@@ -719,7 +728,7 @@ mod tests {
         next_item.assert_hits_async(1).await;
     }
 
-    #[cfg(feature = "stream")]
+    #[cfg(all(feature = "stream", not(feature = "blocking")))]
     #[tokio::test]
     async fn stream() {
         use futures_util::StreamExt;
@@ -792,7 +801,7 @@ mod tests {
         next_item.assert_hits_async(1).await;
     }
 
-    #[cfg(feature = "stream")]
+    #[cfg(all(feature = "stream", not(feature = "blocking")))]
     #[tokio::test]
     async fn stream_multiple() {
         use futures_util::StreamExt;
@@ -0,0 +1,627 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::stripe::client::{config::random_u64, Client, Response};
+use crate::stripe::ids::{BillingMeterEventSummaryId, BillingMeterId, CustomerId};
+use crate::stripe::params::{Expand, List, Object, Paginable, Timestamp};
+use crate::stripe::resources::{
+    BillingMeter, BillingMeterEvent, BillingMeterEventAdjustment, BillingMeterEventAdjustmentType,
+    BillingMeterEventSummary, BillingMeterEventTimeWindow, BillingMeterResourceAggregationSettingsFormula,
+    BillingMeterResourceCustomerMappingSettingsType, BillingMeterStatus,
+};
+
+impl BillingMeter {
+    /// Retrieve a list of billing meters.
+    pub fn list(client: &Client, params: &ListBillingMeters<'_>) -> Response<List<BillingMeter>> {
+        client.get_query("/billing/meters", params)
+    }
+
+    /// Creates a billing meter.
+    pub fn create(client: &Client, params: CreateBillingMeter<'_>) -> Response<BillingMeter> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form("/billing/meters", &params)
+    }
+
+    /// Retrieves a billing meter given an ID.
+    pub fn retrieve(client: &Client, id: &BillingMeterId, expand: &[&str]) -> Response<BillingMeter> {
+        client.get_query(&format!("/billing/meters/{}", id), &Expand { expand })
+    }
+
+    /// Updates a billing meter.
+    pub fn update(client: &Client, id: &BillingMeterId, params: UpdateBillingMeter<'_>) -> Response<BillingMeter> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form(&format!("/billing/meters/{}", id), &params)
+    }
+
+    /// When a meter is deactivated, no more meter events will be accepted for this meter.
+    ///
+    /// You can’t attach a deactivated meter to a price.
+    pub fn deactivate(client: &Client, id: &BillingMeterId) -> Response<BillingMeter> {
+        client.post(&format!("/billing/meters/{}/deactivate", id))
+    }
+
+    /// When a meter is reactivated, events for this meter can be accepted and you can attach the meter to a price.
+    pub fn reactivate(client: &Client, id: &BillingMeterId) -> Response<BillingMeter> {
+        client.post(&format!("/billing/meters/{}/reactivate", id))
+    }
+
+    /// Retrieve a list of billing meter event summaries.
+    ///
+    /// Use `paginate` on the result to walk through every summary in the time range.
+    pub fn list_event_summaries(
+        client: &Client,
+        id: &BillingMeterId,
+        params: &ListBillingMeterEventSummaries<'_>,
+    ) -> Response<List<BillingMeterEventSummary>> {
+        client.get_query(&format!("/billing/meters/{}/event_summaries", id), params)
+    }
+}
+
+impl BillingMeterEvent {
+    /// Creates a billing meter event.
+    pub fn create(client: &Client, params: CreateBillingMeterEvent<'_>) -> Response<BillingMeterEvent> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form("/billing/meter_events", &params)
+    }
+}
+
+impl BillingMeterEventAdjustment {
+    /// Creates a billing meter event adjustment.
+    pub fn create(
+        client: &Client,
+        params: CreateBillingMeterEventAdjustment<'_>,
+    ) -> Response<BillingMeterEventAdjustment> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form("/billing/meter_event_adjustments", &params)
+    }
+}
+
+/// The parameters for `BillingMeter::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListBillingMeters<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<BillingMeterId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<BillingMeterId>,
+
+    /// Filter results to only include meters with the given status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<BillingMeterStatus>,
+}
+
+impl ListBillingMeters<'_> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Paginable for ListBillingMeters<'_> {
+    type O = BillingMeter;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+/// The parameters for `BillingMeter::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateBillingMeter<'a> {
+    /// Fields that specify how to map a meter event to a customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_mapping: Option<CreateBillingMeterCustomerMapping<'a>>,
+
+    /// The default settings to aggregate a meter's events with.
+    pub default_aggregation: CreateBillingMeterDefaultAggregation,
+
+    /// The meter’s name.
+    ///
+    /// Not visible to the customer.
+    pub display_name: &'a str,
+
+    /// The name of the meter event to record usage for.
+    ///
+    /// Corresponds with the `event_name` field on meter events.
+    pub event_name: &'a str,
+
+    /// The time window to pre-aggregate meter events for, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_time_window: Option<BillingMeterEventTimeWindow>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Fields that specify how to calculate a meter event's value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value_settings: Option<CreateBillingMeterValueSettings<'a>>,
+}
+
+impl<'a> CreateBillingMeter<'a> {
+    pub fn new(
+        display_name: &'a str,
+        event_name: &'a str,
+        formula: BillingMeterResourceAggregationSettingsFormula,
+    ) -> Self {
+        Self {
+            customer_mapping: Default::default(),
+            default_aggregation: CreateBillingMeterDefaultAggregation { formula },
+            display_name,
+            event_name,
+            event_time_window: Default::default(),
+            expand: Default::default(),
+            value_settings: Default::default(),
+        }
+    }
+}
+
+/// Fields that specify how to map a meter event to a customer.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateBillingMeterCustomerMapping<'a> {
+    /// The key in the meter event payload to use for mapping the event to a customer.
+    pub event_payload_key: &'a str,
+
+    /// The method for mapping a meter event to a customer.
+    ///
+    /// Must be `by_id`.
+    #[serde(rename = "type")]
+    pub type_: BillingMeterResourceCustomerMappingSettingsType,
+}
+
+/// The default settings to aggregate a meter's events with.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateBillingMeterDefaultAggregation {
+    /// Specifies how events are aggregated.
+    pub formula: BillingMeterResourceAggregationSettingsFormula,
+}
+
+/// Fields that specify how to calculate a meter event's value.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateBillingMeterValueSettings<'a> {
+    /// The key in the usage event payload to use as the value for this meter.
+    pub event_payload_key: &'a str,
+}
+
+/// The parameters for `BillingMeter::update`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateBillingMeter<'a> {
+    /// The meter’s name.
+    ///
+    /// Not visible to the customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+}
+
+impl UpdateBillingMeter<'_> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// The parameters for `BillingMeter::list_event_summaries`.
+#[derive(Clone, Debug, Serialize)]
+pub struct ListBillingMeterEventSummaries<'a> {
+    /// The customer for which to fetch event summaries.
+    pub customer: CustomerId,
+
+    /// The timestamp from when to stop aggregating meter events (exclusive).
+    ///
+    /// Must be aligned with minute boundaries.
+    pub end_time: Timestamp,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<BillingMeterEventSummaryId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// The timestamp from when to start aggregating meter events (inclusive).
+    ///
+    /// Must be aligned with minute boundaries.
+    pub start_time: Timestamp,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<BillingMeterEventSummaryId>,
+
+    /// Specifies what granularity to use when generating event summaries.
+    ///
+    /// If not specified, a single event summary would be returned for the specified time range.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value_grouping_window: Option<BillingMeterEventSummaryGroupingWindow>,
+}
+
+impl ListBillingMeterEventSummaries<'_> {
+    pub fn new(customer: CustomerId, start_time: Timestamp, end_time: Timestamp) -> Self {
+        Self {
+            customer,
+            end_time,
+            ending_before: Default::default(),
+            expand: Default::default(),
+            limit: Default::default(),
+            start_time,
+            starting_after: Default::default(),
+            value_grouping_window: Default::default(),
+        }
+    }
+}
+
+impl Paginable for ListBillingMeterEventSummaries<'_> {
+    type O = BillingMeterEventSummary;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+/// An enum representing the possible values of a `ListBillingMeterEventSummaries`'s `value_grouping_window` field.
+#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BillingMeterEventSummaryGroupingWindow {
+    #[default]
+    Day,
+    Hour,
+}
+
+impl BillingMeterEventSummaryGroupingWindow {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            BillingMeterEventSummaryGroupingWindow::Day => "day",
+            BillingMeterEventSummaryGroupingWindow::Hour => "hour",
+        }
+    }
+}
+
+impl AsRef<str> for BillingMeterEventSummaryGroupingWindow {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for BillingMeterEventSummaryGroupingWindow {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// The parameters for `BillingMeterEvent::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateBillingMeterEvent<'a> {
+    /// The name of the meter event.
+    ///
+    /// Corresponds with the `event_name` field on a meter.
+    pub event_name: &'a str,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A unique identifier for the event.
+    ///
+    /// If not provided, one is generated.
+    /// We recommend using a globally unique identifier for this, so Stripe can deduplicate retried events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identifier: Option<&'a str>,
+
+    /// The payload of the event.
+    ///
+    /// This must contain the fields corresponding to a meter's `customer_mapping.event_payload_key` (default is `stripe_customer_id`) and `value_settings.event_payload_key` (default is `value`).
+    pub payload: HashMap<String, String>,
+
+    /// The time of the event.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    /// Must be within the past 35 calendar days or up to 5 minutes in the future.
+    /// Defaults to current timestamp if not specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<Timestamp>,
+}
+
+impl<'a> CreateBillingMeterEvent<'a> {
+    pub fn new(event_name: &'a str, payload: HashMap<String, String>) -> Self {
+        Self {
+            event_name,
+            expand: Default::default(),
+            identifier: Default::default(),
+            payload,
+            timestamp: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `BillingMeterEventAdjustment::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateBillingMeterEventAdjustment<'a> {
+    /// Specifies which event to cancel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel: Option<CancelBillingMeterEvent<'a>>,
+
+    /// The name of the meter event.
+    ///
+    /// Corresponds with the `event_name` field on a meter.
+    pub event_name: &'a str,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Specifies whether to cancel a single event or a range of events for a time period.
+    ///
+    /// Time period cancellation is not supported yet.
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<BillingMeterEventAdjustmentType>,
+}
+
+impl<'a> CreateBillingMeterEventAdjustment<'a> {
+    /// Cancel the event reported as `identifier`.
+    pub fn cancel(event_name: &'a str, identifier: &'a str) -> Self {
+        Self {
+            cancel: Some(CancelBillingMeterEvent { identifier }),
+            event_name,
+            expand: Default::default(),
+            type_: Some(BillingMeterEventAdjustmentType::Cancel),
+        }
+    }
+}
+
+/// Specifies which meter event to cancel.
+#[derive(Clone, Debug, Serialize)]
+pub struct CancelBillingMeterEvent<'a> {
+    /// Unique identifier for the event.
+    pub identifier: &'a str,
+}
+
+/// A meter event waiting in a [`MeterEventReporter`].
+#[derive(Clone, Debug, Serialize)]
+pub struct PendingMeterEvent {
+    pub event_name: String,
+    /// Assigned when the event is recorded, and kept until it is reported
+    pub identifier: String,
+    pub payload: HashMap<String, String>,
+    /// The time the event was recorded
+    pub timestamp: Timestamp,
+}
+
+impl PendingMeterEvent {
+    fn params(&self) -> CreateBillingMeterEvent<'_> {
+        CreateBillingMeterEvent {
+            identifier: Some(&self.identifier),
+            timestamp: Some(self.timestamp),
+            ..CreateBillingMeterEvent::new(&self.event_name, self.payload.clone())
+        }
+    }
+}
+
+/// Buffers meter events and reports them to Stripe in batches.
+///
+/// Each event gets its identifier and timestamp when it is recorded. Events a
+/// flush could not report go back to the front of the buffer with the same
+/// identifier, so the next flush retries them and Stripe drops any duplicate
+/// of an event that did arrive.
+///
+/// Nothing is sent on drop: call [`flush`](MeterEventReporter::flush) before
+/// shutting down.
+#[derive(Clone)]
+pub struct MeterEventReporter {
+    client: Client,
+    pending: Arc<Mutex<VecDeque<PendingMeterEvent>>>,
+    prefix: String,
+    sequence: Arc<AtomicU64>,
+    batch_size: usize,
+}
+
+impl MeterEventReporter {
+    /// A reporter that considers itself full at 100 events
+    pub fn new(client: Client) -> Self {
+        Self {
+            client,
+            pending: Default::default(),
+            prefix: format!("{:016x}", random_u64()),
+            sequence: Default::default(),
+            batch_size: 100,
+        }
+    }
+
+    /// How many events to buffer before [`is_full`](MeterEventReporter::is_full) says to flush
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Buffer an event, returning its identifier
+    pub fn record(&self, event_name: impl Into<String>, payload: HashMap<String, String>) -> String {
+        let sequence = self.sequence.fetch_add(1, Ordering::Relaxed);
+        let identifier = format!("{}-{}", self.prefix, sequence);
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map(|now| now.as_secs() as Timestamp);
+        self.lock().push_back(PendingMeterEvent {
+            event_name: event_name.into(),
+            identifier: identifier.clone(),
+            payload,
+            timestamp: timestamp.unwrap_or_default(),
+        });
+        identifier
+    }
+
+    /// Buffer `value` for `customer`, with the default `stripe_customer_id` and `value` payload keys
+    pub fn record_usage(&self, event_name: impl Into<String>, customer: &CustomerId, value: i64) -> String {
+        let payload = HashMap::from([
+            ("stripe_customer_id".to_string(), customer.to_string()),
+            ("value".to_string(), value.to_string()),
+        ]);
+        self.record(event_name, payload)
+    }
+
+    /// The number of events waiting to be reported
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether a batch worth of events is waiting
+    pub fn is_full(&self) -> bool {
+        self.len() >= self.batch_size
+    }
+
+    /// Report every buffered event, returning how many were reported
+    ///
+    /// Stops at the first error, which is returned after the unreported events
+    /// are put back. Dropping the future before it finishes puts them back too.
+    #[cfg(not(feature = "blocking"))]
+    pub fn flush(&self) -> Response<usize> {
+        let reporter = self.clone();
+        Box::pin(async move {
+            let mut batch = reporter.take();
+            let mut sent = 0;
+            while let Some(event) = batch.events.front() {
+                BillingMeterEvent::create(&reporter.client, event.params()).await?;
+                batch.events.pop_front();
+                sent += 1;
+            }
+            Ok(sent)
+        })
+    }
+
+    /// Report every buffered event, returning how many were reported
+    ///
+    /// Stops at the first error, which is returned after the unreported events
+    /// are put back.
+    #[cfg(feature = "blocking")]
+    pub fn flush(&self) -> Response<usize> {
+        let mut batch = self.take();
+        let mut sent = 0;
+        while let Some(event) = batch.events.front() {
+            BillingMeterEvent::create(&self.client, event.params())?;
+            batch.events.pop_front();
+            sent += 1;
+        }
+        Ok(sent)
+    }
+
+    /// A copy of the buffered events
+    pub fn pending(&self) -> Vec<PendingMeterEvent> {
+        self.lock().iter().cloned().collect()
+    }
+
+    fn take(&self) -> Batch<'_> {
+        Batch { reporter: self, events: self.lock().drain(..).collect() }
+    }
+
+    /// Return unreported events to the front of the buffer, ahead of any recorded since
+    fn put_back(&self, events: VecDeque<PendingMeterEvent>) {
+        let mut pending = self.lock();
+        for event in events.into_iter().rev() {
+            pending.push_front(event);
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, VecDeque<PendingMeterEvent>> {
+        self.pending.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Events a flush took from the buffer; the ones still here when it is
+/// dropped, because sending failed or the flush was cancelled, go back
+struct Batch<'a> {
+    reporter: &'a MeterEventReporter,
+    events: VecDeque<PendingMeterEvent>,
+}
+
+impl Drop for Batch<'_> {
+    fn drop(&mut self) {
+        self.reporter.put_back(std::mem::take(&mut self.events));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stripe::client::testing::{wait, FakeTransport};
+
+    const METER_EVENT: &str =
+        r#"{"created": 0, "event_name": "api_calls", "identifier": "x", "livemode": false, "payload": {}, "timestamp": 0}"#;
+
+    #[test]
+    fn test_reporter_keeps_identifiers_of_unreported_events() {
+        let transport =
+            FakeTransport::new().respond(200, METER_EVENT).respond(400, r#"{"error": {"type": "invalid_request_error"}}"#);
+        let reporter = MeterEventReporter::new(transport.client()).with_batch_size(2);
+
+        let customer: CustomerId = "cus_123".parse().unwrap();
+        let first = reporter.record_usage("api_calls", &customer, 3);
+        assert!(!reporter.is_full());
+        let second = reporter.record_usage("api_calls", &customer, 4);
+        assert!(reporter.is_full());
+        assert_ne!(first, second);
+
+        assert!(wait(reporter.flush()).is_err());
+        let pending = reporter.pending();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].identifier, second);
+
+        let bodies: Vec<String> =
+            transport.requests().into_iter().map(|request| String::from_utf8(request.body.unwrap()).unwrap()).collect();
+        assert!(bodies[0].contains(&format!("identifier={}", first)));
+        assert!(bodies[0].contains("payload[value]=3"));
+        assert!(bodies[1].contains(&format!("identifier={}", second)));
+    }
+
+    #[cfg(not(feature = "blocking"))]
+    #[test]
+    fn test_cancelled_flush_keeps_unreported_events() {
+        let transport = FakeTransport::new().respond(200, METER_EVENT).hang();
+        let reporter = MeterEventReporter::new(transport.client());
+
+        let customer: CustomerId = "cus_123".parse().unwrap();
+        reporter.record_usage("api_calls", &customer, 1);
+        let second = reporter.record_usage("api_calls", &customer, 2);
+        let third = reporter.record_usage("api_calls", &customer, 3);
+
+        // The second event never gets an answer, so the flush is dropped while sending it
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let flush = runtime.block_on(async {
+            tokio::time::timeout(std::time::Duration::from_millis(50), reporter.flush()).await
+        });
+        assert!(flush.is_err());
+        assert_eq!(transport.requests().len(), 2);
+        let pending: Vec<String> = reporter.pending().into_iter().map(|event| event.identifier).collect();
+        assert_eq!(pending, [second, third]);
+    }
+}
@@ -0,0 +1,159 @@
+use std::path::{Path, PathBuf};
+
+use crate::stripe::client::{Client, Download, Multipart, Response};
+use crate::stripe::error::StripeError;
+use crate::stripe::ids::FileId;
+use crate::stripe::params::{Metadata, Timestamp};
+use crate::stripe::resources::{File, FilePurpose};
+
+impl File {
+    /// To upload a file to Stripe, you need to send a request of type `multipart/form-data`.
+    ///
+    /// The request goes to `files.stripe.com` rather than the main API.
+    ///
+    /// For more details see <https://stripe.com/docs/api/files/create>.
+    #[cfg(not(feature = "blocking"))]
+    pub fn create(client: &Client, params: CreateFile) -> Response<File> {
+        let client = client.clone();
+        Box::pin(async move {
+            let CreateFile { file, purpose, file_link_data } = params;
+            let (filename, data) = match file {
+                FileUpload::Bytes { filename, data } => (filename, data),
+                FileUpload::Path(path) => {
+                    let data = tokio::fs::read(&path).await.map_err(|e| read_error(&path, e))?;
+                    (file_name(&path), data)
+                }
+            };
+            upload(&client, purpose, file_link_data, filename, data).await
+        })
+    }
+
+    /// To upload a file to Stripe, you need to send a request of type `multipart/form-data`.
+    ///
+    /// The request goes to `files.stripe.com` rather than the main API.
+    ///
+    /// For more details see <https://stripe.com/docs/api/files/create>.
+    #[cfg(feature = "blocking")]
+    pub fn create(client: &Client, params: CreateFile) -> Response<File> {
+        let CreateFile { file, purpose, file_link_data } = params;
+        let (filename, data) = match file {
+            FileUpload::Bytes { filename, data } => (filename, data),
+            FileUpload::Path(path) => {
+                let data = std::fs::read(&path).map_err(|e| read_error(&path, e))?;
+                (file_name(&path), data)
+            }
+        };
+        upload(client, purpose, file_link_data, filename, data)
+    }
+
+    /// Downloads the contents of a file from `files.stripe.com`.
+    ///
+    /// The contents arrive as a stream of chunks, or as a reader with the
+    /// `blocking` feature, so large files are never held in memory at once.
+    ///
+    /// For more details see <https://stripe.com/docs/file-upload#download-file-contents>.
+    pub fn download(client: &Client, id: &FileId) -> Response<Download> {
+        client.download(&format!("/files/{}/contents", id))
+    }
+}
+
+/// The parameters for `File::create`.
+#[derive(Clone, Debug)]
+pub struct CreateFile {
+    /// The file to upload.
+    pub file: FileUpload,
+
+    /// The [purpose](https://stripe.com/docs/file-upload#uploading-a-file) of the uploaded file.
+    pub purpose: FilePurpose,
+
+    /// Optional parameters that automatically create a [file link](https://stripe.com/docs/api#file_links) for the newly created file.
+    pub file_link_data: Option<CreateFileLinkData>,
+}
+
+impl CreateFile {
+    pub fn new(file: FileUpload, purpose: FilePurpose) -> Self {
+        CreateFile { file, purpose, file_link_data: Default::default() }
+    }
+}
+
+/// The contents of a file to upload.
+#[derive(Clone, Debug)]
+pub enum FileUpload {
+    /// Bytes already in memory, with the name to give the file
+    Bytes { filename: String, data: Vec<u8> },
+    /// A file on disk, read when the request is made
+    Path(PathBuf),
+}
+
+impl FileUpload {
+    pub fn from_bytes(filename: impl Into<String>, data: impl Into<Vec<u8>>) -> Self {
+        FileUpload::Bytes { filename: filename.into(), data: data.into() }
+    }
+
+    pub fn from_path(path: impl Into<PathBuf>) -> Self {
+        FileUpload::Path(path.into())
+    }
+}
+
+/// Optional parameters that automatically create a file link for the newly created file.
+#[derive(Clone, Debug, Default)]
+pub struct CreateFileLinkData {
+    /// Set this to `true` to create a file link for the newly created file.
+    ///
+    /// Creating a link is only possible when the file's `purpose` is one of the following: `business_icon`, `business_logo`, `customer_signature`, `dispute_evidence`, `issuing_regulatory_reporting`, `pci_document`, `tax_document_user_upload`, or `terminal_reader_splashscreen`.
+    pub create: bool,
+
+    /// The link isn't available after this future timestamp.
+    pub expires_at: Option<Timestamp>,
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    pub metadata: Option<Metadata>,
+}
+
+/// Send the multipart form for `File::create`
+fn upload(
+    client: &Client,
+    purpose: FilePurpose,
+    file_link_data: Option<CreateFileLinkData>,
+    filename: String,
+    data: Vec<u8>,
+) -> Response<File> {
+    let mut form = Multipart::new().text("purpose", purpose.as_str());
+    if let Some(link) = file_link_data {
+        form = form.text("file_link_data[create]", link.create.to_string());
+        if let Some(expires_at) = link.expires_at {
+            form = form.text("file_link_data[expires_at]", expires_at.to_string());
+        }
+        for (key, value) in link.metadata.iter().flatten() {
+            form = form.text(&format!("file_link_data[metadata][{}]", key), value);
+        }
+    }
+    let form = form.file("file", &filename, content_type(&filename), data);
+
+    client.post_multipart("/files", form)
+}
+
+fn read_error(path: &Path, e: std::io::Error) -> StripeError {
+    StripeError::ClientError(format!("Failed to read {}: {}", path.display(), e))
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_else(|| "file".to_string())
+}
+
+/// The content type Stripe expects for the file types it accepts
+fn content_type(filename: &str) -> &'static str {
+    let extension = filename.rsplit_once('.').map(|(_, ext)| ext.to_ascii_lowercase()).unwrap_or_default();
+    match extension.as_str() {
+        "pdf" => "application/pdf",
+        "jpg" | "jpeg" => "image/jpeg",
+        "png" => "image/png",
+        "gif" => "image/gif",
+        "csv" => "text/csv",
+        "txt" => "text/plain",
+        "xls" => "application/vnd.ms-excel",
+        "xlsx" => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        "docx" => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        _ => "application/octet-stream",
+    }
+}
@@ -78,6 +78,11 @@ pub mod tax_calculation {
     pub mod tax_calculation;
     pub mod tax_calculation_line_item;
     pub mod tax_product_resource_customer_details;
+    pub mod tax_product_resource_ship_from_details;
+    pub mod tax_registration;
+    pub mod tax_settings;
+    pub mod tax_transaction;
+    pub mod tax_transaction_line_item;
 }
 
 #[path = "generated"]
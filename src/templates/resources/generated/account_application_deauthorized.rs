@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{Application};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "account.application.deauthorized".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct AccountApplicationDeauthorized {
+    /// The object the event is about.
+    pub object: Application,
 }
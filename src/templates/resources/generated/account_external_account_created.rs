@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{ExternalAccount};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "account.external_account.created".
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AccountExternalAccountCreated {
+    /// The object the event is about.
+    pub object: ExternalAccount,
 }
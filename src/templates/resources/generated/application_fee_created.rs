@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{ApplicationFee};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "application_fee.created".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct ApplicationFeeCreated {
+    /// The object the event is about.
+    pub object: ApplicationFee,
 }
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{Balance};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "balance.available".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct BalanceAvailable {
+    /// The object the event is about.
+    pub object: Balance,
 }
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{BillingCreditBalanceTransaction};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "billing.credit_balance_transaction.created".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct BillingCreditBalanceTransactionCreated {
+    /// The object the event is about.
+    pub object: BillingCreditBalanceTransaction,
 }
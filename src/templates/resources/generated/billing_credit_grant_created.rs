@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{BillingCreditGrant};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "billing.credit_grant.created".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct BillingCreditGrantCreated {
+    /// The object the event is about.
+    pub object: BillingCreditGrant,
 }
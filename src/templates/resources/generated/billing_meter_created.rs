@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{BillingMeter};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "billing.meter.created".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct BillingMeterCreated {
+    /// The object the event is about.
+    pub object: BillingMeter,
 }
@@ -2,6 +2,8 @@
 // This file was automatically generated.
 // ======================================
 
+use std::collections::HashMap;
+
 use crate::stripe::params::{Object, Timestamp};
 use serde::{Deserialize, Serialize};
 
@@ -31,7 +33,7 @@ pub struct BillingMeterEvent {
     ///
     /// This contains the fields corresponding to a meter's `customer_mapping.event_payload_key` (default is `stripe_customer_id`) and `value_settings.event_payload_key` (default is `value`).
     /// Read more about the [payload](https://stripe.com/docs/billing/subscriptions/usage-based/recording-usage#payload-key-overrides).
-    pub payload: String,
+    pub payload: HashMap<String, String>,
 
     /// The timestamp passed in when creating the event.
     ///
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{BillingMeter};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "billing.meter.reactivated".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct BillingMeterReactivated {
+    /// The object the event is about.
+    pub object: BillingMeter,
 }
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{BillingPortalConfiguration};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "billing_portal.configuration.created".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct BillingPortalConfigurationCreated {
+    /// The object the event is about.
+    pub object: BillingPortalConfiguration,
 }
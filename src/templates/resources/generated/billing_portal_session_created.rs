@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{BillingPortalSession};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "billing_portal.session.created".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct BillingPortalSessionCreated {
+    /// The object the event is about.
+    pub object: BillingPortalSession,
 }
@@ -2,9 +2,18 @@
 // This file was automatically generated.
 // ======================================
 
+use std::collections::HashMap;
+
+use crate::stripe::resources::{Capability};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "capability.updated".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CapabilityUpdated {
+    /// The object the event is about.
+    pub object: Capability,
+
+    /// The values of the attributes that changed, as they were before the event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_attributes: Option<HashMap<String, serde_json::Value>>,
 }
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{CashBalance};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "cash_balance.funds_available".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CashBalanceFundsAvailable {
+    /// The object the event is about.
+    pub object: CashBalance,
 }
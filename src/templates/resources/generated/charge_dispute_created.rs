@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{Dispute};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "charge.dispute.created".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct ChargeDisputeCreated {
+    /// The object the event is about.
+    pub object: Dispute,
 }
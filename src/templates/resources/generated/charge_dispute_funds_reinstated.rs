@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{Dispute};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "charge.dispute.funds_reinstated".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct ChargeDisputeFundsReinstated {
+    /// The object the event is about.
+    pub object: Dispute,
 }
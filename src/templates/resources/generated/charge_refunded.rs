@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{Charge};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "charge.refunded".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct ChargeRefunded {
+    /// The object the event is about.
+    pub object: Charge,
 }
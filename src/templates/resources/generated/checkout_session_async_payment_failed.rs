@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{CheckoutSession};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "checkout.session.async_payment_failed".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CheckoutSessionAsyncPaymentFailed {
+    /// The object the event is about.
+    pub object: CheckoutSession,
 }
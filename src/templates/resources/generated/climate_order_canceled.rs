@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{ClimateOrder};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "climate.order.canceled".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct ClimateOrderCanceled {
+    /// The object the event is about.
+    pub object: ClimateOrder,
 }
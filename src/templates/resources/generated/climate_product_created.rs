@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{ClimateProduct};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "climate.product.created".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct ClimateProductCreated {
+    /// The object the event is about.
+    pub object: ClimateProduct,
 }
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{Coupon};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "coupon.created".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CouponCreated {
+    /// The object the event is about.
+    pub object: Coupon,
 }
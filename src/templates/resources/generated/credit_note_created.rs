@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{CreditNote};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "credit_note.created".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CreditNoteCreated {
+    /// The object the event is about.
+    pub object: CreditNote,
 }
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{CustomerCashBalanceTransaction};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "customer_cash_balance_transaction.created".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CustomerCashBalanceTransactionCreated {
+    /// The object the event is about.
+    pub object: CustomerCashBalanceTransaction,
 }
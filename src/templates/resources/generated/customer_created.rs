@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{Customer};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "customer.created".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CustomerCreated {
+    /// The object the event is about.
+    pub object: Customer,
 }
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{Discount};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "customer.discount.deleted".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CustomerDiscountDeleted {
+    /// The object the event is about.
+    pub object: Discount,
 }
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{PaymentSource};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "customer.source.created".
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CustomerSourceCreated {
+    /// The object the event is about.
+    pub object: PaymentSource,
 }
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{Subscription};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "customer.subscription.pending_update_expired".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CustomerSubscriptionPendingUpdateExpired {
+    /// The object the event is about.
+    pub object: Subscription,
 }
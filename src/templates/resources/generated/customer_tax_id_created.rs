@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{TaxId};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "customer.tax_id.created".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CustomerTaxIdCreated {
+    /// The object the event is about.
+    pub object: TaxId,
 }
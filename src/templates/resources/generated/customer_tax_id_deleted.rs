@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{TaxId};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "customer.tax_id.deleted".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CustomerTaxIdDeleted {
+    /// The object the event is about.
+    pub object: TaxId,
 }
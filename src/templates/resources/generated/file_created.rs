@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{File};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "file.created".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct FileCreated {
+    /// The object the event is about.
+    pub object: File,
 }
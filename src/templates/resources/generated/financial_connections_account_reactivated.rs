@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{FinancialConnectionsAccount};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "financial_connections.account.reactivated".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct FinancialConnectionsAccountReactivated {
+    /// The object the event is about.
+    pub object: FinancialConnectionsAccount,
 }
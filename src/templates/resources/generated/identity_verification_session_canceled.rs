@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{IdentityVerificationSession};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "identity.verification_session.canceled".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct IdentityVerificationSessionCanceled {
+    /// The object the event is about.
+    pub object: IdentityVerificationSession,
 }
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{Invoice};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "invoice.upcoming".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct InvoiceUpcoming {
+    /// The object the event is about.
+    pub object: Invoice,
 }
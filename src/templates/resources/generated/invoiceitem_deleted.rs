@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{InvoiceItem};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "invoiceitem.deleted".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct InvoiceitemDeleted {
+    /// The object the event is about.
+    pub object: InvoiceItem,
 }
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{IssuingAuthorization};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "issuing_authorization.created".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct IssuingAuthorizationCreated {
+    /// The object the event is about.
+    pub object: IssuingAuthorization,
 }
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{IssuingCard};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "issuing_card.created".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct IssuingCardCreated {
+    /// The object the event is about.
+    pub object: IssuingCard,
 }
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{IssuingCardholder};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "issuing_cardholder.created".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct IssuingCardholderCreated {
+    /// The object the event is about.
+    pub object: IssuingCardholder,
 }
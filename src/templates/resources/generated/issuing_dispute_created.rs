@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{IssuingDispute};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "issuing_dispute.created".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct IssuingDisputeCreated {
+    /// The object the event is about.
+    pub object: IssuingDispute,
 }
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{IssuingDispute};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "issuing_dispute.funds_rescinded".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct IssuingDisputeFundsRescinded {
+    /// The object the event is about.
+    pub object: IssuingDispute,
 }
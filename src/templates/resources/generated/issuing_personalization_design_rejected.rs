@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{IssuingPersonalizationDesign};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "issuing_personalization_design.rejected".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct IssuingPersonalizationDesignRejected {
+    /// The object the event is about.
+    pub object: IssuingPersonalizationDesign,
 }
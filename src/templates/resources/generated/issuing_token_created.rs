@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{IssuingToken};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "issuing_token.created".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct IssuingTokenCreated {
+    /// The object the event is about.
+    pub object: IssuingToken,
 }
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{IssuingTransaction};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "issuing_transaction.created".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct IssuingTransactionCreated {
+    /// The object the event is about.
+    pub object: IssuingTransaction,
 }
@@ -0,0 +1,52 @@
+// ======================================
+// This file was automatically generated.
+// ======================================
+
+use serde::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "payment_flows_private_payment_methods_card_present_common_wallet".
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PaymentFlowsPrivatePaymentMethodsCardPresentCommonWallet {
+
+    /// The type of mobile wallet, one of `apple_pay`, `google_pay`, `samsung_pay`, or `unknown`.
+    #[serde(rename = "type")]
+    pub type_: PaymentFlowsPrivatePaymentMethodsCardPresentCommonWalletType,
+}
+
+/// An enum representing the possible values of an `PaymentFlowsPrivatePaymentMethodsCardPresentCommonWallet`'s `type` field.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentFlowsPrivatePaymentMethodsCardPresentCommonWalletType {
+    ApplePay,
+    GooglePay,
+    SamsungPay,
+    Unknown,
+}
+
+impl PaymentFlowsPrivatePaymentMethodsCardPresentCommonWalletType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PaymentFlowsPrivatePaymentMethodsCardPresentCommonWalletType::ApplePay => "apple_pay",
+            PaymentFlowsPrivatePaymentMethodsCardPresentCommonWalletType::GooglePay => "google_pay",
+            PaymentFlowsPrivatePaymentMethodsCardPresentCommonWalletType::SamsungPay => "samsung_pay",
+            PaymentFlowsPrivatePaymentMethodsCardPresentCommonWalletType::Unknown => "unknown",
+        }
+    }
+}
+
+impl AsRef<str> for PaymentFlowsPrivatePaymentMethodsCardPresentCommonWalletType {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for PaymentFlowsPrivatePaymentMethodsCardPresentCommonWalletType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl std::default::Default for PaymentFlowsPrivatePaymentMethodsCardPresentCommonWalletType {
+    fn default() -> Self {
+        Self::ApplePay
+    }
+}
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{PaymentIntent};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "payment_intent.amount_capturable_updated".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct PaymentIntentAmountCapturableUpdated {
+    /// The object the event is about.
+    pub object: PaymentIntent,
 }
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{PaymentIntent};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "payment_intent.partially_funded".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct PaymentIntentPartiallyFunded {
+    /// The object the event is about.
+    pub object: PaymentIntent,
 }
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{PaymentLink};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "payment_link.created".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct PaymentLinkCreated {
+    /// The object the event is about.
+    pub object: PaymentLink,
 }
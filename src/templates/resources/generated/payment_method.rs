@@ -1861,8 +1861,8 @@ impl std::default::Default for UpdatePaymentMethodUsBankAccountAccountType {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(untagged, rename_all = "snake_case")]
 pub enum CreatePaymentMethodCardUnion {
-    pub CardDetailsParams(CardDetailsParams),
-    pub TokenParams(TokenParams),
+    CardDetailsParams(CardDetailsParams),
+    TokenParams(TokenParams),
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -1913,14 +1913,3 @@ pub struct UpdateApiParam {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub networks: Option<Networks>,
 }
-
-/// Contains information about card networks used to process the payment.
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct Networks {
-    /// The customer's preferred card network for co-branded cards.
-    ///
-    /// Supports `cartes_bancaires`, `mastercard`, or `visa`.
-    /// Selection of a network that does not apply to the card will be stored as `invalid_preference` on the card.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub preferred: Option<String>,
-}
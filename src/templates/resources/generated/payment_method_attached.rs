@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{PaymentMethod};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "payment_method.attached".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct PaymentMethodAttached {
+    /// The object the event is about.
+    pub object: PaymentMethod,
 }
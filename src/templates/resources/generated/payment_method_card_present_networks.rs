@@ -0,0 +1,16 @@
+// ======================================
+// This file was automatically generated.
+// ======================================
+
+use serde::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "payment_method_card_present_networks".
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PaymentMethodCardPresentNetworks {
+
+    /// All networks available for selection via [payment_method_options.card.network](/api/payment_intents/confirm#confirm_payment_intent-payment_method_options-card-network).
+    pub available: Vec<String>,
+
+    /// The preferred network for the card.
+    pub preferred: Option<String>,
+}
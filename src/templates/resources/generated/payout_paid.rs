@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{Payout};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "payout.paid".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct PayoutPaid {
+    /// The object the event is about.
+    pub object: Payout,
 }
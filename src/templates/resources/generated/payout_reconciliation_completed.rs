@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{Payout};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "payout.reconciliation_completed".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct PayoutReconciliationCompleted {
+    /// The object the event is about.
+    pub object: Payout,
 }
@@ -0,0 +1,21 @@
+// ======================================
+// This file was automatically generated.
+// ======================================
+
+use crate::stripe::params::{Timestamp};
+use serde::{Deserialize, Serialize};
+
+/// The resource representing a Stripe "InvoiceLineItemPeriod".
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Period {
+
+    /// The end of the period, which must be greater than or equal to the start.
+    ///
+    /// This value is inclusive.
+    pub end: Option<Timestamp>,
+
+    /// The start of the period.
+    ///
+    /// This value is inclusive.
+    pub start: Option<Timestamp>,
+}
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{Person};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "person.created".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct PersonCreated {
+    /// The object the event is about.
+    pub object: Person,
 }
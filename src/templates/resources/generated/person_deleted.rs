@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{Person};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "person.deleted".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct PersonDeleted {
+    /// The object the event is about.
+    pub object: Person,
 }
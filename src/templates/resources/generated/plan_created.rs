@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{Plan};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "plan.created".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct PlanCreated {
+    /// The object the event is about.
+    pub object: Plan,
 }
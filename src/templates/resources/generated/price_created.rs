@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{Price};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "price.created".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct PriceCreated {
+    /// The object the event is about.
+    pub object: Price,
 }
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{Product};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "product.created".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct ProductCreated {
+    /// The object the event is about.
+    pub object: Product,
 }
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{Product};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "product.deleted".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct ProductDeleted {
+    /// The object the event is about.
+    pub object: Product,
 }
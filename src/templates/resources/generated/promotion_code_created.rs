@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{PromotionCode};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "promotion_code.created".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct PromotionCodeCreated {
+    /// The object the event is about.
+    pub object: PromotionCode,
 }
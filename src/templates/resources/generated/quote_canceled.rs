@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{Quote};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "quote.canceled".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct QuoteCanceled {
+    /// The object the event is about.
+    pub object: Quote,
 }
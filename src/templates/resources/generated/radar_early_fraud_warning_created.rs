@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{RadarEarlyFraudWarning};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "radar.early_fraud_warning.created".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct RadarEarlyFraudWarningCreated {
+    /// The object the event is about.
+    pub object: RadarEarlyFraudWarning,
 }
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{Refund};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "refund.failed".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct RefundFailed {
+    /// The object the event is about.
+    pub object: Refund,
 }
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{ReportingReportRun};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "reporting.report_run.succeeded".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct ReportingReportRunSucceeded {
+    /// The object the event is about.
+    pub object: ReportingReportRun,
 }
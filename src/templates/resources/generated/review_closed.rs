@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{Review};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "review.closed".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct ReviewClosed {
+    /// The object the event is about.
+    pub object: Review,
 }
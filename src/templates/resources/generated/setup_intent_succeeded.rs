@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{SetupIntent};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "setup_intent.succeeded".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct SetupIntentSucceeded {
+    /// The object the event is about.
+    pub object: SetupIntent,
 }
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{ScheduledQueryRun};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "sigma.scheduled_query_run.created".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct SigmaScheduledQueryRunCreated {
+    /// The object the event is about.
+    pub object: ScheduledQueryRun,
 }
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{Source};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "source.canceled".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct SourceCanceled {
+    /// The object the event is about.
+    pub object: Source,
 }
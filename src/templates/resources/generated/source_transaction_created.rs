@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{SourceTransaction};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "source.transaction.created".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct SourceTransactionCreated {
+    /// The object the event is about.
+    pub object: SourceTransaction,
 }
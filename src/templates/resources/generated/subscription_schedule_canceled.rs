@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{SubscriptionSchedule};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "subscription_schedule.canceled".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct SubscriptionScheduleCanceled {
+    /// The object the event is about.
+    pub object: SubscriptionSchedule,
 }
@@ -2,7 +2,6 @@
 // This file was automatically generated.
 // ======================================
 
-use crate::stripe::resources::{TaxProductResourcePostalAddress};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "TaxProductResourceCustomerDetails".
@@ -36,6 +35,30 @@ pub struct TaxProductResourceCustomerDetailsResourceTaxId {
     pub value: String,
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TaxProductResourcePostalAddress {
+
+    /// City, district, suburb, town, or village.
+    pub city: Option<String>,
+
+    /// Two-letter country code ([ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2)).
+    pub country: String,
+
+    /// Address line 1 (e.g., street, PO Box, or company name).
+    pub line1: Option<String>,
+
+    /// Address line 2 (e.g., apartment, suite, unit, or building).
+    pub line2: Option<String>,
+
+    /// ZIP or postal code.
+    pub postal_code: Option<String>,
+
+    /// State/province as an [ISO 3166-2](https://en.wikipedia.org/wiki/ISO_3166-2) subdivision code, without country prefix.
+    ///
+    /// Example: "NY" or "TX".
+    pub state: Option<String>,
+}
+
 /// An enum representing the possible values of an `TaxProductResourceCustomerDetails`'s `address_source` field.
 #[derive(Copy, Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{TaxRate};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "tax_rate.created".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TaxRateCreated {
+    /// The object the event is about.
+    pub object: TaxRate,
 }
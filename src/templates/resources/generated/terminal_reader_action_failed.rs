@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{TerminalReader};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "terminal.reader.action_failed".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TerminalReaderActionFailed {
+    /// The object the event is about.
+    pub object: TerminalReader,
 }
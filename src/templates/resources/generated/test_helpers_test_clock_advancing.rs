@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{TestHelpersTestClock};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "test_helpers.test_clock.advancing".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TestHelpersTestClockAdvancing {
+    /// The object the event is about.
+    pub object: TestHelpersTestClock,
 }
@@ -535,8 +535,8 @@ impl std::default::Default for CreateTokenPersonPoliticalExposure {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(untagged, rename_all = "snake_case")]
 pub enum CreateTokenCardUnion {
-    pub CreditCardSpecs(CreditCardSpecs),
-    pub String(String),
+    CreditCardSpecs(CreditCardSpecs),
+    String(String),
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{Topup};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "topup.succeeded".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TopupSucceeded {
+    /// The object the event is about.
+    pub object: Topup,
 }
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{Transfer};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "transfer.reversed".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TransferReversed {
+    /// The object the event is about.
+    pub object: Transfer,
 }
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{TreasuryCreditReversal};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "treasury.credit_reversal.posted".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryCreditReversalPosted {
+    /// The object the event is about.
+    pub object: TreasuryCreditReversal,
 }
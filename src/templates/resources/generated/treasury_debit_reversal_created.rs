@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{TreasuryDebitReversal};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "treasury.debit_reversal.created".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryDebitReversalCreated {
+    /// The object the event is about.
+    pub object: TreasuryDebitReversal,
 }
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{TreasuryFinancialAccount};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "treasury.financial_account.closed".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryFinancialAccountClosed {
+    /// The object the event is about.
+    pub object: TreasuryFinancialAccount,
 }
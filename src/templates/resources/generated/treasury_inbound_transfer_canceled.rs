@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{TreasuryInboundTransfer};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "treasury.inbound_transfer.canceled".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryInboundTransferCanceled {
+    /// The object the event is about.
+    pub object: TreasuryInboundTransfer,
 }
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{TreasuryOutboundPayment};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "treasury.outbound_payment.expected_arrival_date_updated".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryOutboundPaymentExpectedArrivalDateUpdated {
+    /// The object the event is about.
+    pub object: TreasuryOutboundPayment,
 }
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{TreasuryOutboundTransfer};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "treasury.outbound_transfer.canceled".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryOutboundTransferCanceled {
+    /// The object the event is about.
+    pub object: TreasuryOutboundTransfer,
 }
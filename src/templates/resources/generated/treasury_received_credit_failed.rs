@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{TreasuryReceivedCredit};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "treasury.received_credit.failed".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryReceivedCreditFailed {
+    /// The object the event is about.
+    pub object: TreasuryReceivedCredit,
 }
@@ -2,9 +2,12 @@
 // This file was automatically generated.
 // ======================================
 
+use crate::stripe::resources::{TreasuryReceivedDebit};
 use serde::{Deserialize, Serialize};
 
 /// The resource representing a Stripe "treasury.received_debit.created".
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct TreasuryReceivedDebitCreated {
+    /// The object the event is about.
+    pub object: TreasuryReceivedDebit,
 }
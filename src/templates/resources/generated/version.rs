@@ -1,3 +1,3 @@
 use crate::stripe::ApiVersion;
 
-pub const VERSION: ApiVersion = ApiVersion::V2025_03_31.basil;
\ No newline at end of file
+pub const VERSION: ApiVersion = ApiVersion::V2025_03_31;
@@ -0,0 +1,339 @@
+use serde::Serialize;
+
+use crate::stripe::client::{Client, Response};
+use crate::stripe::ids::{CustomerId, IdentityVerificationReportId, IdentityVerificationSessionId};
+use crate::stripe::params::{Expand, List, Metadata, Object, Paginable, RangeQuery, Timestamp};
+use crate::stripe::resources::{
+    GelatoProvidedDetails, GelatoVerificationSessionOptions, IdentityVerificationReport, IdentityVerificationReportType,
+    IdentityVerificationSession, IdentityVerificationSessionStatus, IdentityVerificationSessionType,
+};
+
+impl IdentityVerificationSession {
+    /// Returns a list of VerificationSessions.
+    pub fn list(
+        client: &Client,
+        params: &ListIdentityVerificationSessions<'_>,
+    ) -> Response<List<IdentityVerificationSession>> {
+        client.get_query("/identity/verification_sessions", params)
+    }
+
+    /// Creates a VerificationSession object.
+    ///
+    /// After the VerificationSession is created, display a verification modal using the session `client_secret` or send your users to the session’s `url`.
+    pub fn create(
+        client: &Client,
+        params: CreateIdentityVerificationSession<'_>,
+    ) -> Response<IdentityVerificationSession> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form("/identity/verification_sessions", &params)
+    }
+
+    /// Retrieves the details of a VerificationSession that was previously created.
+    ///
+    /// When the session status is `requires_input`, you can use this method to retrieve a valid `client_secret` or `url` to allow re-submission.
+    pub fn retrieve(
+        client: &Client,
+        id: &IdentityVerificationSessionId,
+        expand: &[&str],
+    ) -> Response<IdentityVerificationSession> {
+        client.get_query(&format!("/identity/verification_sessions/{}", id), &Expand { expand })
+    }
+
+    /// Updates a VerificationSession object.
+    ///
+    /// When the session status is `requires_input`, you can use this method to update the verification check and options.
+    pub fn update(
+        client: &Client,
+        id: &IdentityVerificationSessionId,
+        params: UpdateIdentityVerificationSession<'_>,
+    ) -> Response<IdentityVerificationSession> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form(&format!("/identity/verification_sessions/{}", id), &params)
+    }
+
+    /// A VerificationSession object can be canceled when it is in `requires_input` status.
+    ///
+    /// Once canceled, future submission attempts are disabled.
+    /// This cannot be undone.
+    pub fn cancel(client: &Client, id: &IdentityVerificationSessionId) -> Response<IdentityVerificationSession> {
+        client.post(&format!("/identity/verification_sessions/{}/cancel", id))
+    }
+
+    /// Redact a VerificationSession to remove all collected information from Stripe.
+    ///
+    /// This will redact the VerificationSession and all objects related to it, including VerificationReports, Events, request logs, etc.
+    /// A VerificationSession object in the `processing` status cannot be redacted.
+    pub fn redact(client: &Client, id: &IdentityVerificationSessionId) -> Response<IdentityVerificationSession> {
+        client.post(&format!("/identity/verification_sessions/{}/redact", id))
+    }
+}
+
+impl IdentityVerificationReport {
+    /// List all verification reports.
+    pub fn list(
+        client: &Client,
+        params: &ListIdentityVerificationReports<'_>,
+    ) -> Response<List<IdentityVerificationReport>> {
+        client.get_query("/identity/verification_reports", params)
+    }
+
+    /// Retrieves an existing VerificationReport.
+    pub fn retrieve(
+        client: &Client,
+        id: &IdentityVerificationReportId,
+        expand: &[&str],
+    ) -> Response<IdentityVerificationReport> {
+        client.get_query(&format!("/identity/verification_reports/{}", id), &Expand { expand })
+    }
+}
+
+/// The parameters for `IdentityVerificationSession::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListIdentityVerificationSessions<'a> {
+    /// A string to reference this user.
+    ///
+    /// This can be a customer ID, a session ID, or similar, and can be used to reconcile this verification with your internal systems.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_reference_id: Option<&'a str>,
+
+    /// Only return VerificationSessions that were created during the given date interval.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<IdentityVerificationSessionId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// Only return VerificationSessions related to the given customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub related_customer: Option<CustomerId>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<IdentityVerificationSessionId>,
+
+    /// Only return VerificationSessions with this status.
+    ///
+    /// [Learn more about the lifecycle of sessions](https://stripe.com/docs/identity/how-sessions-work).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<IdentityVerificationSessionStatus>,
+}
+
+impl ListIdentityVerificationSessions<'_> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Paginable for ListIdentityVerificationSessions<'_> {
+    type O = IdentityVerificationSession;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+/// The parameters for `IdentityVerificationSession::create`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct CreateIdentityVerificationSession<'a> {
+    /// A string to reference this user.
+    ///
+    /// This can be a customer ID, a session ID, or similar, and can be used to reconcile this verification with your internal systems.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_reference_id: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// A set of options for the session’s verification checks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<GelatoVerificationSessionOptions>,
+
+    /// Details provided about the user being verified.
+    ///
+    /// These details may be shown to the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provided_details: Option<GelatoProvidedDetails>,
+
+    /// Customer ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub related_customer: Option<CustomerId>,
+
+    /// The URL that the user will be redirected to upon completing the verification flow.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_url: Option<&'a str>,
+
+    /// The type of [verification check](https://stripe.com/docs/identity/verification-checks) to be performed.
+    ///
+    /// You must provide a `type` if not passing `verification_flow`.
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<IdentityVerificationSessionType>,
+
+    /// The ID of a verification flow from the Dashboard.
+    ///
+    /// See <https://docs.stripe.com/identity/verification-flows>.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification_flow: Option<&'a str>,
+}
+
+impl CreateIdentityVerificationSession<'_> {
+    /// A session performing the given type of verification check.
+    pub fn new(type_: IdentityVerificationSessionType) -> Self {
+        CreateIdentityVerificationSession { type_: Some(type_), ..Default::default() }
+    }
+}
+
+/// The parameters for `IdentityVerificationSession::update`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateIdentityVerificationSession<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// A set of options for the session’s verification checks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<GelatoVerificationSessionOptions>,
+
+    /// Details provided about the user being verified.
+    ///
+    /// These details may be shown to the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provided_details: Option<GelatoProvidedDetails>,
+
+    /// The type of [verification check](https://stripe.com/docs/identity/verification-checks) to be performed.
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<IdentityVerificationSessionType>,
+}
+
+impl UpdateIdentityVerificationSession<'_> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// The parameters for `IdentityVerificationReport::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListIdentityVerificationReports<'a> {
+    /// A string to reference this user.
+    ///
+    /// This can be a customer ID, a session ID, or similar, and can be used to reconcile this verification with your internal systems.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_reference_id: Option<&'a str>,
+
+    /// Only return VerificationReports that were created during the given date interval.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<IdentityVerificationReportId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<IdentityVerificationReportId>,
+
+    /// Only return VerificationReports of this type.
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<IdentityVerificationReportType>,
+
+    /// Only return VerificationReports created by this VerificationSession ID.
+    ///
+    /// It is allowed to provide a VerificationIntent ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification_session: Option<IdentityVerificationSessionId>,
+}
+
+impl ListIdentityVerificationReports<'_> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Paginable for ListIdentityVerificationReports<'_> {
+    type O = IdentityVerificationReport;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stripe::client::testing::request;
+
+    #[test]
+    fn test_create_session_params() {
+        let mut params = CreateIdentityVerificationSession::new(IdentityVerificationSessionType::Document);
+        params.client_reference_id = Some("user_42");
+        params.related_customer = Some("cus_123".parse().unwrap());
+        assert_eq!(
+            serde_qs::to_string(&params).unwrap(),
+            "client_reference_id=user_42&related_customer=cus_123&type=document"
+        );
+    }
+
+    #[test]
+    fn test_list_sessions_params() {
+        let mut params = ListIdentityVerificationSessions::new();
+        params.limit = Some(5);
+        params.status = Some(IdentityVerificationSessionStatus::RequiresInput);
+        assert_eq!(serde_qs::to_string(&params).unwrap(), "limit=5&status=requires_input");
+    }
+
+    #[test]
+    fn test_paths() {
+        let params = CreateIdentityVerificationSession::new(IdentityVerificationSessionType::IdNumber);
+        let (path, body) = request(|client| IdentityVerificationSession::create(client, params));
+        assert_eq!(path, "POST /v1/identity/verification_sessions");
+        assert_eq!(body, "type=id_number");
+
+        let session: IdentityVerificationSessionId = "vs_123".parse().unwrap();
+        let (path, _) = request(|client| IdentityVerificationSession::cancel(client, &session));
+        assert_eq!(path, "POST /v1/identity/verification_sessions/vs_123/cancel");
+        let (path, _) = request(|client| IdentityVerificationSession::redact(client, &session));
+        assert_eq!(path, "POST /v1/identity/verification_sessions/vs_123/redact");
+
+        let report: IdentityVerificationReportId = "vr_123".parse().unwrap();
+        let (path, _) = request(|client| IdentityVerificationReport::retrieve(client, &report, &[]));
+        assert_eq!(path, "GET /v1/identity/verification_reports/vr_123");
+    }
+}
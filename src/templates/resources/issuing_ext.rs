@@ -0,0 +1,906 @@
+use serde::Serialize;
+
+use crate::stripe::client::{Client, Response};
+use crate::stripe::ids::{
+    IssuingAuthorizationId, IssuingCardId, IssuingCardholderId, IssuingDisputeId, IssuingTransactionId,
+};
+use crate::stripe::params::{Expand, List, Metadata, Object, Paginable, RangeQuery, Timestamp};
+use crate::stripe::resources::{
+    Address, ApiVersion, Currency, IssuingAuthorization, IssuingAuthorizationStatus, IssuingCard,
+    IssuingCardCancellationReason, IssuingCardReplacementReason, IssuingCardSpendingLimit, IssuingCardStatus,
+    IssuingCardType, IssuingCardholder, IssuingCardholderIndividualDob, IssuingCardholderPreferredLocales,
+    IssuingCardholderSpendingLimit, IssuingCardholderStatus, IssuingCardholderType, IssuingDispute,
+    IssuingDisputeStatus, IssuingTransaction, IssuingTransactionType, MerchantCategory,
+};
+
+impl IssuingCard {
+    /// Returns a list of Issuing `Card` objects.
+    ///
+    /// The objects are sorted in descending order by creation date, with the most recently created object appearing first.
+    pub fn list(client: &Client, params: &ListIssuingCards<'_>) -> Response<List<IssuingCard>> {
+        client.get_query("/issuing/cards", params)
+    }
+
+    /// Creates an Issuing `Card` object.
+    pub fn create(client: &Client, params: CreateIssuingCard<'_>) -> Response<IssuingCard> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form("/issuing/cards", &params)
+    }
+
+    /// Retrieves an Issuing `Card` object.
+    pub fn retrieve(client: &Client, id: &IssuingCardId, expand: &[&str]) -> Response<IssuingCard> {
+        client.get_query(&format!("/issuing/cards/{}", id), &Expand { expand })
+    }
+
+    /// Updates the specified Issuing `Card` object by setting the values of the parameters passed.
+    ///
+    /// Any parameters not provided will be left unchanged.
+    pub fn update(client: &Client, id: &IssuingCardId, params: UpdateIssuingCard<'_>) -> Response<IssuingCard> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form(&format!("/issuing/cards/{}", id), &params)
+    }
+}
+
+impl IssuingCardholder {
+    /// Returns a list of Issuing `Cardholder` objects.
+    ///
+    /// The objects are sorted in descending order by creation date, with the most recently created object appearing first.
+    pub fn list(client: &Client, params: &ListIssuingCardholders<'_>) -> Response<List<IssuingCardholder>> {
+        client.get_query("/issuing/cardholders", params)
+    }
+
+    /// Creates a new Issuing `Cardholder` object that can be issued cards.
+    pub fn create(client: &Client, params: CreateIssuingCardholder<'_>) -> Response<IssuingCardholder> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form("/issuing/cardholders", &params)
+    }
+
+    /// Retrieves an Issuing `Cardholder` object.
+    pub fn retrieve(client: &Client, id: &IssuingCardholderId, expand: &[&str]) -> Response<IssuingCardholder> {
+        client.get_query(&format!("/issuing/cardholders/{}", id), &Expand { expand })
+    }
+
+    /// Updates the specified Issuing `Cardholder` object by setting the values of the parameters passed.
+    ///
+    /// Any parameters not provided will be left unchanged.
+    pub fn update(
+        client: &Client,
+        id: &IssuingCardholderId,
+        params: UpdateIssuingCardholder<'_>,
+    ) -> Response<IssuingCardholder> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form(&format!("/issuing/cardholders/{}", id), &params)
+    }
+}
+
+impl IssuingAuthorization {
+    /// Returns a list of Issuing `Authorization` objects.
+    ///
+    /// The objects are sorted in descending order by creation date, with the most recently created object appearing first.
+    pub fn list(client: &Client, params: &ListIssuingAuthorizations<'_>) -> Response<List<IssuingAuthorization>> {
+        client.get_query("/issuing/authorizations", params)
+    }
+
+    /// Retrieves an Issuing `Authorization` object.
+    pub fn retrieve(client: &Client, id: &IssuingAuthorizationId, expand: &[&str]) -> Response<IssuingAuthorization> {
+        client.get_query(&format!("/issuing/authorizations/{}", id), &Expand { expand })
+    }
+
+    /// Updates the specified Issuing `Authorization` object by setting the values of the parameters passed.
+    ///
+    /// Any parameters not provided will be left unchanged.
+    pub fn update(
+        client: &Client,
+        id: &IssuingAuthorizationId,
+        params: UpdateIssuingAuthorization<'_>,
+    ) -> Response<IssuingAuthorization> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form(&format!("/issuing/authorizations/{}", id), &params)
+    }
+
+    /// Approves a pending Issuing `Authorization` object.
+    ///
+    /// This request should be made within the timeout window of the [real-time authorization](https://stripe.com/docs/issuing/controls/real-time-authorizations) flow.
+    /// Responding to the `issuing_authorization.request` webhook with an [`IssuingAuthorizationDecision`] is preferred.
+    pub fn approve(
+        client: &Client,
+        id: &IssuingAuthorizationId,
+        params: ApproveIssuingAuthorization<'_>,
+    ) -> Response<IssuingAuthorization> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form(&format!("/issuing/authorizations/{}/approve", id), &params)
+    }
+
+    /// Declines a pending Issuing `Authorization` object.
+    ///
+    /// This request should be made within the timeout window of the [real-time authorization](https://stripe.com/docs/issuing/controls/real-time-authorizations) flow.
+    /// Responding to the `issuing_authorization.request` webhook with an [`IssuingAuthorizationDecision`] is preferred.
+    pub fn decline(
+        client: &Client,
+        id: &IssuingAuthorizationId,
+        params: DeclineIssuingAuthorization<'_>,
+    ) -> Response<IssuingAuthorization> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form(&format!("/issuing/authorizations/{}/decline", id), &params)
+    }
+
+    /// Approves or declines a pending authorization through the API, as `decision` says.
+    pub fn respond(
+        client: &Client,
+        id: &IssuingAuthorizationId,
+        decision: &IssuingAuthorizationDecision,
+    ) -> Response<IssuingAuthorization> {
+        if decision.approved {
+            let mut params = ApproveIssuingAuthorization::new();
+            params.amount = decision.amount;
+            params.metadata = decision.metadata.clone();
+            IssuingAuthorization::approve(client, id, params)
+        } else {
+            let mut params = DeclineIssuingAuthorization::new();
+            params.metadata = decision.metadata.clone();
+            IssuingAuthorization::decline(client, id, params)
+        }
+    }
+
+    /// The amount a pending request asks to hold, if this authorization is awaiting a decision.
+    pub fn pending_amount(&self) -> Option<i64> {
+        self.pending_request.as_ref().map(|request| request.amount)
+    }
+
+    /// Whether a partial amount can be approved for the pending request.
+    pub fn is_amount_controllable(&self) -> bool {
+        self.pending_request.as_ref().is_some_and(|request| request.is_amount_controllable)
+    }
+}
+
+impl IssuingTransaction {
+    /// Returns a list of Issuing `Transaction` objects.
+    ///
+    /// The objects are sorted in descending order by creation date, with the most recently created object appearing first.
+    pub fn list(client: &Client, params: &ListIssuingTransactions<'_>) -> Response<List<IssuingTransaction>> {
+        client.get_query("/issuing/transactions", params)
+    }
+
+    /// Retrieves an Issuing `Transaction` object.
+    pub fn retrieve(client: &Client, id: &IssuingTransactionId, expand: &[&str]) -> Response<IssuingTransaction> {
+        client.get_query(&format!("/issuing/transactions/{}", id), &Expand { expand })
+    }
+}
+
+impl IssuingDispute {
+    /// Returns a list of Issuing `Dispute` objects.
+    ///
+    /// The objects are sorted in descending order by creation date, with the most recently created object appearing first.
+    pub fn list(client: &Client, params: &ListIssuingDisputes<'_>) -> Response<List<IssuingDispute>> {
+        client.get_query("/issuing/disputes", params)
+    }
+
+    /// Retrieves an Issuing `Dispute` object.
+    pub fn retrieve(client: &Client, id: &IssuingDisputeId, expand: &[&str]) -> Response<IssuingDispute> {
+        client.get_query(&format!("/issuing/disputes/{}", id), &Expand { expand })
+    }
+}
+
+/// The reply to an `issuing_authorization.request` webhook.
+///
+/// Stripe waits for the webhook response to approve or decline a [real-time authorization](https://stripe.com/docs/issuing/controls/real-time-authorizations).
+/// Respond with status `200`, the [`headers`](IssuingAuthorizationDecision::headers) and the [`body`](IssuingAuthorizationDecision::body).
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct IssuingAuthorizationDecision {
+    /// Whether to approve the authorization.
+    pub approved: bool,
+
+    /// The amount to approve, for partial approvals of amount-controllable requests.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) to attach to the authorization.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+impl IssuingAuthorizationDecision {
+    /// Approve the authorization.
+    pub fn approve() -> Self {
+        Self { approved: true, ..Default::default() }
+    }
+
+    /// Decline the authorization.
+    pub fn decline() -> Self {
+        Self { approved: false, ..Default::default() }
+    }
+
+    /// Approve only `amount`, when the pending request is amount-controllable.
+    pub fn with_amount(mut self, amount: i64) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    /// Attach metadata to the authorization.
+    pub fn with_metadata(mut self, metadata: Metadata) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
+    /// The headers the webhook response must carry.
+    pub fn headers() -> [(&'static str, &'static str); 2] {
+        [("content-type", "application/json"), ("stripe-version", ApiVersion::default().as_str())]
+    }
+
+    /// The JSON body of the webhook response.
+    pub fn body(&self) -> String {
+        serde_json::to_string(self).unwrap_or_else(|_| format!("{{\"approved\":{}}}", self.approved))
+    }
+}
+
+/// The spending rules that can be set on an Issuing `Card`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct IssuingCardSpendingControls {
+    /// Array of strings containing categories of authorizations to allow.
+    ///
+    /// All other categories will be blocked.
+    /// Cannot be set with `blocked_categories`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_categories: Option<Vec<MerchantCategory>>,
+
+    /// Array of strings containing countries from which authorizations will be allowed.
+    ///
+    /// Cannot be set with `blocked_merchant_countries`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_merchant_countries: Option<Vec<String>>,
+
+    /// Array of strings containing categories of authorizations to decline.
+    ///
+    /// All other categories will be allowed.
+    /// Cannot be set with `allowed_categories`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocked_categories: Option<Vec<MerchantCategory>>,
+
+    /// Array of strings containing countries from which authorizations will be declined.
+    ///
+    /// Cannot be set with `allowed_merchant_countries`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocked_merchant_countries: Option<Vec<String>>,
+
+    /// Limit spending with amount-based rules that apply across any cards this card replaced.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spending_limits: Option<Vec<IssuingCardSpendingLimit>>,
+}
+
+/// The spending rules that can be set on an Issuing `Cardholder`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct IssuingCardholderSpendingControls {
+    /// Array of strings containing categories of authorizations to allow.
+    ///
+    /// All other categories will be blocked.
+    /// Cannot be set with `blocked_categories`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_categories: Option<Vec<MerchantCategory>>,
+
+    /// Array of strings containing countries from which authorizations will be allowed.
+    ///
+    /// Cannot be set with `blocked_merchant_countries`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_merchant_countries: Option<Vec<String>>,
+
+    /// Array of strings containing categories of authorizations to decline.
+    ///
+    /// All other categories will be allowed.
+    /// Cannot be set with `allowed_categories`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocked_categories: Option<Vec<MerchantCategory>>,
+
+    /// Array of strings containing countries from which authorizations will be declined.
+    ///
+    /// Cannot be set with `allowed_merchant_countries`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocked_merchant_countries: Option<Vec<String>>,
+
+    /// Limit spending with amount-based rules that apply across this cardholder's cards.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spending_limits: Option<Vec<IssuingCardholderSpendingLimit>>,
+
+    /// Currency of amounts within `spending_limits`.
+    ///
+    /// Defaults to your merchant country's currency.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spending_limits_currency: Option<Currency>,
+}
+
+/// The billing address of an Issuing `Cardholder`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct IssuingCardholderBilling {
+    /// The cardholder’s billing address.
+    pub address: Address,
+}
+
+/// Additional information about an `individual` cardholder.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct IssuingCardholderIndividualParams<'a> {
+    /// The date of birth of this cardholder.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dob: Option<IssuingCardholderIndividualDob>,
+
+    /// The first name of this cardholder.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<&'a str>,
+
+    /// The last name of this cardholder.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<&'a str>,
+}
+
+/// The parameters for `IssuingCard::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListIssuingCards<'a> {
+    /// Only return cards belonging to the Cardholder with the provided ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cardholder: Option<IssuingCardholderId>,
+
+    /// Only return cards that were issued during the given date interval.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<IssuingCardId>,
+
+    /// Only return cards that have the given expiration month.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp_month: Option<i64>,
+
+    /// Only return cards that have the given expiration year.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp_year: Option<i64>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Only return cards that have the given last four digits.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last4: Option<&'a str>,
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<IssuingCardId>,
+
+    /// Only return cards that have the given status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<IssuingCardStatus>,
+
+    /// Only return cards that have the given type.
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<IssuingCardType>,
+}
+
+impl ListIssuingCards<'_> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Paginable for ListIssuingCards<'_> {
+    type O = IssuingCard;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+/// The parameters for `IssuingCard::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateIssuingCard<'a> {
+    /// The Cardholder object with which the card will be associated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cardholder: Option<IssuingCardholderId>,
+
+    /// The currency for the card.
+    pub currency: Currency,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The card this is meant to be a replacement for (if any).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replacement_for: Option<IssuingCardId>,
+
+    /// If `replacement_for` is specified, this should indicate why that card is being replaced.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replacement_reason: Option<IssuingCardReplacementReason>,
+
+    /// Rules that control spending for this card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spending_controls: Option<IssuingCardSpendingControls>,
+
+    /// Whether authorizations can be approved on this card.
+    ///
+    /// May be blocked from activating cards depending on past-due Cardholder requirements.
+    /// Defaults to `inactive`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<IssuingCardStatus>,
+
+    /// The type of card to issue.
+    #[serde(rename = "type")]
+    pub type_: IssuingCardType,
+}
+
+impl CreateIssuingCard<'_> {
+    pub fn new(currency: Currency, type_: IssuingCardType) -> Self {
+        Self {
+            cardholder: Default::default(),
+            currency,
+            expand: Default::default(),
+            metadata: Default::default(),
+            replacement_for: Default::default(),
+            replacement_reason: Default::default(),
+            spending_controls: Default::default(),
+            status: Default::default(),
+            type_,
+        }
+    }
+}
+
+/// The parameters for `IssuingCard::update`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateIssuingCard<'a> {
+    /// Reason why the `status` of this card is `canceled`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancellation_reason: Option<IssuingCardCancellationReason>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// Rules that control spending for this card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spending_controls: Option<IssuingCardSpendingControls>,
+
+    /// Dictates whether authorizations can be approved on this card.
+    ///
+    /// If this card is being canceled because it was lost or stolen, this information should be provided as `cancellation_reason`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<IssuingCardStatus>,
+}
+
+impl UpdateIssuingCard<'_> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// The parameters for `IssuingCardholder::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListIssuingCardholders<'a> {
+    /// Only return cardholders that were created during the given date interval.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+
+    /// Only return cardholders that have the given email address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<IssuingCardholderId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// Only return cardholders that have the given phone number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<&'a str>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<IssuingCardholderId>,
+
+    /// Only return cardholders that have the given status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<IssuingCardholderStatus>,
+
+    /// Only return cardholders that have the given type.
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<IssuingCardholderType>,
+}
+
+impl ListIssuingCardholders<'_> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Paginable for ListIssuingCardholders<'_> {
+    type O = IssuingCardholder;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+/// The parameters for `IssuingCardholder::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateIssuingCardholder<'a> {
+    /// The cardholder's billing address.
+    pub billing: IssuingCardholderBilling,
+
+    /// The cardholder's email address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Additional information about an `individual` cardholder.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub individual: Option<IssuingCardholderIndividualParams<'a>>,
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The cardholder's name.
+    ///
+    /// This will be printed on cards issued to them.
+    pub name: &'a str,
+
+    /// The cardholder's phone number.
+    ///
+    /// This will be transformed to [E.164](https://en.wikipedia.org/wiki/E.164) if it is not provided in that format already.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<&'a str>,
+
+    /// The cardholder’s preferred locales (languages), ordered by preference.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferred_locales: Option<Vec<IssuingCardholderPreferredLocales>>,
+
+    /// Rules that control spending across this cardholder's cards.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spending_controls: Option<IssuingCardholderSpendingControls>,
+
+    /// Specifies whether to permit authorizations on this cardholder's cards.
+    ///
+    /// Defaults to `active`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<IssuingCardholderStatus>,
+
+    /// One of `individual` or `company`.
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<IssuingCardholderType>,
+}
+
+impl<'a> CreateIssuingCardholder<'a> {
+    pub fn new(name: &'a str, billing: IssuingCardholderBilling) -> Self {
+        Self {
+            billing,
+            email: Default::default(),
+            expand: Default::default(),
+            individual: Default::default(),
+            metadata: Default::default(),
+            name,
+            phone_number: Default::default(),
+            preferred_locales: Default::default(),
+            spending_controls: Default::default(),
+            status: Default::default(),
+            type_: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `IssuingCardholder::update`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateIssuingCardholder<'a> {
+    /// The cardholder's billing address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing: Option<IssuingCardholderBilling>,
+
+    /// The cardholder's email address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Additional information about an `individual` cardholder.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub individual: Option<IssuingCardholderIndividualParams<'a>>,
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The cardholder's phone number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<&'a str>,
+
+    /// The cardholder’s preferred locales (languages), ordered by preference.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferred_locales: Option<Vec<IssuingCardholderPreferredLocales>>,
+
+    /// Rules that control spending across this cardholder's cards.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spending_controls: Option<IssuingCardholderSpendingControls>,
+
+    /// Specifies whether to permit authorizations on this cardholder's cards.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<IssuingCardholderStatus>,
+}
+
+impl UpdateIssuingCardholder<'_> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// The parameters for `IssuingAuthorization::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListIssuingAuthorizations<'a> {
+    /// Only return authorizations that belong to the given card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card: Option<IssuingCardId>,
+
+    /// Only return authorizations that belong to the given cardholder.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cardholder: Option<IssuingCardholderId>,
+
+    /// Only return authorizations that were created during the given date interval.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<IssuingAuthorizationId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<IssuingAuthorizationId>,
+
+    /// Only return authorizations with the given status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<IssuingAuthorizationStatus>,
+}
+
+impl ListIssuingAuthorizations<'_> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Paginable for ListIssuingAuthorizations<'_> {
+    type O = IssuingAuthorization;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+/// The parameters for `IssuingAuthorization::update`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateIssuingAuthorization<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+impl UpdateIssuingAuthorization<'_> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// The parameters for `IssuingAuthorization::approve`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ApproveIssuingAuthorization<'a> {
+    /// If the authorization's `pending_request.is_amount_controllable` property is `true`, you may provide this value to control how much to hold for the authorization.
+    ///
+    /// Must be positive (use [`decline`](IssuingAuthorization::decline) to decline an authorization request).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+impl ApproveIssuingAuthorization<'_> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// The parameters for `IssuingAuthorization::decline`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct DeclineIssuingAuthorization<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+impl DeclineIssuingAuthorization<'_> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// The parameters for `IssuingTransaction::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListIssuingTransactions<'a> {
+    /// Only return transactions that belong to the given card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card: Option<IssuingCardId>,
+
+    /// Only return transactions that belong to the given cardholder.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cardholder: Option<IssuingCardholderId>,
+
+    /// Only return transactions that were created during the given date interval.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<IssuingTransactionId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<IssuingTransactionId>,
+
+    /// Only return transactions that have the given type.
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<IssuingTransactionType>,
+}
+
+impl ListIssuingTransactions<'_> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Paginable for ListIssuingTransactions<'_> {
+    type O = IssuingTransaction;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+/// The parameters for `IssuingDispute::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListIssuingDisputes<'a> {
+    /// Only return disputes that were created during the given date interval.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<IssuingDisputeId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<IssuingDisputeId>,
+
+    /// Select Issuing disputes with the given status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<IssuingDisputeStatus>,
+
+    /// Select the Issuing dispute for the given transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction: Option<IssuingTransactionId>,
+}
+
+impl ListIssuingDisputes<'_> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Paginable for ListIssuingDisputes<'_> {
+    type O = IssuingDispute;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_authorization_decision_body() {
+        assert_eq!(IssuingAuthorizationDecision::decline().body(), r#"{"approved":false}"#);
+
+        let metadata = Metadata::from([("order".to_string(), "6735".to_string())]);
+        let decision = IssuingAuthorizationDecision::approve().with_amount(500).with_metadata(metadata);
+        assert_eq!(decision.body(), r#"{"approved":true,"amount":500,"metadata":{"order":"6735"}}"#);
+
+        let [_, (name, version)] = IssuingAuthorizationDecision::headers();
+        assert_eq!(name, "stripe-version");
+        assert_eq!(version, ApiVersion::default().as_str());
+    }
+}
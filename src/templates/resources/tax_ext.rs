@@ -0,0 +1,751 @@
+use serde::Serialize;
+
+use crate::stripe::client::{Client, Response};
+use crate::stripe::ids::{
+    CustomerId, ProductId, ShippingRateId, TaxCalculationId, TaxCalculationLineItemId, TaxCodeId, TaxRegistrationId,
+    TaxTransactionId, TaxTransactionLineItemId,
+};
+use crate::stripe::params::{Expand, List, Metadata, Object, Paginable, Timestamp};
+use crate::stripe::resources::{
+    Address, Currency, Scheduled, TaxCalculation, TaxCalculationLineItem, TaxCalculationLineItemTaxBehavior,
+    TaxProductRegistrationsResourceCountryOptions, TaxProductResourceCustomerDetailsAddressSource,
+    TaxProductResourceCustomerDetailsResourceTaxId, TaxProductResourceCustomerDetailsTaxabilityOverride,
+    TaxProductResourcePostalAddress, TaxProductResourceTaxCalculationShippingCostTaxBehavior,
+    TaxProductResourceTaxSettingsDefaultsTaxBehavior, TaxRegistration, TaxRegistrationStatus, TaxSettings,
+    TaxTransaction, TaxTransactionLineItem,
+};
+
+impl TaxCalculation {
+    /// Calculates tax based on the input and returns a Tax `Calculation` object.
+    pub fn create(client: &Client, params: CreateTaxCalculation<'_>) -> Response<TaxCalculation> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form("/tax/calculations", &params)
+    }
+
+    /// Retrieves a Tax `Calculation` object, if the calculation hasn’t expired.
+    pub fn retrieve(client: &Client, id: &TaxCalculationId, expand: &[&str]) -> Response<TaxCalculation> {
+        client.get_query(&format!("/tax/calculations/{}", id), &Expand { expand })
+    }
+
+    /// Retrieves the line items of a tax calculation as a collection, if the calculation hasn’t expired.
+    pub fn list_line_items(
+        client: &Client,
+        id: &TaxCalculationId,
+        params: &ListTaxCalculationLineItems<'_>,
+    ) -> Response<List<TaxCalculationLineItem>> {
+        client.get_query(&format!("/tax/calculations/{}/line_items", id), params)
+    }
+}
+
+impl TaxTransaction {
+    /// Creates a Tax Transaction from a calculation, if that calculation hasn’t expired.
+    ///
+    /// Calculations expire after 90 days.
+    pub fn create_from_calculation(
+        client: &Client,
+        params: CreateTaxTransactionFromCalculation<'_>,
+    ) -> Response<TaxTransaction> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form("/tax/transactions/create_from_calculation", &params)
+    }
+
+    /// Partially or fully reverses a previously created `Transaction`.
+    pub fn create_reversal(client: &Client, params: CreateTaxTransactionReversal<'_>) -> Response<TaxTransaction> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form("/tax/transactions/create_reversal", &params)
+    }
+
+    /// Retrieves a Tax `Transaction` object.
+    pub fn retrieve(client: &Client, id: &TaxTransactionId, expand: &[&str]) -> Response<TaxTransaction> {
+        client.get_query(&format!("/tax/transactions/{}", id), &Expand { expand })
+    }
+
+    /// Retrieves the line items of a committed standalone transaction as a collection.
+    pub fn list_line_items(
+        client: &Client,
+        id: &TaxTransactionId,
+        params: &ListTaxTransactionLineItems<'_>,
+    ) -> Response<List<TaxTransactionLineItem>> {
+        client.get_query(&format!("/tax/transactions/{}/line_items", id), params)
+    }
+}
+
+impl TaxRegistration {
+    /// Returns a list of Tax `Registration` objects.
+    pub fn list(client: &Client, params: &ListTaxRegistrations<'_>) -> Response<List<TaxRegistration>> {
+        client.get_query("/tax/registrations", params)
+    }
+
+    /// Creates a new Tax `Registration` object.
+    pub fn create(client: &Client, params: CreateTaxRegistration<'_>) -> Response<TaxRegistration> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form("/tax/registrations", &params)
+    }
+
+    /// Returns a Tax `Registration` object.
+    pub fn retrieve(client: &Client, id: &TaxRegistrationId, expand: &[&str]) -> Response<TaxRegistration> {
+        client.get_query(&format!("/tax/registrations/{}", id), &Expand { expand })
+    }
+
+    /// Updates an existing Tax `Registration` object.
+    ///
+    /// A registration cannot be deleted after it has been created.
+    /// If you wish to end a registration you may do so by setting `expires_at`.
+    pub fn update(
+        client: &Client,
+        id: &TaxRegistrationId,
+        params: UpdateTaxRegistration<'_>,
+    ) -> Response<TaxRegistration> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form(&format!("/tax/registrations/{}", id), &params)
+    }
+}
+
+impl TaxSettings {
+    /// Retrieves Tax `Settings` for a merchant.
+    pub fn retrieve(client: &Client, expand: &[&str]) -> Response<TaxSettings> {
+        client.get_query("/tax/settings", &Expand { expand })
+    }
+
+    /// Updates Tax `Settings` parameters used in tax calculations.
+    ///
+    /// All parameters are editable but none can be removed once set.
+    pub fn update(client: &Client, params: UpdateTaxSettings<'_>) -> Response<TaxSettings> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form("/tax/settings", &params)
+    }
+}
+
+/// The parameters for `TaxCalculation::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTaxCalculation<'a> {
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    ///
+    /// Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Currency,
+
+    /// The ID of an existing customer to use for this calculation.
+    ///
+    /// If provided, the customer's address and tax IDs are copied to `customer_details`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<CustomerId>,
+
+    /// Details about the customer, including address and tax IDs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_details: Option<CreateTaxCalculationCustomerDetails>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A list of items the customer is purchasing.
+    pub line_items: Vec<CreateTaxCalculationLineItem<'a>>,
+
+    /// Details about the address from which the goods are being shipped.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ship_from_details: Option<TaxShipFromDetails>,
+
+    /// Shipping cost details to be used for the calculation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_cost: Option<CreateTaxCalculationShippingCost>,
+
+    /// Timestamp of date at which the tax rules and rates in effect applies for the calculation.
+    ///
+    /// Measured in seconds since the Unix epoch.
+    /// Can be up to 48 hours in the past, and up to 48 hours in the future.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_date: Option<Timestamp>,
+}
+
+impl<'a> CreateTaxCalculation<'a> {
+    pub fn new(currency: Currency, line_items: Vec<CreateTaxCalculationLineItem<'a>>) -> Self {
+        CreateTaxCalculation {
+            currency,
+            customer: Default::default(),
+            customer_details: Default::default(),
+            expand: Default::default(),
+            line_items,
+            ship_from_details: Default::default(),
+            shipping_cost: Default::default(),
+            tax_date: Default::default(),
+        }
+    }
+}
+
+/// Details about the customer, including address and tax IDs.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreateTaxCalculationCustomerDetails {
+    /// The customer's postal address (for example, home or business location).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<TaxProductResourcePostalAddress>,
+
+    /// The type of customer address provided.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_source: Option<TaxProductResourceCustomerDetailsAddressSource>,
+
+    /// The customer's IP address (IPv4 or IPv6).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<String>,
+
+    /// The customer's tax IDs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_ids: Option<Vec<TaxProductResourceCustomerDetailsResourceTaxId>>,
+
+    /// Overrides the tax calculation result to allow you to not collect tax from your customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taxability_override: Option<TaxProductResourceCustomerDetailsTaxabilityOverride>,
+}
+
+/// An item the customer is purchasing.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreateTaxCalculationLineItem<'a> {
+    /// A positive integer representing the line item's total price in the smallest currency unit.
+    ///
+    /// If `tax_behavior=inclusive`, then this amount includes taxes.
+    /// Otherwise, taxes are calculated on top of this amount.
+    pub amount: i64,
+
+    /// If provided, the product's `tax_code` will be used as the line item's `tax_code`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<ProductId>,
+
+    /// The number of units of the item being purchased.
+    ///
+    /// Used to calculate the per-unit price from the total `amount` for the line.
+    /// For example, if `amount=100` and `quantity=4`, the calculated unit price is 25.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+
+    /// A custom identifier for this line item, which must be unique across the line items in the calculation.
+    ///
+    /// The reference helps identify each line item in exported [tax reports](https://stripe.com/docs/tax/reports).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<&'a str>,
+
+    /// Specifies whether the `amount` includes taxes.
+    ///
+    /// Defaults to `exclusive`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_behavior: Option<TaxCalculationLineItemTaxBehavior>,
+
+    /// A [tax code](https://stripe.com/docs/tax/tax-categories) ID to use for this line item.
+    ///
+    /// If not provided, we will use the tax code from the provided `product` param.
+    /// If neither `tax_code` nor `product` is provided, we will use the default tax code from your Tax Settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_code: Option<TaxCodeId>,
+}
+
+impl CreateTaxCalculationLineItem<'_> {
+    pub fn new(amount: i64) -> Self {
+        CreateTaxCalculationLineItem { amount, ..Default::default() }
+    }
+}
+
+/// Details about the address from which the goods are being shipped.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct TaxShipFromDetails {
+    /// The address from which the goods are being shipped from.
+    pub address: TaxProductResourcePostalAddress,
+}
+
+/// Shipping cost details to be used for the calculation.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreateTaxCalculationShippingCost {
+    /// A positive integer in the smallest currency unit representing the shipping charge.
+    ///
+    /// If `tax_behavior=inclusive`, then this amount includes taxes.
+    /// Otherwise, taxes are calculated on top of this amount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+
+    /// If provided, the [shipping rate](https://stripe.com/docs/api/shipping_rates/object)'s `amount`, `tax_code` and `tax_behavior` are used.
+    ///
+    /// If you provide a shipping rate, then you cannot pass the `amount`, `tax_code`, or `tax_behavior` parameters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_rate: Option<ShippingRateId>,
+
+    /// Specifies whether the `amount` includes taxes.
+    ///
+    /// If `tax_behavior=inclusive`, then the amount includes taxes.
+    /// Defaults to `exclusive`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_behavior: Option<TaxProductResourceTaxCalculationShippingCostTaxBehavior>,
+
+    /// The [tax code](https://stripe.com/docs/tax/tax-categories) used to calculate tax on shipping.
+    ///
+    /// If not provided, the default shipping tax code from your [Tax Settings](/settings/tax) is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_code: Option<TaxCodeId>,
+}
+
+/// The parameters for `TaxCalculation::list_line_items`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListTaxCalculationLineItems<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TaxCalculationLineItemId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TaxCalculationLineItemId>,
+}
+
+impl ListTaxCalculationLineItems<'_> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Paginable for ListTaxCalculationLineItems<'_> {
+    type O = TaxCalculationLineItem;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+/// The parameters for `TaxTransaction::create_from_calculation`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTaxTransactionFromCalculation<'a> {
+    /// Tax Calculation ID to be used as input when creating the transaction.
+    pub calculation: TaxCalculationId,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The Unix timestamp representing when the tax liability is assumed or reduced, which determines the liability posting period and handling in tax liability reports.
+    ///
+    /// The timestamp must fall within the `tax_date` and the current time, unless the `tax_date` is scheduled in advance.
+    /// Defaults to the current time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub posted_at: Option<Timestamp>,
+
+    /// A custom order or sale identifier, such as 'myOrder_123'.
+    ///
+    /// Must be unique across all transactions, including reversals.
+    pub reference: &'a str,
+}
+
+impl<'a> CreateTaxTransactionFromCalculation<'a> {
+    pub fn new(calculation: TaxCalculationId, reference: &'a str) -> Self {
+        CreateTaxTransactionFromCalculation {
+            calculation,
+            expand: Default::default(),
+            metadata: Default::default(),
+            posted_at: Default::default(),
+            reference,
+        }
+    }
+}
+
+/// The parameters for `TaxTransaction::create_reversal`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTaxTransactionReversal<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A flat amount to reverse across the entire transaction, in the smallest currency unit in negative.
+    ///
+    /// This value represents the total amount to refund from the transaction, including taxes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flat_amount: Option<i64>,
+
+    /// The line item amounts to reverse.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_items: Option<Vec<CreateTaxTransactionReversalLineItem<'a>>>,
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// If `partial`, the provided line item or shipping cost amounts are reversed.
+    ///
+    /// If `full`, the original transaction is fully reversed.
+    pub mode: TaxTransactionReversalMode,
+
+    /// The ID of the Transaction to partially or fully reverse.
+    pub original_transaction: TaxTransactionId,
+
+    /// A custom identifier for this reversal, such as `myOrder_123-refund_1`, which must be unique across all transactions.
+    ///
+    /// The reference helps identify this reversal transaction in exported [tax reports](https://stripe.com/docs/tax/reports).
+    pub reference: &'a str,
+
+    /// The shipping cost to reverse.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_cost: Option<CreateTaxTransactionReversalShippingCost>,
+}
+
+impl<'a> CreateTaxTransactionReversal<'a> {
+    /// Reverse `original_transaction` in full.
+    pub fn full(original_transaction: TaxTransactionId, reference: &'a str) -> Self {
+        Self::new(TaxTransactionReversalMode::Full, original_transaction, reference)
+    }
+
+    /// Reverse the given line item amounts of `original_transaction`.
+    pub fn partial(
+        original_transaction: TaxTransactionId,
+        reference: &'a str,
+        line_items: Vec<CreateTaxTransactionReversalLineItem<'a>>,
+    ) -> Self {
+        CreateTaxTransactionReversal {
+            line_items: Some(line_items),
+            ..Self::new(TaxTransactionReversalMode::Partial, original_transaction, reference)
+        }
+    }
+
+    pub fn new(mode: TaxTransactionReversalMode, original_transaction: TaxTransactionId, reference: &'a str) -> Self {
+        CreateTaxTransactionReversal {
+            expand: Default::default(),
+            flat_amount: Default::default(),
+            line_items: Default::default(),
+            metadata: Default::default(),
+            mode,
+            original_transaction,
+            reference,
+            shipping_cost: Default::default(),
+        }
+    }
+}
+
+/// A line item amount to reverse.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTaxTransactionReversalLineItem<'a> {
+    /// The amount to reverse, in the smallest currency unit in negative.
+    pub amount: i64,
+
+    /// The amount of tax to reverse, in the smallest currency unit in negative.
+    pub amount_tax: i64,
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The `id` of the line item to reverse in the original transaction.
+    pub original_line_item: TaxTransactionLineItemId,
+
+    /// The quantity reversed.
+    ///
+    /// Appears in [tax exports](https://stripe.com/docs/tax/reports), but does not affect the amount of tax reversed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+
+    /// A custom identifier for this line item in the reversal transaction, such as 'L1-refund'.
+    pub reference: &'a str,
+}
+
+/// The shipping cost to reverse.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CreateTaxTransactionReversalShippingCost {
+    /// The amount to reverse, in the smallest currency unit in negative.
+    pub amount: i64,
+
+    /// The amount of tax to reverse, in the smallest currency unit in negative.
+    pub amount_tax: i64,
+}
+
+/// An enum representing the possible values of a `CreateTaxTransactionReversal`'s `mode` field.
+#[derive(Copy, Clone, Debug, Default, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TaxTransactionReversalMode {
+    #[default]
+    Full,
+    Partial,
+}
+
+impl TaxTransactionReversalMode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TaxTransactionReversalMode::Full => "full",
+            TaxTransactionReversalMode::Partial => "partial",
+        }
+    }
+}
+
+impl AsRef<str> for TaxTransactionReversalMode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Display for TaxTransactionReversalMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// The parameters for `TaxTransaction::list_line_items`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListTaxTransactionLineItems<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TaxTransactionLineItemId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TaxTransactionLineItemId>,
+}
+
+impl ListTaxTransactionLineItems<'_> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Paginable for ListTaxTransactionLineItems<'_> {
+    type O = TaxTransactionLineItem;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+/// The parameters for `TaxRegistration::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListTaxRegistrations<'a> {
+    /// A cursor for use in pagination.
+    ///
+    /// `ending_before` is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TaxRegistrationId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    ///
+    /// `starting_after` is an object ID that defines your place in the list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TaxRegistrationId>,
+
+    /// The status of the Tax Registration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TaxRegistrationStatus>,
+}
+
+impl ListTaxRegistrations<'_> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Paginable for ListTaxRegistrations<'_> {
+    type O = TaxRegistration;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+/// The parameters for `TaxRegistration::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTaxRegistration<'a> {
+    /// Time at which the Tax Registration becomes active.
+    ///
+    /// It can be either `now` to indicate the current time, or a future timestamp measured in seconds since the Unix epoch.
+    pub active_from: Scheduled,
+
+    /// Two-letter country code ([ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2)).
+    pub country: &'a str,
+
+    /// Specific options for a registration in the specified `country`.
+    pub country_options: TaxProductRegistrationsResourceCountryOptions,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// If set, the Tax Registration stops being active at this time.
+    ///
+    /// If not set, the Tax Registration will be active indefinitely.
+    /// Timestamp measured in seconds since the Unix epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Scheduled>,
+}
+
+impl<'a> CreateTaxRegistration<'a> {
+    pub fn new(
+        country: &'a str,
+        country_options: TaxProductRegistrationsResourceCountryOptions,
+        active_from: Scheduled,
+    ) -> Self {
+        CreateTaxRegistration {
+            active_from,
+            country,
+            country_options,
+            expand: Default::default(),
+            expires_at: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `TaxRegistration::update`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateTaxRegistration<'a> {
+    /// Time at which the registration becomes active.
+    ///
+    /// It can be either `now` to indicate the current time, or a timestamp measured in seconds since the Unix epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_from: Option<Scheduled>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// If set, the registration stops being active at this time.
+    ///
+    /// It can be either `now` to indicate the current time, or a timestamp measured in seconds since the Unix epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Scheduled>,
+}
+
+impl UpdateTaxRegistration<'_> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// The parameters for `TaxSettings::update`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateTaxSettings<'a> {
+    /// Default configuration to be used on Stripe Tax calculations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub defaults: Option<UpdateTaxSettingsDefaults>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// The place where your business is located.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub head_office: Option<UpdateTaxSettingsHeadOffice>,
+}
+
+impl UpdateTaxSettings<'_> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Default configuration to be used on Stripe Tax calculations.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct UpdateTaxSettingsDefaults {
+    /// Specifies the default [tax behavior](https://stripe.com/docs/tax/tax-behavior) to be used when the item's price has unspecified tax behavior.
+    ///
+    /// One of inclusive, exclusive, or inferred_by_currency.
+    /// Once specified, it cannot be changed back to null.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_behavior: Option<TaxProductResourceTaxSettingsDefaultsTaxBehavior>,
+
+    /// A [tax code](https://stripe.com/docs/tax/tax-categories) ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_code: Option<TaxCodeId>,
+}
+
+/// The place where your business is located.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct UpdateTaxSettingsHeadOffice {
+    /// The location of the business for tax purposes.
+    pub address: Address,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stripe::client::testing::request;
+
+    #[test]
+    fn test_calculation_params() {
+        let line_item = CreateTaxCalculationLineItem { reference: Some("L1"), ..CreateTaxCalculationLineItem::new(1000) };
+        let params = CreateTaxCalculation::new(Currency::USD, vec![line_item]);
+        assert_eq!(
+            serde_qs::to_string(&params).unwrap(),
+            "currency=usd&line_items[0][amount]=1000&line_items[0][reference]=L1"
+        );
+    }
+
+    #[test]
+    fn test_reversal_params() {
+        let transaction: TaxTransactionId = "tax_123".parse().unwrap();
+        let params = CreateTaxTransactionReversal::full(transaction.clone(), "refund_1");
+        assert_eq!(serde_qs::to_string(&params).unwrap(), "mode=full&original_transaction=tax_123&reference=refund_1");
+
+        let line_item = CreateTaxTransactionReversalLineItem {
+            amount: -500,
+            amount_tax: -50,
+            metadata: None,
+            original_line_item: "tax_li_123".parse().unwrap(),
+            quantity: None,
+            reference: "L1-refund",
+        };
+        let params = CreateTaxTransactionReversal::partial(transaction, "refund_2", vec![line_item]);
+        assert_eq!(
+            serde_qs::to_string(&params).unwrap(),
+            "line_items[0][amount]=-500&line_items[0][amount_tax]=-50&line_items[0][original_line_item]=tax_li_123\
+             &line_items[0][reference]=L1-refund&mode=partial&original_transaction=tax_123&reference=refund_2"
+        );
+    }
+
+    #[test]
+    fn test_paths() {
+        let transaction: TaxTransactionId = "tax_123".parse().unwrap();
+        let (path, body) = request(|client| {
+            TaxTransaction::create_reversal(client, CreateTaxTransactionReversal::full(transaction, "refund_1"))
+        });
+        assert_eq!(path, "POST /v1/tax/transactions/create_reversal");
+        assert_eq!(body, "mode=full&original_transaction=tax_123&reference=refund_1");
+
+        let calculation: TaxCalculationId = "taxcalc_123".parse().unwrap();
+        let params = ListTaxCalculationLineItems::new();
+        let (path, _) = request(|client| TaxCalculation::list_line_items(client, &calculation, &params));
+        assert_eq!(path, "GET /v1/tax/calculations/taxcalc_123/line_items");
+
+        let mut params = UpdateTaxSettings::new();
+        params.defaults = Some(UpdateTaxSettingsDefaults {
+            tax_behavior: Some(TaxProductResourceTaxSettingsDefaultsTaxBehavior::InferredByCurrency),
+            tax_code: None,
+        });
+        let (path, body) = request(|client| TaxSettings::update(client, params));
+        assert_eq!(path, "POST /v1/tax/settings");
+        assert_eq!(body, "defaults[tax_behavior]=inferred_by_currency");
+    }
+}
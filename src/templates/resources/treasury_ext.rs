@@ -0,0 +1,1096 @@
+use serde::Serialize;
+
+use crate::stripe::client::{Client, Response};
+use crate::stripe::ids::{
+    CustomerId, PaymentMethodId, TreasuryFinancialAccountId, TreasuryInboundTransferId, TreasuryOutboundPaymentId,
+    TreasuryOutboundTransferId, TreasuryReceivedCreditId, TreasuryReceivedDebitId, TreasuryTransactionEntryId,
+    TreasuryTransactionId,
+};
+use crate::stripe::params::{Expand, List, Metadata, Object, Paginable, RangeQuery, Timestamp};
+use crate::stripe::resources::{
+    Currency, TreasuryFinancialAccount, TreasuryFinancialAccountFeatures, TreasuryInboundTransfer,
+    TreasuryInboundTransferStatus, TreasuryInboundTransfersResourceFailureDetailsCode, TreasuryOutboundPayment,
+    TreasuryOutboundPaymentStatus, TreasuryOutboundPaymentsResourceReturnedStatusCode, TreasuryOutboundTransfer,
+    TreasuryOutboundTransferStatus, TreasuryOutboundTransfersResourceReturnedDetailsCode, TreasuryReceivedCredit,
+    TreasuryReceivedCreditNetwork, TreasuryReceivedCreditStatus, TreasuryReceivedDebit, TreasuryReceivedDebitNetwork,
+    TreasuryReceivedDebitStatus, TreasuryTransaction, TreasuryTransactionEntry, TreasuryTransactionStatus,
+};
+
+impl TreasuryFinancialAccount {
+    /// Returns a list of FinancialAccounts.
+    pub fn list(
+        client: &Client,
+        params: &ListTreasuryFinancialAccounts<'_>,
+    ) -> Response<List<TreasuryFinancialAccount>> {
+        client.get_query("/treasury/financial_accounts", params)
+    }
+
+    /// Creates a new FinancialAccount.
+    ///
+    /// For now, each connected account can only have one FinancialAccount.
+    pub fn create(client: &Client, params: CreateTreasuryFinancialAccount<'_>) -> Response<TreasuryFinancialAccount> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form("/treasury/financial_accounts", &params)
+    }
+
+    /// Retrieves the details of a FinancialAccount.
+    pub fn retrieve(
+        client: &Client,
+        id: &TreasuryFinancialAccountId,
+        expand: &[&str],
+    ) -> Response<TreasuryFinancialAccount> {
+        client.get_query(&format!("/treasury/financial_accounts/{}", id), &Expand { expand })
+    }
+
+    /// Updates the details of a FinancialAccount.
+    pub fn update(
+        client: &Client,
+        id: &TreasuryFinancialAccountId,
+        params: UpdateTreasuryFinancialAccount<'_>,
+    ) -> Response<TreasuryFinancialAccount> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form(&format!("/treasury/financial_accounts/{}", id), &params)
+    }
+
+    /// Retrieves Features information associated with the FinancialAccount.
+    pub fn retrieve_features(
+        client: &Client,
+        id: &TreasuryFinancialAccountId,
+    ) -> Response<TreasuryFinancialAccountFeatures> {
+        client.get(&format!("/treasury/financial_accounts/{}/features", id))
+    }
+
+    /// Updates the Features associated with a FinancialAccount.
+    pub fn update_features(
+        client: &Client,
+        id: &TreasuryFinancialAccountId,
+        params: TreasuryFinancialAccountFeatureRequests,
+    ) -> Response<TreasuryFinancialAccountFeatures> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form(&format!("/treasury/financial_accounts/{}/features", id), &params)
+    }
+}
+
+impl TreasuryInboundTransfer {
+    /// Returns a list of InboundTransfers sent from the specified FinancialAccount.
+    pub fn list(client: &Client, params: &ListTreasuryInboundTransfers<'_>) -> Response<List<TreasuryInboundTransfer>> {
+        client.get_query("/treasury/inbound_transfers", params)
+    }
+
+    /// Creates an InboundTransfer.
+    pub fn create(client: &Client, params: CreateTreasuryInboundTransfer<'_>) -> Response<TreasuryInboundTransfer> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form("/treasury/inbound_transfers", &params)
+    }
+
+    /// Retrieves the details of an existing InboundTransfer.
+    pub fn retrieve(
+        client: &Client,
+        id: &TreasuryInboundTransferId,
+        expand: &[&str],
+    ) -> Response<TreasuryInboundTransfer> {
+        client.get_query(&format!("/treasury/inbound_transfers/{}", id), &Expand { expand })
+    }
+
+    /// Cancels an InboundTransfer.
+    pub fn cancel(client: &Client, id: &TreasuryInboundTransferId) -> Response<TreasuryInboundTransfer> {
+        client.post(&format!("/treasury/inbound_transfers/{}/cancel", id))
+    }
+
+    /// Transitions a test mode InboundTransfer to the `failed` status.
+    ///
+    /// The InboundTransfer must already be in the `processing` state.
+    pub fn test_fail(
+        client: &Client,
+        id: &TreasuryInboundTransferId,
+        code: Option<TreasuryInboundTransfersResourceFailureDetailsCode>,
+    ) -> Response<TreasuryInboundTransfer> {
+        let params = TestFailure { failure_details: code.map(|code| TestCode { code }) };
+        client.post_form(&format!("/test_helpers/treasury/inbound_transfers/{}/fail", id), &params)
+    }
+
+    /// Marks a test mode InboundTransfer as returned and links the InboundTransfer to a ReceivedDebit.
+    ///
+    /// The InboundTransfer must already be in the `succeeded` state.
+    pub fn test_return(client: &Client, id: &TreasuryInboundTransferId) -> Response<TreasuryInboundTransfer> {
+        client.post(&format!("/test_helpers/treasury/inbound_transfers/{}/return", id))
+    }
+
+    /// Transitions a test mode InboundTransfer to the `succeeded` status.
+    ///
+    /// The InboundTransfer must already be in the `processing` state.
+    pub fn test_succeed(client: &Client, id: &TreasuryInboundTransferId) -> Response<TreasuryInboundTransfer> {
+        client.post(&format!("/test_helpers/treasury/inbound_transfers/{}/succeed", id))
+    }
+}
+
+impl TreasuryOutboundTransfer {
+    /// Returns a list of OutboundTransfers sent from the specified FinancialAccount.
+    pub fn list(
+        client: &Client,
+        params: &ListTreasuryOutboundTransfers<'_>,
+    ) -> Response<List<TreasuryOutboundTransfer>> {
+        client.get_query("/treasury/outbound_transfers", params)
+    }
+
+    /// Creates an OutboundTransfer.
+    pub fn create(client: &Client, params: CreateTreasuryOutboundTransfer<'_>) -> Response<TreasuryOutboundTransfer> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form("/treasury/outbound_transfers", &params)
+    }
+
+    /// Retrieves the details of an existing OutboundTransfer.
+    pub fn retrieve(
+        client: &Client,
+        id: &TreasuryOutboundTransferId,
+        expand: &[&str],
+    ) -> Response<TreasuryOutboundTransfer> {
+        client.get_query(&format!("/treasury/outbound_transfers/{}", id), &Expand { expand })
+    }
+
+    /// An OutboundTransfer can be canceled if the funds have not yet been paid out.
+    pub fn cancel(client: &Client, id: &TreasuryOutboundTransferId) -> Response<TreasuryOutboundTransfer> {
+        client.post(&format!("/treasury/outbound_transfers/{}/cancel", id))
+    }
+
+    /// Transitions a test mode OutboundTransfer to the `failed` status.
+    ///
+    /// The OutboundTransfer must already be in the `processing` state.
+    pub fn test_fail(client: &Client, id: &TreasuryOutboundTransferId) -> Response<TreasuryOutboundTransfer> {
+        client.post(&format!("/test_helpers/treasury/outbound_transfers/{}/fail", id))
+    }
+
+    /// Transitions a test mode OutboundTransfer to the `posted` status.
+    ///
+    /// The OutboundTransfer must already be in the `processing` state.
+    pub fn test_post(client: &Client, id: &TreasuryOutboundTransferId) -> Response<TreasuryOutboundTransfer> {
+        client.post(&format!("/test_helpers/treasury/outbound_transfers/{}/post", id))
+    }
+
+    /// Transitions a test mode OutboundTransfer to the `returned` status.
+    ///
+    /// The OutboundTransfer must already be in the `processing` state.
+    pub fn test_return(
+        client: &Client,
+        id: &TreasuryOutboundTransferId,
+        code: Option<TreasuryOutboundTransfersResourceReturnedDetailsCode>,
+    ) -> Response<TreasuryOutboundTransfer> {
+        let params = TestReturn { returned_details: code.map(|code| TestCode { code }) };
+        client.post_form(&format!("/test_helpers/treasury/outbound_transfers/{}/return", id), &params)
+    }
+}
+
+impl TreasuryOutboundPayment {
+    /// Returns a list of OutboundPayments sent from the specified FinancialAccount.
+    pub fn list(client: &Client, params: &ListTreasuryOutboundPayments<'_>) -> Response<List<TreasuryOutboundPayment>> {
+        client.get_query("/treasury/outbound_payments", params)
+    }
+
+    /// Creates an OutboundPayment.
+    pub fn create(client: &Client, params: CreateTreasuryOutboundPayment<'_>) -> Response<TreasuryOutboundPayment> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form("/treasury/outbound_payments", &params)
+    }
+
+    /// Retrieves the details of an existing OutboundPayment.
+    pub fn retrieve(
+        client: &Client,
+        id: &TreasuryOutboundPaymentId,
+        expand: &[&str],
+    ) -> Response<TreasuryOutboundPayment> {
+        client.get_query(&format!("/treasury/outbound_payments/{}", id), &Expand { expand })
+    }
+
+    /// Cancel an OutboundPayment.
+    pub fn cancel(client: &Client, id: &TreasuryOutboundPaymentId) -> Response<TreasuryOutboundPayment> {
+        client.post(&format!("/treasury/outbound_payments/{}/cancel", id))
+    }
+
+    /// Transitions a test mode OutboundPayment to the `failed` status.
+    ///
+    /// The OutboundPayment must already be in the `processing` state.
+    pub fn test_fail(client: &Client, id: &TreasuryOutboundPaymentId) -> Response<TreasuryOutboundPayment> {
+        client.post(&format!("/test_helpers/treasury/outbound_payments/{}/fail", id))
+    }
+
+    /// Transitions a test mode OutboundPayment to the `posted` status.
+    ///
+    /// The OutboundPayment must already be in the `processing` state.
+    pub fn test_post(client: &Client, id: &TreasuryOutboundPaymentId) -> Response<TreasuryOutboundPayment> {
+        client.post(&format!("/test_helpers/treasury/outbound_payments/{}/post", id))
+    }
+
+    /// Transitions a test mode OutboundPayment to the `returned` status.
+    ///
+    /// The OutboundPayment must already be in the `processing` state.
+    pub fn test_return(
+        client: &Client,
+        id: &TreasuryOutboundPaymentId,
+        code: Option<TreasuryOutboundPaymentsResourceReturnedStatusCode>,
+    ) -> Response<TreasuryOutboundPayment> {
+        let params = TestReturn { returned_details: code.map(|code| TestCode { code }) };
+        client.post_form(&format!("/test_helpers/treasury/outbound_payments/{}/return", id), &params)
+    }
+}
+
+impl TreasuryReceivedCredit {
+    /// Returns a list of ReceivedCredits.
+    pub fn list(client: &Client, params: &ListTreasuryReceivedCredits<'_>) -> Response<List<TreasuryReceivedCredit>> {
+        client.get_query("/treasury/received_credits", params)
+    }
+
+    /// Retrieves the details of an existing ReceivedCredit by passing the unique ReceivedCredit ID from the ReceivedCredit list.
+    pub fn retrieve(
+        client: &Client,
+        id: &TreasuryReceivedCreditId,
+        expand: &[&str],
+    ) -> Response<TreasuryReceivedCredit> {
+        client.get_query(&format!("/treasury/received_credits/{}", id), &Expand { expand })
+    }
+
+    /// Use this endpoint to simulate a test mode ReceivedCredit initiated by a third party.
+    ///
+    /// In live mode, you can’t directly create ReceivedCredits initiated by third parties.
+    pub fn test_create(
+        client: &Client,
+        params: CreateTreasuryReceivedCredit<'_>,
+    ) -> Response<TreasuryReceivedCredit> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form("/test_helpers/treasury/received_credits", &params)
+    }
+}
+
+impl TreasuryReceivedDebit {
+    /// Returns a list of ReceivedDebits.
+    pub fn list(client: &Client, params: &ListTreasuryReceivedDebits<'_>) -> Response<List<TreasuryReceivedDebit>> {
+        client.get_query("/treasury/received_debits", params)
+    }
+
+    /// Retrieves the details of an existing ReceivedDebit by passing the unique ReceivedDebit ID from the ReceivedDebit list.
+    pub fn retrieve(client: &Client, id: &TreasuryReceivedDebitId, expand: &[&str]) -> Response<TreasuryReceivedDebit> {
+        client.get_query(&format!("/treasury/received_debits/{}", id), &Expand { expand })
+    }
+
+    /// Use this endpoint to simulate a test mode ReceivedDebit initiated by a third party.
+    ///
+    /// In live mode, you can’t directly create ReceivedDebits initiated by third parties.
+    pub fn test_create(client: &Client, params: CreateTreasuryReceivedDebit<'_>) -> Response<TreasuryReceivedDebit> {
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        client.post_form("/test_helpers/treasury/received_debits", &params)
+    }
+}
+
+impl TreasuryTransaction {
+    /// Retrieves a list of Transaction objects.
+    pub fn list(client: &Client, params: &ListTreasuryTransactions<'_>) -> Response<List<TreasuryTransaction>> {
+        client.get_query("/treasury/transactions", params)
+    }
+
+    /// Retrieves the details of an existing Transaction.
+    pub fn retrieve(client: &Client, id: &TreasuryTransactionId, expand: &[&str]) -> Response<TreasuryTransaction> {
+        client.get_query(&format!("/treasury/transactions/{}", id), &Expand { expand })
+    }
+}
+
+impl TreasuryTransactionEntry {
+    /// Retrieves a list of TransactionEntry objects.
+    pub fn list(
+        client: &Client,
+        params: &ListTreasuryTransactionEntries<'_>,
+    ) -> Response<List<TreasuryTransactionEntry>> {
+        client.get_query("/treasury/transaction_entries", params)
+    }
+
+    /// Retrieves a TransactionEntry object.
+    pub fn retrieve(
+        client: &Client,
+        id: &TreasuryTransactionEntryId,
+        expand: &[&str],
+    ) -> Response<TreasuryTransactionEntry> {
+        client.get_query(&format!("/treasury/transaction_entries/{}", id), &Expand { expand })
+    }
+}
+
+/// The `failure_details` of a test mode failure.
+#[derive(Serialize)]
+struct TestFailure<C> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    failure_details: Option<TestCode<C>>,
+}
+
+/// The `returned_details` of a test mode return.
+#[derive(Serialize)]
+struct TestReturn<C> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    returned_details: Option<TestCode<C>>,
+}
+
+#[derive(Serialize)]
+struct TestCode<C> {
+    code: C,
+}
+
+/// Request that a feature be turned on or off.
+#[derive(Clone, Copy, Debug, Serialize, Default)]
+pub struct TreasuryFeatureRequest {
+    /// Whether the FinancialAccount should have the Feature.
+    pub requested: bool,
+}
+
+impl TreasuryFeatureRequest {
+    pub fn new(requested: bool) -> Self {
+        Self { requested }
+    }
+}
+
+/// Feature requests for each network a money-movement Feature supports.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct TreasuryFeatureNetworkRequests {
+    /// Adds an ABA FinancialAddress to the FinancialAccount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aba: Option<TreasuryFeatureRequest>,
+
+    /// Enables ACH transfers via the FinancialAccount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ach: Option<TreasuryFeatureRequest>,
+
+    /// Enables US domestic wire transfers via the FinancialAccount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub us_domestic_wire: Option<TreasuryFeatureRequest>,
+}
+
+/// The Features to request for a FinancialAccount.
+///
+/// Used by `TreasuryFinancialAccount::update_features`, and as `features` when creating or updating a FinancialAccount.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct TreasuryFinancialAccountFeatureRequests {
+    /// Enables Issuing cards to be attached to the FinancialAccount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card_issuing: Option<TreasuryFeatureRequest>,
+
+    /// Represents whether this FinancialAccount is eligible for deposit insurance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deposit_insurance: Option<TreasuryFeatureRequest>,
+
+    /// Contains Features that add FinancialAddresses to the FinancialAccount.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub financial_addresses: Option<TreasuryFeatureNetworkRequests>,
+
+    /// Contains settings related to adding funds to a FinancialAccount from another Account with the same owner.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inbound_transfers: Option<TreasuryFeatureNetworkRequests>,
+
+    /// Represents the ability for the FinancialAccount to send money to, or receive money from other FinancialAccounts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub intra_stripe_flows: Option<TreasuryFeatureRequest>,
+
+    /// Includes Features related to initiating money movement out of the FinancialAccount to someone else's bucket of money.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outbound_payments: Option<TreasuryFeatureNetworkRequests>,
+
+    /// Contains a Feature and settings related to moving money out of the FinancialAccount into another Account with the same owner.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outbound_transfers: Option<TreasuryFeatureNetworkRequests>,
+}
+
+/// The parameters for `TreasuryFinancialAccount::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListTreasuryFinancialAccounts<'a> {
+    /// Only return FinancialAccounts that were created during the given date interval.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+
+    /// An object ID cursor for use in pagination.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TreasuryFinancialAccountId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// A limit ranging from 1 to 100 (defaults to 10).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// An object ID cursor for use in pagination.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TreasuryFinancialAccountId>,
+}
+
+impl ListTreasuryFinancialAccounts<'_> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Paginable for ListTreasuryFinancialAccounts<'_> {
+    type O = TreasuryFinancialAccount;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+/// The parameters for `TreasuryFinancialAccount::create`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct CreateTreasuryFinancialAccount<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Encodes whether a FinancialAccount has access to a particular feature.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub features: Option<TreasuryFinancialAccountFeatureRequests>,
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The currencies the FinancialAccount can hold a balance in.
+    pub supported_currencies: Vec<Currency>,
+}
+
+impl CreateTreasuryFinancialAccount<'_> {
+    pub fn new(supported_currencies: Vec<Currency>) -> Self {
+        Self { supported_currencies, ..Default::default() }
+    }
+}
+
+/// The parameters for `TreasuryFinancialAccount::update`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct UpdateTreasuryFinancialAccount<'a> {
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Encodes whether a FinancialAccount has access to a particular feature.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub features: Option<TreasuryFinancialAccountFeatureRequests>,
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+impl UpdateTreasuryFinancialAccount<'_> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// The parameters for `TreasuryInboundTransfer::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListTreasuryInboundTransfers<'a> {
+    /// A cursor for use in pagination.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TreasuryInboundTransferId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Returns objects associated with this FinancialAccount.
+    pub financial_account: TreasuryFinancialAccountId,
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TreasuryInboundTransferId>,
+
+    /// Only return InboundTransfers that have the given status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TreasuryInboundTransferStatus>,
+}
+
+impl ListTreasuryInboundTransfers<'_> {
+    pub fn new(financial_account: TreasuryFinancialAccountId) -> Self {
+        Self { financial_account, ..Default::default() }
+    }
+}
+
+impl Paginable for ListTreasuryInboundTransfers<'_> {
+    type O = TreasuryInboundTransfer;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+/// The parameters for `TreasuryInboundTransfer::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTreasuryInboundTransfer<'a> {
+    /// Amount (in cents) to be transferred.
+    pub amount: i64,
+
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    pub currency: Currency,
+
+    /// An arbitrary string attached to the object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// The FinancialAccount to send funds to.
+    pub financial_account: TreasuryFinancialAccountId,
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The origin payment method to be debited for the InboundTransfer.
+    pub origin_payment_method: PaymentMethodId,
+
+    /// The complete description that appears on your customers' statements.
+    ///
+    /// Maximum 10 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+}
+
+impl CreateTreasuryInboundTransfer<'_> {
+    pub fn new(
+        financial_account: TreasuryFinancialAccountId,
+        origin_payment_method: PaymentMethodId,
+        amount: i64,
+        currency: Currency,
+    ) -> Self {
+        Self {
+            amount,
+            currency,
+            description: Default::default(),
+            expand: Default::default(),
+            financial_account,
+            metadata: Default::default(),
+            origin_payment_method,
+            statement_descriptor: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `TreasuryOutboundTransfer::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListTreasuryOutboundTransfers<'a> {
+    /// A cursor for use in pagination.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TreasuryOutboundTransferId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Returns objects associated with this FinancialAccount.
+    pub financial_account: TreasuryFinancialAccountId,
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TreasuryOutboundTransferId>,
+
+    /// Only return OutboundTransfers that have the given status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TreasuryOutboundTransferStatus>,
+}
+
+impl ListTreasuryOutboundTransfers<'_> {
+    pub fn new(financial_account: TreasuryFinancialAccountId) -> Self {
+        Self { financial_account, ..Default::default() }
+    }
+}
+
+impl Paginable for ListTreasuryOutboundTransfers<'_> {
+    type O = TreasuryOutboundTransfer;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+/// The parameters for `TreasuryOutboundTransfer::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTreasuryOutboundTransfer<'a> {
+    /// Amount (in cents) to be transferred.
+    pub amount: i64,
+
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    pub currency: Currency,
+
+    /// An arbitrary string attached to the object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+
+    /// The PaymentMethod to use as the payment instrument for the OutboundTransfer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_payment_method: Option<PaymentMethodId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// The FinancialAccount to pull funds from.
+    pub financial_account: TreasuryFinancialAccountId,
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// Statement descriptor to be shown on the receiving end of an OutboundTransfer.
+    ///
+    /// Maximum 10 characters for `ach` transfers or 140 characters for `us_domestic_wire` transfers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+}
+
+impl CreateTreasuryOutboundTransfer<'_> {
+    pub fn new(financial_account: TreasuryFinancialAccountId, amount: i64, currency: Currency) -> Self {
+        Self {
+            amount,
+            currency,
+            description: Default::default(),
+            destination_payment_method: Default::default(),
+            expand: Default::default(),
+            financial_account,
+            metadata: Default::default(),
+            statement_descriptor: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `TreasuryOutboundPayment::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListTreasuryOutboundPayments<'a> {
+    /// Only return OutboundPayments that were created during the given date interval.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+
+    /// Only return OutboundPayments sent to this customer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<CustomerId>,
+
+    /// A cursor for use in pagination.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TreasuryOutboundPaymentId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Returns objects associated with this FinancialAccount.
+    pub financial_account: TreasuryFinancialAccountId,
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TreasuryOutboundPaymentId>,
+
+    /// Only return OutboundPayments that have the given status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TreasuryOutboundPaymentStatus>,
+}
+
+impl ListTreasuryOutboundPayments<'_> {
+    pub fn new(financial_account: TreasuryFinancialAccountId) -> Self {
+        Self { financial_account, ..Default::default() }
+    }
+}
+
+impl Paginable for ListTreasuryOutboundPayments<'_> {
+    type O = TreasuryOutboundPayment;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+/// The parameters for `TreasuryOutboundPayment::create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTreasuryOutboundPayment<'a> {
+    /// Amount (in cents) to be transferred.
+    pub amount: i64,
+
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    pub currency: Currency,
+
+    /// ID of the customer to whom the OutboundPayment is sent.
+    ///
+    /// Must match the Customer attached to the `destination_payment_method` passed in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<CustomerId>,
+
+    /// An arbitrary string attached to the object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+
+    /// The PaymentMethod to use as the payment instrument for the OutboundPayment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_payment_method: Option<PaymentMethodId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// The FinancialAccount to pull funds from.
+    pub financial_account: TreasuryFinancialAccountId,
+
+    /// Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+
+    /// The description that appears on the receiving end for this OutboundPayment.
+    ///
+    /// Maximum 10 characters for `ach` payments or 140 characters for `us_domestic_wire` payments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+}
+
+impl CreateTreasuryOutboundPayment<'_> {
+    pub fn new(financial_account: TreasuryFinancialAccountId, amount: i64, currency: Currency) -> Self {
+        Self {
+            amount,
+            currency,
+            customer: Default::default(),
+            description: Default::default(),
+            destination_payment_method: Default::default(),
+            expand: Default::default(),
+            financial_account,
+            metadata: Default::default(),
+            statement_descriptor: Default::default(),
+        }
+    }
+}
+
+/// The parameters for `TreasuryReceivedCredit::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListTreasuryReceivedCredits<'a> {
+    /// A cursor for use in pagination.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TreasuryReceivedCreditId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// The FinancialAccount that received the funds.
+    pub financial_account: TreasuryFinancialAccountId,
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TreasuryReceivedCreditId>,
+
+    /// Only return ReceivedCredits that have the given status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TreasuryReceivedCreditStatus>,
+}
+
+impl ListTreasuryReceivedCredits<'_> {
+    pub fn new(financial_account: TreasuryFinancialAccountId) -> Self {
+        Self { financial_account, ..Default::default() }
+    }
+}
+
+impl Paginable for ListTreasuryReceivedCredits<'_> {
+    type O = TreasuryReceivedCredit;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+/// The parameters for `TreasuryReceivedCredit::test_create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTreasuryReceivedCredit<'a> {
+    /// Amount (in cents) to be transferred.
+    pub amount: i64,
+
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    pub currency: Currency,
+
+    /// An arbitrary string attached to the object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// The FinancialAccount to send funds to.
+    pub financial_account: TreasuryFinancialAccountId,
+
+    /// The rails used for the object, `ach` or `us_domestic_wire`.
+    pub network: TreasuryReceivedCreditNetwork,
+}
+
+impl CreateTreasuryReceivedCredit<'_> {
+    pub fn new(
+        financial_account: TreasuryFinancialAccountId,
+        network: TreasuryReceivedCreditNetwork,
+        amount: i64,
+        currency: Currency,
+    ) -> Self {
+        Self {
+            amount,
+            currency,
+            description: Default::default(),
+            expand: Default::default(),
+            financial_account,
+            network,
+        }
+    }
+}
+
+/// The parameters for `TreasuryReceivedDebit::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListTreasuryReceivedDebits<'a> {
+    /// A cursor for use in pagination.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TreasuryReceivedDebitId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// The FinancialAccount that funds were pulled from.
+    pub financial_account: TreasuryFinancialAccountId,
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TreasuryReceivedDebitId>,
+
+    /// Only return ReceivedDebits that have the given status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TreasuryReceivedDebitStatus>,
+}
+
+impl ListTreasuryReceivedDebits<'_> {
+    pub fn new(financial_account: TreasuryFinancialAccountId) -> Self {
+        Self { financial_account, ..Default::default() }
+    }
+}
+
+impl Paginable for ListTreasuryReceivedDebits<'_> {
+    type O = TreasuryReceivedDebit;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+/// The parameters for `TreasuryReceivedDebit::test_create`.
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateTreasuryReceivedDebit<'a> {
+    /// Amount (in cents) to be transferred.
+    pub amount: i64,
+
+    /// Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase.
+    pub currency: Currency,
+
+    /// An arbitrary string attached to the object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// The FinancialAccount to pull funds from.
+    pub financial_account: TreasuryFinancialAccountId,
+
+    /// The rails used for the object, `ach` is the only one supported.
+    pub network: TreasuryReceivedDebitNetwork,
+}
+
+impl CreateTreasuryReceivedDebit<'_> {
+    pub fn new(
+        financial_account: TreasuryFinancialAccountId,
+        network: TreasuryReceivedDebitNetwork,
+        amount: i64,
+        currency: Currency,
+    ) -> Self {
+        Self {
+            amount,
+            currency,
+            description: Default::default(),
+            expand: Default::default(),
+            financial_account,
+            network,
+        }
+    }
+}
+
+/// The parameters for `TreasuryTransaction::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListTreasuryTransactions<'a> {
+    /// Only return Transactions that were created during the given date interval.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+
+    /// A cursor for use in pagination.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TreasuryTransactionId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Returns objects associated with this FinancialAccount.
+    pub financial_account: TreasuryFinancialAccountId,
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TreasuryTransactionId>,
+
+    /// Only return Transactions that have the given status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TreasuryTransactionStatus>,
+}
+
+impl ListTreasuryTransactions<'_> {
+    pub fn new(financial_account: TreasuryFinancialAccountId) -> Self {
+        Self { financial_account, ..Default::default() }
+    }
+}
+
+impl Paginable for ListTreasuryTransactions<'_> {
+    type O = TreasuryTransaction;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+/// The parameters for `TreasuryTransactionEntry::list`.
+#[derive(Clone, Debug, Serialize, Default)]
+pub struct ListTreasuryTransactionEntries<'a> {
+    /// Only return TransactionEntries that were created during the given date interval.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+
+    /// Only return TransactionEntries that took effect during the given date interval.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effective_at: Option<RangeQuery<Timestamp>>,
+
+    /// A cursor for use in pagination.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<TreasuryTransactionEntryId>,
+
+    /// Specifies which fields in the response should be expanded.
+    #[serde(skip_serializing_if = "Expand::is_empty")]
+    pub expand: &'a [&'a str],
+
+    /// Returns objects associated with this FinancialAccount.
+    pub financial_account: TreasuryFinancialAccountId,
+
+    /// A limit on the number of objects to be returned.
+    ///
+    /// Limit can range between 1 and 100, and the default is 10.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+
+    /// A cursor for use in pagination.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<TreasuryTransactionEntryId>,
+
+    /// Only return TransactionEntries associated with this Transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction: Option<TreasuryTransactionId>,
+}
+
+impl ListTreasuryTransactionEntries<'_> {
+    pub fn new(financial_account: TreasuryFinancialAccountId) -> Self {
+        Self { financial_account, ..Default::default() }
+    }
+}
+
+impl Paginable for ListTreasuryTransactionEntries<'_> {
+    type O = TreasuryTransactionEntry;
+    fn set_last(&mut self, item: Self::O) {
+        self.starting_after = Some(item.id());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stripe::client::testing::request;
+
+    #[test]
+    fn test_feature_request_params() {
+        let params = TreasuryFinancialAccountFeatureRequests {
+            card_issuing: Some(TreasuryFeatureRequest::new(true)),
+            outbound_payments: Some(TreasuryFeatureNetworkRequests {
+                ach: Some(TreasuryFeatureRequest::new(false)),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_qs::to_string(&params).unwrap(),
+            "card_issuing[requested]=true&outbound_payments[ach][requested]=false"
+        );
+    }
+
+    #[test]
+    fn test_create_outbound_transfer_params() {
+        let financial_account: TreasuryFinancialAccountId = "fa_123".parse().unwrap();
+        let mut params = CreateTreasuryOutboundTransfer::new(financial_account, 500, Currency::USD);
+        params.statement_descriptor = Some("payout");
+        assert_eq!(
+            serde_qs::to_string(&params).unwrap(),
+            "amount=500&currency=usd&financial_account=fa_123&statement_descriptor=payout"
+        );
+    }
+
+    #[test]
+    fn test_paths() {
+        let financial_account: TreasuryFinancialAccountId = "fa_123".parse().unwrap();
+        let (path, _) = request(|client| TreasuryFinancialAccount::retrieve_features(client, &financial_account));
+        assert_eq!(path, "GET /v1/treasury/financial_accounts/fa_123/features");
+
+        let features = TreasuryFinancialAccountFeatureRequests {
+            card_issuing: Some(TreasuryFeatureRequest::new(true)),
+            ..Default::default()
+        };
+        let (path, body) =
+            request(|client| TreasuryFinancialAccount::update_features(client, &financial_account, features));
+        assert_eq!(path, "POST /v1/treasury/financial_accounts/fa_123/features");
+        assert_eq!(body, "card_issuing[requested]=true");
+
+        let transfer: TreasuryInboundTransferId = "ibt_123".parse().unwrap();
+        let code = Some(TreasuryInboundTransfersResourceFailureDetailsCode::AccountClosed);
+        let (path, body) = request(|client| TreasuryInboundTransfer::test_fail(client, &transfer, code));
+        assert_eq!(path, "POST /v1/test_helpers/treasury/inbound_transfers/ibt_123/fail");
+        assert_eq!(body, "failure_details[code]=account_closed");
+
+        let payment: TreasuryOutboundPaymentId = "obp_123".parse().unwrap();
+        let (path, _) = request(|client| TreasuryOutboundPayment::cancel(client, &payment));
+        assert_eq!(path, "POST /v1/treasury/outbound_payments/obp_123/cancel");
+    }
+}
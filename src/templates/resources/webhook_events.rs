@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+#[cfg(feature = "webhook-events")]
 use chrono::Utc;
 #[cfg(feature = "webhook-events")]
 use hmac::{Hmac, Mac};
@@ -34,10 +35,26 @@ pub enum EventType {
     ApplicationFeeRefunded,
     #[serde(rename = "balance.available")]
     BalanceAvailable,
+    #[serde(rename = "billing.credit_balance_transaction.created")]
+    BillingCreditBalanceTransactionCreated,
+    #[serde(rename = "billing.credit_grant.created")]
+    BillingCreditGrantCreated,
+    #[serde(rename = "billing.credit_grant.updated")]
+    BillingCreditGrantUpdated,
+    #[serde(rename = "billing.meter.created")]
+    BillingMeterCreated,
+    #[serde(rename = "billing.meter.deactivated")]
+    BillingMeterDeactivated,
+    #[serde(rename = "billing.meter.reactivated")]
+    BillingMeterReactivated,
+    #[serde(rename = "billing.meter.updated")]
+    BillingMeterUpdated,
     #[serde(rename = "billing_portal.configuration.created")]
     BillingPortalConfigurationCreated,
     #[serde(rename = "billing_portal.configuration.updated")]
     BillingPortalConfigurationUpdated,
+    #[serde(rename = "billing_portal.session.created")]
+    BillingPortalSessionCreated,
     #[serde(rename = "capability.updated")]
     CapabilityUpdated,
     #[serde(rename = "cash_balance.funds_available")]
@@ -76,6 +93,20 @@ pub enum EventType {
     CheckoutSessionCompleted,
     #[serde(rename = "checkout.session.expired")]
     CheckoutSessionExpired,
+    #[serde(rename = "climate.order.canceled")]
+    ClimateOrderCanceled,
+    #[serde(rename = "climate.order.created")]
+    ClimateOrderCreated,
+    #[serde(rename = "climate.order.delayed")]
+    ClimateOrderDelayed,
+    #[serde(rename = "climate.order.delivered")]
+    ClimateOrderDelivered,
+    #[serde(rename = "climate.order.product_substituted")]
+    ClimateOrderProductSubstituted,
+    #[serde(rename = "climate.product.created")]
+    ClimateProductCreated,
+    #[serde(rename = "climate.product.pricing_updated")]
+    ClimateProductPricingUpdated,
     #[serde(rename = "coupon.created")]
     CouponCreated,
     #[serde(rename = "coupon.deleted")]
@@ -130,8 +161,26 @@ pub enum EventType {
     CustomerTaxIdUpdated,
     #[serde(rename = "customer.updated")]
     CustomerUpdated,
+    #[serde(rename = "customer_cash_balance_transaction.created")]
+    CustomerCashBalanceTransactionCreated,
+    #[serde(rename = "entitlements.active_entitlement_summary.updated")]
+    EntitlementsActiveEntitlementSummaryUpdated,
     #[serde(rename = "file.created")]
     FileCreated,
+    #[serde(rename = "financial_connections.account.created")]
+    FinancialConnectionsAccountCreated,
+    #[serde(rename = "financial_connections.account.deactivated")]
+    FinancialConnectionsAccountDeactivated,
+    #[serde(rename = "financial_connections.account.disconnected")]
+    FinancialConnectionsAccountDisconnected,
+    #[serde(rename = "financial_connections.account.reactivated")]
+    FinancialConnectionsAccountReactivated,
+    #[serde(rename = "financial_connections.account.refreshed_balance")]
+    FinancialConnectionsAccountRefreshedBalance,
+    #[serde(rename = "financial_connections.account.refreshed_ownership")]
+    FinancialConnectionsAccountRefreshedOwnership,
+    #[serde(rename = "financial_connections.account.refreshed_transactions")]
+    FinancialConnectionsAccountRefreshedTransactions,
     #[serde(rename = "identity.verification_session.canceled")]
     IdentityVerificationSessionCanceled,
     #[serde(rename = "identity.verification_session.created")]
@@ -154,6 +203,10 @@ pub enum EventType {
     InvoiceFinalized,
     #[serde(rename = "invoice.marked_uncollectible")]
     InvoiceMarkedUncollectible,
+    #[serde(rename = "invoice.overdue")]
+    InvoiceOverdue,
+    #[serde(rename = "invoice.overpaid")]
+    InvoiceOverpaid,
     #[serde(rename = "invoice.paid")]
     InvoicePaid,
     #[serde(rename = "invoice.payment_action_required")]
@@ -170,6 +223,8 @@ pub enum EventType {
     InvoiceUpdated,
     #[serde(rename = "invoice.voided")]
     InvoiceVoided,
+    #[serde(rename = "invoice.will_be_due")]
+    InvoiceWillBeDue,
     #[serde(rename = "invoiceitem.created")]
     InvoiceItemCreated,
     #[serde(rename = "invoiceitem.deleted")]
@@ -196,12 +251,28 @@ pub enum EventType {
     IssuingDisputeCreated,
     #[serde(rename = "issuing_dispute.funds_reinstated")]
     IssuingDisputeFundsReinstated,
+    #[serde(rename = "issuing_dispute.funds_rescinded")]
+    IssuingDisputeFundsRescinded,
     #[serde(rename = "issuing_dispute.submitted")]
     IssuingDisputeSubmitted,
     #[serde(rename = "issuing_dispute.updated")]
     IssuingDisputeUpdated,
+    #[serde(rename = "issuing_personalization_design.activated")]
+    IssuingPersonalizationDesignActivated,
+    #[serde(rename = "issuing_personalization_design.deactivated")]
+    IssuingPersonalizationDesignDeactivated,
+    #[serde(rename = "issuing_personalization_design.rejected")]
+    IssuingPersonalizationDesignRejected,
+    #[serde(rename = "issuing_personalization_design.updated")]
+    IssuingPersonalizationDesignUpdated,
+    #[serde(rename = "issuing_token.created")]
+    IssuingTokenCreated,
+    #[serde(rename = "issuing_token.updated")]
+    IssuingTokenUpdated,
     #[serde(rename = "issuing_transaction.created")]
     IssuingTransactionCreated,
+    #[serde(rename = "issuing_transaction.purchase_details_receipt_updated")]
+    IssuingTransactionPurchaseDetailsReceiptUpdated,
     #[serde(rename = "issuing_transaction.updated")]
     IssuingTransactionUpdated,
     #[serde(rename = "mandate.updated")]
@@ -256,6 +327,8 @@ pub enum EventType {
     PayoutFailed,
     #[serde(rename = "payout.paid")]
     PayoutPaid,
+    #[serde(rename = "payout.reconciliation_completed")]
+    PayoutReconciliationCompleted,
     #[serde(rename = "payout.updated")]
     PayoutUpdated,
     #[serde(rename = "person.created")]
@@ -304,6 +377,12 @@ pub enum EventType {
     RecipientDeleted,
     #[serde(rename = "recipient.updated")]
     RecipientUpdated,
+    #[serde(rename = "refund.created")]
+    RefundCreated,
+    #[serde(rename = "refund.failed")]
+    RefundFailed,
+    #[serde(rename = "refund.updated")]
+    RefundUpdated,
     #[serde(rename = "reporting.report_run.failed")]
     ReportingReportRunFailed,
     #[serde(rename = "reporting.report_run.succeeded")]
@@ -360,6 +439,8 @@ pub enum EventType {
     SubscriptionScheduleReleased,
     #[serde(rename = "subscription_schedule.updated")]
     SubscriptionScheduleUpdated,
+    #[serde(rename = "tax.settings.updated")]
+    TaxSettingsUpdated,
     #[serde(rename = "tax_rate.created")]
     TaxRateCreated,
     #[serde(rename = "tax_rate.updated")]
@@ -398,6 +479,66 @@ pub enum EventType {
     TransferReversed,
     #[serde(rename = "transfer.updated")]
     TransferUpdated,
+    #[serde(rename = "treasury.credit_reversal.created")]
+    TreasuryCreditReversalCreated,
+    #[serde(rename = "treasury.credit_reversal.posted")]
+    TreasuryCreditReversalPosted,
+    #[serde(rename = "treasury.debit_reversal.completed")]
+    TreasuryDebitReversalCompleted,
+    #[serde(rename = "treasury.debit_reversal.created")]
+    TreasuryDebitReversalCreated,
+    #[serde(rename = "treasury.debit_reversal.initial_credit_granted")]
+    TreasuryDebitReversalInitialCreditGranted,
+    #[serde(rename = "treasury.financial_account.closed")]
+    TreasuryFinancialAccountClosed,
+    #[serde(rename = "treasury.financial_account.created")]
+    TreasuryFinancialAccountCreated,
+    #[serde(rename = "treasury.financial_account.features_status_updated")]
+    TreasuryFinancialAccountFeaturesStatusUpdated,
+    #[serde(rename = "treasury.inbound_transfer.canceled")]
+    TreasuryInboundTransferCanceled,
+    #[serde(rename = "treasury.inbound_transfer.created")]
+    TreasuryInboundTransferCreated,
+    #[serde(rename = "treasury.inbound_transfer.failed")]
+    TreasuryInboundTransferFailed,
+    #[serde(rename = "treasury.inbound_transfer.succeeded")]
+    TreasuryInboundTransferSucceeded,
+    #[serde(rename = "treasury.outbound_payment.canceled")]
+    TreasuryOutboundPaymentCanceled,
+    #[serde(rename = "treasury.outbound_payment.created")]
+    TreasuryOutboundPaymentCreated,
+    #[serde(rename = "treasury.outbound_payment.expected_arrival_date_updated")]
+    TreasuryOutboundPaymentExpectedArrivalDateUpdated,
+    #[serde(rename = "treasury.outbound_payment.failed")]
+    TreasuryOutboundPaymentFailed,
+    #[serde(rename = "treasury.outbound_payment.posted")]
+    TreasuryOutboundPaymentPosted,
+    #[serde(rename = "treasury.outbound_payment.returned")]
+    TreasuryOutboundPaymentReturned,
+    #[serde(rename = "treasury.outbound_payment.tracking_details_updated")]
+    TreasuryOutboundPaymentTrackingDetailsUpdated,
+    #[serde(rename = "treasury.outbound_transfer.canceled")]
+    TreasuryOutboundTransferCanceled,
+    #[serde(rename = "treasury.outbound_transfer.created")]
+    TreasuryOutboundTransferCreated,
+    #[serde(rename = "treasury.outbound_transfer.expected_arrival_date_updated")]
+    TreasuryOutboundTransferExpectedArrivalDateUpdated,
+    #[serde(rename = "treasury.outbound_transfer.failed")]
+    TreasuryOutboundTransferFailed,
+    #[serde(rename = "treasury.outbound_transfer.posted")]
+    TreasuryOutboundTransferPosted,
+    #[serde(rename = "treasury.outbound_transfer.returned")]
+    TreasuryOutboundTransferReturned,
+    #[serde(rename = "treasury.outbound_transfer.tracking_details_updated")]
+    TreasuryOutboundTransferTrackingDetailsUpdated,
+    #[serde(rename = "treasury.received_credit.created")]
+    TreasuryReceivedCreditCreated,
+    #[serde(rename = "treasury.received_credit.failed")]
+    TreasuryReceivedCreditFailed,
+    #[serde(rename = "treasury.received_credit.succeeded")]
+    TreasuryReceivedCreditSucceeded,
+    #[serde(rename = "treasury.received_debit.created")]
+    TreasuryReceivedDebitCreated,
     #[serde(other)]
     #[default]
     Unknown,
@@ -478,6 +619,9 @@ pub enum EventObject {
     TestHelpersTestClock(TestHelpersTestClock),
     Topup(Topup),
     Transfer(Transfer),
+    /// An object without a variant above, kept as JSON
+    #[serde(untagged)]
+    Unknown(Value),
 }
 
 impl Default for EventObject {
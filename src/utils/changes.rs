@@ -0,0 +1,339 @@
+//! Planned file-system changes.
+//!
+//! Commands record every directory, write and removal in a [`ChangeSet`]
+//! instead of touching the disk, so the same plan can be applied, listed with
+//! `--dry-run` or shown as unified diffs with `--diff`.
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::utils::fs::Overwrite;
+use crate::utils::output::{self, Dependency, Event, SkipReason};
+
+/// What a command does with its planned changes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Write everything to disk
+    Apply,
+    /// Only list the planned changes
+    DryRun,
+    /// List the planned changes with unified diffs against the files on disk
+    Diff,
+}
+
+impl Mode {
+    /// Pick the mode from the `--dry-run` / `--diff` flags
+    pub fn from_flags(dry_run: bool, diff: bool) -> Self {
+        if diff {
+            Mode::Diff
+        } else if dry_run {
+            Mode::DryRun
+        } else {
+            Mode::Apply
+        }
+    }
+}
+
+/// A single planned change
+#[derive(Debug)]
+enum Change {
+    CreateDir,
+    Write {
+        content: String,
+        /// Hidden writes (such as lockfile snapshots) are applied but not listed
+        hidden: bool,
+        /// Why the file is written, shown next to it
+        reason: Option<String>,
+    },
+    Remove {
+        hidden: bool,
+    },
+}
+
+/// An ordered set of planned changes, keyed by path
+pub struct ChangeSet {
+    mode: Mode,
+    /// Paths are shown relative to this directory
+    root: PathBuf,
+    order: Vec<PathBuf>,
+    changes: BTreeMap<PathBuf, Change>,
+    /// What to do with files that already exist
+    overwrite: Overwrite,
+    /// Dependencies reported once the changes are applied
+    dependencies: Vec<Dependency>,
+}
+
+impl ChangeSet {
+    pub fn new(mode: Mode, root: &Path) -> Self {
+        Self {
+            mode,
+            root: root.to_path_buf(),
+            order: Vec::new(),
+            changes: BTreeMap::new(),
+            overwrite: Overwrite::from_flags(false, false, false),
+            dependencies: Vec::new(),
+        }
+    }
+
+    /// Use `overwrite` for files that already exist
+    pub fn with_overwrite(mut self, overwrite: Overwrite) -> Self {
+        self.overwrite = overwrite;
+        self
+    }
+
+    /// Whether the changes are only previewed
+    pub fn is_preview(&self) -> bool {
+        self.mode != Mode::Apply
+    }
+
+    fn plan(&mut self, path: &Path, change: Change) {
+        if self.changes.insert(path.to_path_buf(), change).is_none() {
+            self.order.push(path.to_path_buf());
+        }
+    }
+
+    /// Whether `path` exists once the planned changes are applied
+    pub fn exists(&self, path: &Path) -> bool {
+        match self.changes.get(path) {
+            Some(Change::Remove { .. }) => false,
+            Some(_) => true,
+            None => path.exists(),
+        }
+    }
+
+    /// Read `path` as it will be once the planned changes are applied
+    pub fn read_to_string(&self, path: &Path) -> Result<String> {
+        match self.changes.get(path) {
+            Some(Change::Write { content, .. }) => Ok(content.clone()),
+            _ => fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display())),
+        }
+    }
+
+    /// Plan creating a directory and its parents
+    pub fn create_dir_all(&mut self, path: &Path) {
+        if !self.exists(path) {
+            self.plan(path, Change::CreateDir);
+        }
+    }
+
+    /// Plan writing `content` to `path`
+    pub fn write(&mut self, path: &Path, content: impl Into<String>) {
+        self.plan(path, Change::Write { content: content.into(), hidden: false, reason: None });
+    }
+
+    /// Plan a write that is applied but left out of listings
+    pub fn write_hidden(&mut self, path: &Path, content: impl Into<String>) {
+        self.plan(path, Change::Write { content: content.into(), hidden: true, reason: None });
+    }
+
+    /// Plan writing a file, following the overwrite policy if it exists and force is false
+    ///
+    /// Previews never prompt and show the file as overwritten. Returns whether
+    /// the write was planned.
+    pub fn write_file(&mut self, path: &Path, content: &str, force: bool, relative_path: &str) -> Result<bool> {
+        if self.exists(path) && !force {
+            let overwrite = match self.overwrite {
+                Overwrite::Ask if self.is_preview() => Overwrite::Yes,
+                overwrite => overwrite,
+            };
+            if !overwrite.allows(relative_path)? {
+                output::emit(Event::FileSkipped { path: &self.display(path), reason: SkipReason::Exists });
+                return Ok(false);
+            }
+        }
+
+        self.write(path, content);
+        Ok(true)
+    }
+
+    /// Explain why a planned write happens
+    pub fn set_reason(&mut self, path: &Path, reason: impl Into<String>) {
+        if let Some(Change::Write { reason: slot, .. }) = self.changes.get_mut(path) {
+            *slot = Some(reason.into());
+        }
+    }
+
+    /// Report a dependency once the changes are applied
+    pub fn add_dependency(&mut self, dependency: Dependency) {
+        self.dependencies.push(dependency);
+    }
+
+    /// Plan removing a file
+    pub fn remove_file(&mut self, path: &Path) {
+        self.plan(path, Change::Remove { hidden: false });
+    }
+
+    /// Plan a removal that is applied but left out of listings
+    pub fn remove_file_hidden(&mut self, path: &Path) {
+        self.plan(path, Change::Remove { hidden: true });
+    }
+
+    fn display(&self, path: &Path) -> String {
+        path.strip_prefix(&self.root).unwrap_or(path).display().to_string()
+    }
+
+    /// Apply the changes, or print them when previewing
+    pub fn finish(self) -> Result<()> {
+        match self.mode {
+            Mode::Apply => self.apply(),
+            Mode::DryRun | Mode::Diff => {
+                if output::is_json() {
+                    self.emit_plan();
+                } else {
+                    self.print_plan();
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn apply(self) -> Result<()> {
+        let ChangeSet { root, order, mut changes, dependencies, .. } = self;
+        let display = |path: &Path| path.strip_prefix(&root).unwrap_or(path).display().to_string();
+
+        for path in order {
+            let Some(change) = changes.remove(&path) else {
+                continue;
+            };
+            match change {
+                Change::CreateDir => {
+                    fs::create_dir_all(&path)
+                        .with_context(|| format!("Failed to create directory {}", path.display()))?;
+                    output::emit(Event::DirectoryCreated { path: &display(&path) });
+                }
+                Change::Write { content, hidden, reason } => {
+                    if let Some(parent) = path.parent() {
+                        fs::create_dir_all(parent)
+                            .with_context(|| format!("Failed to create directory {}", parent.display()))?;
+                    }
+                    let existed = path.exists();
+                    if existed && fs::read_to_string(&path).is_ok_and(|current| current == content) {
+                        if !hidden {
+                            output::emit(Event::FileSkipped { path: &display(&path), reason: SkipReason::Unchanged });
+                        }
+                        continue;
+                    }
+                    fs::write(&path, content)
+                        .with_context(|| format!("Failed to write file: {}", path.display()))?;
+                    if !hidden {
+                        output::emit(Event::FileWritten {
+                            path: &display(&path),
+                            created: !existed,
+                            reason: reason.as_deref(),
+                        });
+                    }
+                }
+                Change::Remove { hidden } => {
+                    if path.exists() {
+                        fs::remove_file(&path)
+                            .with_context(|| format!("Failed to remove {}", path.display()))?;
+                        if !hidden {
+                            output::emit(Event::FileRemoved { path: &display(&path) });
+                        }
+                    }
+                }
+            }
+        }
+
+        for dependency in &dependencies {
+            output::emit(Event::DependencyAdded(dependency));
+        }
+
+        Ok(())
+    }
+
+    fn print_plan(&self) {
+        println!("{}", "Planned changes (nothing was written):".bold());
+
+        let mut hidden = 0;
+        for path in &self.order {
+            let shown = self.display(path);
+            match &self.changes[path] {
+                Change::CreateDir => println!("  {}  {}/", "create".green(), shown),
+                Change::Write { hidden: true, .. } => hidden += 1,
+                Change::Write { content, reason, .. } => {
+                    let reason = reason.as_ref().map(|reason| format!(" ({})", reason)).unwrap_or_default();
+                    let current = fs::read_to_string(path).ok();
+                    match current {
+                        None => println!("  {}  {}{}", "create".green(), shown, reason),
+                        Some(current) if current == *content => {
+                            println!("  {}  {}{}", "keep  ".dimmed(), shown, reason)
+                        }
+                        Some(current) => {
+                            println!("  {}  {}{}", "modify".yellow(), shown, reason);
+                            self.print_changes(&current, content);
+                        }
+                    }
+                    if self.mode == Mode::Diff && !path.exists() {
+                        self.print_changes("", content);
+                    }
+                }
+                Change::Remove { hidden: true } => {}
+                Change::Remove { hidden: false } => {
+                    if path.exists() {
+                        println!("  {}  {}", "remove".red(), shown);
+                    }
+                }
+            }
+        }
+
+        if hidden > 0 {
+            println!("  (and {} cargo-stripe bookkeeping files)", hidden);
+        }
+    }
+
+    /// Report each planned change as a `file_planned` event
+    fn emit_plan(&self) {
+        for path in &self.order {
+            let (action, reason, diff) = match &self.changes[path] {
+                Change::CreateDir => ("create", None, None),
+                Change::Write { hidden: true, .. } | Change::Remove { hidden: true } => continue,
+                Change::Write { content, reason, .. } => {
+                    let current = fs::read_to_string(path).ok();
+                    let action = match &current {
+                        None => "create",
+                        Some(current) if current == content => "keep",
+                        Some(_) => "modify",
+                    };
+                    let diff = (self.mode == Mode::Diff && action != "keep")
+                        .then(|| diffy::create_patch(current.as_deref().unwrap_or_default(), content).to_string());
+                    (action, reason.as_deref(), diff)
+                }
+                Change::Remove { hidden: false } if path.exists() => ("remove", None, None),
+                Change::Remove { hidden: false } => continue,
+            };
+            output::emit(Event::FilePlanned { path: &self.display(path), action, reason, diff });
+        }
+    }
+
+    /// Show what changes in a file: a unified diff in `--diff` mode, otherwise
+    /// the added and removed lines
+    fn print_changes(&self, old: &str, new: &str) {
+        let patch = diffy::create_patch(old, new);
+        if self.mode == Mode::Diff {
+            for line in patch.to_string().lines() {
+                let line = match line.chars().next() {
+                    Some('+') => line.green().to_string(),
+                    Some('-') => line.red().to_string(),
+                    Some('@') => line.cyan().to_string(),
+                    _ => line.to_string(),
+                };
+                println!("        {}", line);
+            }
+            return;
+        }
+
+        for hunk in patch.hunks() {
+            for line in hunk.lines() {
+                match line {
+                    diffy::Line::Insert(text) => println!("          {} {}", "+".green(), text.trim_end()),
+                    diffy::Line::Delete(text) => println!("          {} {}", "-".red(), text.trim_end()),
+                    diffy::Line::Context(_) => {}
+                }
+            }
+        }
+    }
+}
@@ -2,9 +2,11 @@ use anyhow::{Context, Result, anyhow};
 use colored::Colorize;
 use std::env;
 use std::fs;
-use std::io::{self, Write};
+use std::io::{self, IsTerminal, Write};
 use std::path::{Path, PathBuf};
 
+use crate::utils::output::{self, Event};
+
 /// Find the project root directory (where Cargo.toml is located)
 pub fn find_project_root(target_dir: Option<&Path>) -> Result<PathBuf> {
     let current_dir = match target_dir {
@@ -55,25 +57,51 @@ pub fn find_src_directory(target_dir: Option<&Path>) -> Result<PathBuf> {
     ))
 }
 
-/// Write content to a file, asking for confirmation if the file exists and force is false
-pub fn write_file<T: AsRef<[u8]>>(path: &Path, content: T, force: bool, relative_path: &str) -> Result<()> {
-    if path.exists() && !force {
-        let response = prompt_yes_no(&format!(
-            "The file {} already exists. Overwrite?",
-            relative_path
-        ))?;
-
-        if !response {
-            println!("{} Skipped {}", "→".yellow(), relative_path);
-            return Ok(());
+/// What to do when a file about to be written already exists
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overwrite {
+    /// Ask on the terminal
+    Ask,
+    /// Overwrite without asking (`--yes`)
+    Yes,
+    /// Keep the existing file (`--skip-existing`)
+    Skip,
+    /// Fail the command (`--no-overwrite`)
+    Refuse,
+}
+
+impl Overwrite {
+    /// Pick the policy from the command-line flags
+    ///
+    /// Without a flag the user is asked, unless stdin is not a terminal or
+    /// output is JSON, in which case existing files are kept.
+    pub fn from_flags(yes: bool, no_overwrite: bool, skip_existing: bool) -> Self {
+        if yes {
+            Overwrite::Yes
+        } else if no_overwrite {
+            Overwrite::Refuse
+        } else if skip_existing || !io::stdin().is_terminal() || output::is_json() {
+            Overwrite::Skip
+        } else {
+            Overwrite::Ask
         }
     }
 
-    fs::write(path, content)
-        .with_context(|| format!("Failed to write file: {}", path.display()))?;
-
-    println!("{} Written: {}", "✓".green(), relative_path);
-    Ok(())
+    /// Decide whether the existing file at `relative_path` is overwritten
+    pub fn allows(self, relative_path: &str) -> Result<bool> {
+        match self {
+            Overwrite::Ask => prompt_yes_no(&format!(
+                "The file {} already exists. Overwrite?",
+                relative_path
+            )),
+            Overwrite::Yes => Ok(true),
+            Overwrite::Skip => Ok(false),
+            Overwrite::Refuse => Err(anyhow!(
+                "The file {} already exists. Pass --yes to overwrite it or --skip-existing to keep it.",
+                relative_path
+            )),
+        }
+    }
 }
 
 /// Prompt the user for a yes/no response
@@ -92,11 +120,7 @@ pub fn ensure_project_exists(target_dir: &Path) -> Result<()> {
     // If the directory doesn't exist, create it
     if !target_dir.exists() {
         fs::create_dir_all(target_dir)?;
-        println!(
-            "{} Created directory: {}",
-            "✓".green(),
-            target_dir.display()
-        );
+        output::emit(Event::DirectoryCreated { path: &target_dir.display().to_string() });
 
         // Run cargo init to create a new Rust project
         let status = std::process::Command::new("cargo")
@@ -114,7 +138,7 @@ pub fn ensure_project_exists(target_dir: &Path) -> Result<()> {
             ));
         }
 
-        println!("{} Initialized new Rust project", "✓".green());
+        output::info(format!("{} Initialized new Rust project", "✓".green()));
     }
 
     // Ensure Cargo.toml exists
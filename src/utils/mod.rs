@@ -1 +1,3 @@
+pub mod changes;
 pub mod fs;
+pub mod output;
@@ -0,0 +1,147 @@
+//! Human or machine-readable command output.
+//!
+//! With `--message-format json` each message is printed as one JSON object
+//! per line, tagged by its `event` field, and the human prose is left out.
+
+use clap::ValueEnum;
+use colored::Colorize;
+use serde::Serialize;
+use std::fmt::Display;
+use std::sync::OnceLock;
+
+/// How messages are printed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum MessageFormat {
+    /// Colored prose
+    #[default]
+    Human,
+    /// One JSON event per line
+    Json,
+}
+
+static FORMAT: OnceLock<MessageFormat> = OnceLock::new();
+
+/// Choose the message format for the rest of the run
+pub fn set_format(format: MessageFormat) {
+    let _ = FORMAT.set(format);
+}
+
+/// Whether messages are printed as JSON events
+pub fn is_json() -> bool {
+    FORMAT.get() == Some(&MessageFormat::Json)
+}
+
+/// Why a file was not written
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SkipReason {
+    /// The file exists and the overwrite policy kept it
+    Exists,
+    /// The file already has the planned content
+    Unchanged,
+}
+
+/// Something a project depends on that a command added
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Dependency {
+    /// A crate added to `Cargo.toml`
+    Crate { name: String },
+    /// A feature turned on in `Cargo.toml`
+    Feature { name: String },
+    /// A resource file another file imports a type from
+    Resource {
+        path: String,
+        #[serde(rename = "type")]
+        type_name: String,
+        required_by: String,
+    },
+}
+
+/// A single message
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event<'a> {
+    DirectoryCreated {
+        path: &'a str,
+    },
+    FileWritten {
+        path: &'a str,
+        /// False when an existing file was replaced
+        created: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        reason: Option<&'a str>,
+    },
+    FileSkipped {
+        path: &'a str,
+        reason: SkipReason,
+    },
+    FileRemoved {
+        path: &'a str,
+    },
+    /// A change `--dry-run` or `--diff` would make
+    FilePlanned {
+        path: &'a str,
+        /// One of `create`, `modify`, `keep` or `remove`
+        action: &'a str,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        reason: Option<&'a str>,
+        /// Unified diff against the file on disk, with `--diff`
+        #[serde(skip_serializing_if = "Option::is_none")]
+        diff: Option<String>,
+    },
+    DependencyAdded(&'a Dependency),
+    Component {
+        name: &'a str,
+        installed: bool,
+    },
+    Warning {
+        message: &'a str,
+    },
+    Error {
+        message: &'a str,
+    },
+    Finished {
+        message: &'a str,
+    },
+}
+
+/// Print an event as JSON, or as its human-readable line
+pub fn emit(event: Event) {
+    if is_json() {
+        match serde_json::to_string(&event) {
+            Ok(line) => println!("{}", line),
+            Err(err) => eprintln!("Failed to serialize event: {}", err),
+        }
+        return;
+    }
+
+    match event {
+        Event::DirectoryCreated { path } => println!("{} Created directory: {}", "✓".green(), path),
+        Event::FileWritten { path, created, reason } => {
+            let verb = if created { "Written" } else { "Updated" };
+            match reason {
+                Some(reason) => println!("{} {}: {} ({})", "✓".green(), verb, path, reason),
+                None => println!("{} {}: {}", "✓".green(), verb, path),
+            }
+        }
+        Event::FileSkipped { path, reason: SkipReason::Exists } => println!("{} Skipped {}", "→".yellow(), path),
+        Event::FileRemoved { path } => println!("{} Removed: {}", "✓".green(), path),
+        Event::Component { name, installed: true } => println!("  • {} {}", name, "(installed)".green()),
+        Event::Component { name, installed: false } => println!("  • {}", name),
+        Event::Warning { message } => println!("{} {}", "!".yellow(), message),
+        Event::Error { message } => println!("{} {}", "✗".red(), message),
+        Event::Finished { message } => println!("{}", message.green()),
+        // Shown by the lines around them in human output
+        Event::FileSkipped { reason: SkipReason::Unchanged, .. }
+        | Event::FilePlanned { .. }
+        | Event::DependencyAdded(_) => {}
+    }
+}
+
+/// Print a line of human-only prose
+pub fn info(message: impl Display) {
+    if !is_json() {
+        println!("{}", message);
+    }
+}